@@ -0,0 +1,51 @@
+use crate::{Segments, SliceSegments, ast::block::Block, owned_all};
+use std::{borrow::Cow, ops::Range};
+
+/// A block quote, as described in the [spec](https://spec.commonmark.org/0.31.2/#block-quotes).
+///
+/// The content of the block quote is made of the lines stripped of their block quote markers.
+/// This content is parsed as blocks, which are the children of the block quote.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BlockQuote<'a> {
+    /// The source lines, including the block quote markers and the lazy continuation lines.
+    segments: Vec<Cow<'a, str>>,
+    blocks: Vec<Block<'a>>,
+    pub(crate) range: Range<usize>,
+}
+
+impl<'a> BlockQuote<'a> {
+    pub(crate) fn new(segments: Vec<&'a str>, blocks: Vec<Block<'a>>) -> Self {
+        Self {
+            segments: segments.into_iter().map(Cow::Borrowed).collect(),
+            blocks,
+            range: 0..0,
+        }
+    }
+
+    /// Detaches the block quote from its source, by copying the strings it and its content borrow.
+    pub fn into_owned(self) -> BlockQuote<'static> {
+        BlockQuote {
+            segments: owned_all(self.segments),
+            blocks: self.blocks.into_iter().map(Block::into_owned).collect(),
+            range: self.range,
+        }
+    }
+
+    /// The blocks contained within the block quote.
+    pub fn blocks(&self) -> &[Block<'a>] {
+        &self.blocks
+    }
+
+    pub(crate) fn blocks_mut(&mut self) -> &mut [Block<'a>] {
+        &mut self.blocks
+    }
+}
+
+impl<'a> Segments<'a> for BlockQuote<'a> {
+    type SegmentsIter = SliceSegments<'a>;
+
+    fn segments(&'a self) -> Self::SegmentsIter {
+        self.segments.as_slice().into()
+    }
+}
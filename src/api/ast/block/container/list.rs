@@ -0,0 +1,77 @@
+use super::{ListItem, ListMarker};
+use crate::Segments;
+use std::{iter::FlatMap, ops::Range, slice};
+
+/// A list, as described in the [spec](https://spec.commonmark.org/0.31.2/#lists).
+///
+/// A list is a sequence of one or more list items of the same type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct List<'a> {
+    /// At least one.
+    items: Vec<ListItem<'a>>,
+    tight: bool,
+    pub(crate) range: Range<usize>,
+}
+
+impl<'a> List<'a> {
+    pub(crate) fn new(items: Vec<ListItem<'a>>, tight: bool) -> Self {
+        debug_assert!(!items.is_empty(), "a list must have at least one item");
+        Self {
+            items,
+            tight,
+            range: 0..0,
+        }
+    }
+
+    /// Detaches the list from its source, by copying the strings it and its content borrow.
+    pub fn into_owned(self) -> List<'static> {
+        List {
+            items: self.items.into_iter().map(ListItem::into_owned).collect(),
+            tight: self.tight,
+            range: self.range,
+        }
+    }
+
+    /// The items of the list.
+    pub fn items(&self) -> &[ListItem<'a>] {
+        &self.items
+    }
+
+    pub(crate) fn items_mut(&mut self) -> &mut [ListItem<'a>] {
+        &mut self.items
+    }
+
+    /// Whether the list is [tight](https://spec.commonmark.org/0.31.2/#tight), as opposed to loose.
+    ///
+    /// The paragraphs of tight lists are rendered without `<p>` tags.
+    pub fn is_tight(&self) -> bool {
+        self.tight
+    }
+
+    /// Whether the list is made of ordered items.
+    pub fn is_ordered(&self) -> bool {
+        matches!(self.items[0].marker(), ListMarker::Ordered { .. })
+    }
+
+    /// The number of the first item, for ordered lists.
+    pub fn start(&self) -> Option<u32> {
+        match self.items[0].marker() {
+            ListMarker::Bullet(_) => None,
+            ListMarker::Ordered { number, .. } => Some(number),
+        }
+    }
+}
+
+impl<'a> Segments<'a> for List<'a> {
+    #[allow(clippy::type_complexity)]
+    type SegmentsIter = FlatMap<
+        slice::Iter<'a, ListItem<'a>>,
+        <ListItem<'a> as Segments<'a>>::SegmentsIter,
+        fn(&'a ListItem<'a>) -> <ListItem<'a> as Segments<'a>>::SegmentsIter,
+    >;
+
+    fn segments(&'a self) -> Self::SegmentsIter {
+        self.items.iter().flat_map(ListItem::segments)
+    }
+}
@@ -0,0 +1,170 @@
+use crate::{Segment, Segments, SliceSegments, ast::block::Block, owned, owned_all};
+use std::{borrow::Cow, ops::Range};
+
+/// The marker opening a list item.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(tag = "kind", content = "value", rename_all = "snake_case")
+)]
+pub enum ListMarker {
+    /// One of `-`, `+` or `*`.
+    Bullet(char),
+    /// A sequence of 1 to 9 digits followed by either `.` or `)`.
+    Ordered { number: u32, delimiter: char },
+}
+
+impl ListMarker {
+    /// Returns whether the list items opened by both markers belong to the same list.
+    ///
+    /// Bullet list items must use the same character, and ordered list items the same delimiter.
+    pub fn is_same_type(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Bullet(left), Self::Bullet(right)) => left == right,
+            (
+                Self::Ordered {
+                    delimiter: left, ..
+                },
+                Self::Ordered {
+                    delimiter: right, ..
+                },
+            ) => left == right,
+            _ => false,
+        }
+    }
+}
+
+/// The checkbox of a task list item, as described in the
+/// [GFM spec](https://github.github.com/gfm/#task-list-items-extension-).
+///
+/// It is made of brackets around a space, or around an `x` when it is checked. It follows the list
+/// marker, and is only parsed when the [task lists](crate::ParseOptions::task_lists) extension is
+/// enabled.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TaskListMarker<'a> {
+    segment: Cow<'a, str>,
+    pub(crate) range: Range<usize>,
+}
+
+impl<'a> TaskListMarker<'a> {
+    pub(crate) fn new(segment: &'a str) -> Self {
+        Self {
+            segment: Cow::Borrowed(segment),
+            range: 0..0,
+        }
+    }
+
+    /// Detaches the checkbox from its source, by copying the strings it borrows.
+    pub fn into_owned(self) -> TaskListMarker<'static> {
+        TaskListMarker {
+            segment: owned(self.segment),
+            range: self.range,
+        }
+    }
+
+    /// Returns whether the checkbox is checked, with either a lowercase or an uppercase `x`.
+    pub fn is_checked(&self) -> bool {
+        matches!(&self.segment[1..2], "x" | "X")
+    }
+}
+
+impl Segment for TaskListMarker<'_> {
+    fn segment(&self) -> &str {
+        &self.segment
+    }
+}
+
+/// A list item, as described in the [spec](https://spec.commonmark.org/0.31.2/#list-items).
+///
+/// The content of the list item is made of the lines stripped of the marker, or of the
+/// indentation required to continue the item. This content is parsed as blocks, which are the
+/// children of the list item.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "kind", rename = "list_item"))]
+pub struct ListItem<'a> {
+    /// The source lines, including the marker, the lazy continuation lines and the blank lines up to
+    /// the next list item, if any.
+    segments: Vec<Cow<'a, str>>,
+    marker: ListMarker,
+    task_list_marker: Option<TaskListMarker<'a>>,
+    blocks: Vec<Block<'a>>,
+    pub(crate) range: Range<usize>,
+}
+
+impl<'a> ListItem<'a> {
+    pub(crate) fn new(segments: Vec<&'a str>, marker: ListMarker, blocks: Vec<Block<'a>>) -> Self {
+        Self {
+            segments: segments.into_iter().map(Cow::Borrowed).collect(),
+            marker,
+            task_list_marker: None,
+            blocks,
+            range: 0..0,
+        }
+    }
+
+    pub(crate) fn with_task_list_marker(self, task_list_marker: TaskListMarker<'a>) -> Self {
+        Self {
+            task_list_marker: Some(task_list_marker),
+            ..self
+        }
+    }
+
+    /// Detaches the list item from its source, by copying the strings it and its content borrow.
+    pub fn into_owned(self) -> ListItem<'static> {
+        ListItem {
+            segments: owned_all(self.segments),
+            marker: self.marker,
+            task_list_marker: self.task_list_marker.map(TaskListMarker::into_owned),
+            blocks: self.blocks.into_iter().map(Block::into_owned).collect(),
+            range: self.range,
+        }
+    }
+
+    /// The marker opening the list item.
+    pub fn marker(&self) -> ListMarker {
+        self.marker
+    }
+
+    /// The checkbox following the marker, when the list item is a task list item.
+    pub fn task_list_marker(&self) -> Option<&TaskListMarker<'a>> {
+        self.task_list_marker.as_ref()
+    }
+
+    /// Whether the task list item is checked, or `None` when the list item is not a task list item.
+    pub fn checked(&self) -> Option<bool> {
+        self.task_list_marker
+            .as_ref()
+            .map(TaskListMarker::is_checked)
+    }
+
+    pub(crate) fn task_list_marker_mut(&mut self) -> Option<&mut TaskListMarker<'a>> {
+        self.task_list_marker.as_mut()
+    }
+
+    /// The blocks contained within the list item.
+    pub fn blocks(&self) -> &[Block<'a>] {
+        &self.blocks
+    }
+
+    pub(crate) fn blocks_mut(&mut self) -> &mut [Block<'a>] {
+        &mut self.blocks
+    }
+
+    /// Appends segments that are not part of the item content, such as the blank lines separating it
+    /// from the next item.
+    pub(crate) fn extend_segments(&mut self, segments: impl IntoIterator<Item = &'a str>) {
+        self.segments
+            .extend(segments.into_iter().map(Cow::Borrowed));
+    }
+}
+
+impl<'a> Segments<'a> for ListItem<'a> {
+    type SegmentsIter = SliceSegments<'a>;
+
+    fn segments(&'a self) -> Self::SegmentsIter {
+        self.segments.as_slice().into()
+    }
+}
@@ -1 +1,65 @@
+mod block_quote;
+mod list;
+mod list_item;
 
+use crate::Segments;
+pub use block_quote::*;
+pub use list::*;
+pub use list_item::*;
+use std::iter::FusedIterator;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "kind", rename_all = "snake_case"))]
+pub enum Container<'a> {
+    BlockQuote(BlockQuote<'a>),
+    List(List<'a>),
+}
+
+impl Container<'_> {
+    /// Detaches the container from its source, by copying the strings it and its content borrow.
+    pub fn into_owned(self) -> Container<'static> {
+        match self {
+            Container::BlockQuote(block_quote) => Container::BlockQuote(block_quote.into_owned()),
+            Container::List(list) => Container::List(list.into_owned()),
+        }
+    }
+}
+
+impl<'a> Segments<'a> for Container<'a> {
+    type SegmentsIter = ContainerSegmentsIterator<'a>;
+
+    fn segments(&'a self) -> Self::SegmentsIter {
+        ContainerSegmentsIterator::from(self)
+    }
+}
+
+// TODO: turn into statically typed enum.
+pub struct ContainerSegmentsIterator<'a> {
+    iter: Box<dyn Iterator<Item = &'a str> + 'a>,
+}
+
+impl<'a> ContainerSegmentsIterator<'a> {
+    fn new(iter: Box<dyn Iterator<Item = &'a str> + 'a>) -> Self {
+        Self { iter }
+    }
+}
+
+impl<'a> From<&'a Container<'a>> for ContainerSegmentsIterator<'a> {
+    fn from(container: &'a Container) -> Self {
+        match container {
+            Container::BlockQuote(block_quote) => Self::new(Box::new(block_quote.segments())),
+            Container::List(list) => Self::new(Box::new(list.segments())),
+        }
+    }
+}
+
+impl FusedIterator for ContainerSegmentsIterator<'_> {}
+
+impl<'a> Iterator for ContainerSegmentsIterator<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+}
@@ -1,21 +1,39 @@
-use crate::Segment;
+use crate::{Segment, ast::inline::Inline, borrowed, owned};
+use std::{borrow::Cow, ops::Range};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AtxHeading<'a> {
     /// The source segment from which this struct was constructed.
-    segment: &'a str,
+    segment: Cow<'a, str>,
     /// The title of the heading, possibly empty.
-    title: &'a str,
+    title: Cow<'a, str>,
     /// The level of the heading, from 1 to 6.
     level: u8,
+    /// The inline content of the title, parsed once all the blocks of the document are known.
+    inlines: Vec<Inline<'a>>,
+    pub(crate) range: Range<usize>,
 }
 
 impl<'a> AtxHeading<'a> {
     pub(crate) fn new(segment: &'a str, title: &'a str, level: u8) -> Self {
         Self {
-            segment,
-            title,
+            segment: Cow::Borrowed(segment),
+            title: Cow::Borrowed(title),
             level,
+            inlines: Vec::new(),
+            range: 0..0,
+        }
+    }
+
+    /// Detaches the heading from its source, by copying the strings it and its content borrow.
+    pub fn into_owned(self) -> AtxHeading<'static> {
+        AtxHeading {
+            segment: owned(self.segment),
+            title: owned(self.title),
+            level: self.level,
+            inlines: self.inlines.into_iter().map(Inline::into_owned).collect(),
+            range: self.range,
         }
     }
 
@@ -23,13 +41,32 @@ impl<'a> AtxHeading<'a> {
         self.level
     }
 
-    pub fn title(&self) -> &'a str {
-        self.title
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// The title, as the single line of inline content, unless the heading is detached from its
+    /// source.
+    pub(crate) fn content_line(&self) -> Option<&'a str> {
+        borrowed(&self.title)
+    }
+
+    /// The inline content of the title.
+    pub fn inlines(&self) -> &[Inline<'a>] {
+        &self.inlines
+    }
+
+    pub(crate) fn inlines_mut(&mut self) -> &mut [Inline<'a>] {
+        &mut self.inlines
+    }
+
+    pub(crate) fn set_inlines(&mut self, inlines: Vec<Inline<'a>>) {
+        self.inlines = inlines;
     }
 }
 
-impl<'a> Segment<'a> for AtxHeading<'a> {
-    fn segment(&self) -> &'a str {
-        self.segment
+impl Segment for AtxHeading<'_> {
+    fn segment(&self) -> &str {
+        &self.segment
     }
 }
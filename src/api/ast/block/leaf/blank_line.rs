@@ -1,16 +1,32 @@
-use crate::Segment;
+use crate::{Segment, owned};
+use std::{borrow::Cow, ops::Range};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct BlankLine<'a>(&'a str);
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BlankLine<'a> {
+    segment: Cow<'a, str>,
+    pub(crate) range: Range<usize>,
+}
 
 impl<'a> BlankLine<'a> {
     pub(crate) fn new(segment: &'a str) -> Self {
-        Self(segment)
+        Self {
+            segment: Cow::Borrowed(segment),
+            range: 0..0,
+        }
+    }
+
+    /// Detaches the blank line from its source, by copying the strings it borrows.
+    pub fn into_owned(self) -> BlankLine<'static> {
+        BlankLine {
+            segment: owned(self.segment),
+            range: self.range,
+        }
     }
 }
 
-impl<'a> Segment<'a> for BlankLine<'a> {
-    fn segment(&self) -> &'a str {
-        self.0
+impl Segment for BlankLine<'_> {
+    fn segment(&self) -> &str {
+        &self.segment
     }
 }
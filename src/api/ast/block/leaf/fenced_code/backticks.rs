@@ -1,18 +1,20 @@
 use crate::{
-    Segment, Segments,
+    Segment, Segments, owned_all,
     parse::segment::fenced_code::{
         BackticksFencedCodeClosingSegment, BackticksFencedCodeOpeningSegment,
     },
 };
-use std::{iter::FusedIterator, slice};
+use std::{borrow::Cow, iter::FusedIterator, ops::Range, slice};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BackticksFencedCode<'a> {
     opening_segment: BackticksFencedCodeOpeningSegment<'a>,
-    content_segments: Vec<&'a str>,
+    content_segments: Vec<Cow<'a, str>>,
     /// The closing segment is allowed to be None in one scenario: when the end of input is reached
     /// before a closing segment. This is allowed by the spec.
     closing_segment: Option<BackticksFencedCodeClosingSegment<'a>>,
+    pub(crate) range: Range<usize>,
 }
 
 impl<'a> BackticksFencedCode<'a> {
@@ -28,10 +30,28 @@ impl<'a> BackticksFencedCode<'a> {
     ) -> Self {
         Self {
             opening_segment,
-            content_segments,
+            content_segments: content_segments.into_iter().map(Cow::Borrowed).collect(),
             closing_segment,
+            range: 0..0,
         }
     }
+
+    /// Detaches the code block from its source, by copying the strings it borrows.
+    pub fn into_owned(self) -> BackticksFencedCode<'static> {
+        BackticksFencedCode {
+            opening_segment: self.opening_segment.into_owned(),
+            content_segments: owned_all(self.content_segments),
+            closing_segment: self
+                .closing_segment
+                .map(BackticksFencedCodeClosingSegment::into_owned),
+            range: self.range,
+        }
+    }
+
+    /// The raw info string following the opening fence, which can be empty.
+    pub fn info_string(&self) -> &str {
+        self.opening_segment.info_string()
+    }
 }
 
 impl<'a> Segments<'a> for BackticksFencedCode<'a> {
@@ -43,7 +63,7 @@ impl<'a> Segments<'a> for BackticksFencedCode<'a> {
 }
 
 pub struct BackticksFencedCodeContentSegmentsIterator<'a> {
-    content_segments: slice::Iter<'a, &'a str>,
+    content_segments: slice::Iter<'a, Cow<'a, str>>,
 }
 
 impl<'a> From<&'a BackticksFencedCode<'a>> for BackticksFencedCodeContentSegmentsIterator<'a> {
@@ -65,17 +85,17 @@ impl<'a> Iterator for BackticksFencedCodeContentSegmentsIterator<'a> {
 }
 
 pub struct BackticksFencedCodeSegmentsIterator<'a> {
-    opening_segment: Option<BackticksFencedCodeOpeningSegment<'a>>,
+    opening_segment: Option<&'a BackticksFencedCodeOpeningSegment<'a>>,
     content_segments: BackticksFencedCodeContentSegmentsIterator<'a>,
-    closing_segment: Option<BackticksFencedCodeClosingSegment<'a>>,
+    closing_segment: Option<&'a BackticksFencedCodeClosingSegment<'a>>,
 }
 
 impl<'a> From<&'a BackticksFencedCode<'a>> for BackticksFencedCodeSegmentsIterator<'a> {
     fn from(fenced_code: &'a BackticksFencedCode<'a>) -> Self {
         Self {
-            opening_segment: Some(fenced_code.opening_segment.clone()),
+            opening_segment: Some(&fenced_code.opening_segment),
             content_segments: fenced_code.into(),
-            closing_segment: fenced_code.closing_segment.clone(),
+            closing_segment: fenced_code.closing_segment.as_ref(),
         }
     }
 }
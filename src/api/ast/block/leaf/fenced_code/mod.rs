@@ -8,6 +8,8 @@ use crate::Segments;
 use std::iter::FusedIterator;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "fence", rename_all = "snake_case"))]
 pub enum FencedCode<'a> {
     Backticks(BackticksFencedCode<'a>),
     Tildes(TildesFencedCode<'a>),
@@ -17,6 +19,26 @@ impl<'a> FencedCode<'a> {
     pub fn content_segments(&'a self) -> FencedCodeContentSegmentsIterator<'a> {
         self.into()
     }
+
+    /// The raw info string following the opening fence, which can be empty.
+    ///
+    /// Its first word is usually the language of the code.
+    pub fn info_string(&self) -> &str {
+        match self {
+            Self::Backticks(backticks) => backticks.info_string(),
+            Self::Tildes(tildes) => tildes.info_string(),
+        }
+    }
+}
+
+impl FencedCode<'_> {
+    /// Detaches the code block from its source, by copying the strings it borrows.
+    pub fn into_owned(self) -> FencedCode<'static> {
+        match self {
+            Self::Backticks(backticks) => FencedCode::Backticks(backticks.into_owned()),
+            Self::Tildes(tildes) => FencedCode::Tildes(tildes.into_owned()),
+        }
+    }
 }
 
 impl<'a> From<BackticksFencedCode<'a>> for FencedCode<'a> {
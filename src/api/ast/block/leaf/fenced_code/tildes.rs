@@ -1,16 +1,18 @@
 use crate::{
-    Segment, Segments,
+    Segment, Segments, owned_all,
     parse::segment::fenced_code::{TildesFencedCodeClosingSegment, TildesFencedCodeOpeningSegment},
 };
-use std::{iter::FusedIterator, slice};
+use std::{borrow::Cow, iter::FusedIterator, ops::Range, slice};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TildesFencedCode<'a> {
     opening_segment: TildesFencedCodeOpeningSegment<'a>,
-    content_segments: Vec<&'a str>,
+    content_segments: Vec<Cow<'a, str>>,
     /// The closing segment is allowed to be None in one scenario: when the end of input is reached
     /// before a closing segment. This is allowed by the spec.
     closing_segment: Option<TildesFencedCodeClosingSegment<'a>>,
+    pub(crate) range: Range<usize>,
 }
 
 impl<'a> TildesFencedCode<'a> {
@@ -25,10 +27,28 @@ impl<'a> TildesFencedCode<'a> {
     ) -> Self {
         Self {
             opening_segment,
-            content_segments,
+            content_segments: content_segments.into_iter().map(Cow::Borrowed).collect(),
             closing_segment,
+            range: 0..0,
         }
     }
+
+    /// Detaches the code block from its source, by copying the strings it borrows.
+    pub fn into_owned(self) -> TildesFencedCode<'static> {
+        TildesFencedCode {
+            opening_segment: self.opening_segment.into_owned(),
+            content_segments: owned_all(self.content_segments),
+            closing_segment: self
+                .closing_segment
+                .map(TildesFencedCodeClosingSegment::into_owned),
+            range: self.range,
+        }
+    }
+
+    /// The raw info string following the opening fence, which can be empty.
+    pub fn info_string(&self) -> &str {
+        self.opening_segment.info_string()
+    }
 }
 
 impl<'a> Segments<'a> for TildesFencedCode<'a> {
@@ -40,7 +60,7 @@ impl<'a> Segments<'a> for TildesFencedCode<'a> {
 }
 
 pub struct TildesFencedCodeContentSegmentsIterator<'a> {
-    content_segments: slice::Iter<'a, &'a str>,
+    content_segments: slice::Iter<'a, Cow<'a, str>>,
 }
 
 impl<'a> From<&'a TildesFencedCode<'a>> for TildesFencedCodeContentSegmentsIterator<'a> {
@@ -62,17 +82,17 @@ impl<'a> Iterator for TildesFencedCodeContentSegmentsIterator<'a> {
 }
 
 pub struct TildesFencedCodeSegmentsIterator<'a> {
-    opening_segment: Option<TildesFencedCodeOpeningSegment<'a>>,
+    opening_segment: Option<&'a TildesFencedCodeOpeningSegment<'a>>,
     content_segments: TildesFencedCodeContentSegmentsIterator<'a>,
-    closing_segment: Option<TildesFencedCodeClosingSegment<'a>>,
+    closing_segment: Option<&'a TildesFencedCodeClosingSegment<'a>>,
 }
 
 impl<'a> From<&'a TildesFencedCode<'a>> for TildesFencedCodeSegmentsIterator<'a> {
     fn from(fenced_code: &'a TildesFencedCode<'a>) -> Self {
         Self {
-            opening_segment: Some(fenced_code.opening_segment.clone()),
+            opening_segment: Some(&fenced_code.opening_segment),
             content_segments: fenced_code.into(),
-            closing_segment: fenced_code.closing_segment.clone(),
+            closing_segment: fenced_code.closing_segment.as_ref(),
         }
     }
 }
@@ -1,13 +1,27 @@
-use crate::{Segments, SliceSegments};
+use crate::{Segments, SliceSegments, owned_all};
+use std::{borrow::Cow, ops::Range};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Html<'a> {
-    segments: Vec<&'a str>,
+    segments: Vec<Cow<'a, str>>,
+    pub(crate) range: Range<usize>,
 }
 
 impl<'a> Html<'a> {
     pub(crate) fn new(segments: Vec<&'a str>) -> Self {
-        Self { segments }
+        Self {
+            segments: segments.into_iter().map(Cow::Borrowed).collect(),
+            range: 0..0,
+        }
+    }
+
+    /// Detaches the HTML block from its source, by copying the strings it borrows.
+    pub fn into_owned(self) -> Html<'static> {
+        Html {
+            segments: owned_all(self.segments),
+            range: self.range,
+        }
     }
 }
 
@@ -2,12 +2,17 @@ use crate::{
     Segment, Segments,
     parse::segment::indented_code::{ContinuationSegments, IndentedCodeSegment},
 };
-use std::iter::{self, FusedIterator};
+use std::{
+    iter::{self, FusedIterator},
+    ops::Range,
+};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct IndentedCode<'a> {
     opening_segment: IndentedCodeSegment<'a>,
     continuation_segments: Option<ContinuationSegments<'a>>,
+    pub(crate) range: Range<usize>,
 }
 
 impl<'a> IndentedCode<'a> {
@@ -18,6 +23,7 @@ impl<'a> IndentedCode<'a> {
         Self {
             opening_segment,
             continuation_segments,
+            range: 0..0,
         }
     }
 
@@ -31,6 +37,46 @@ impl<'a> IndentedCode<'a> {
     ) -> Self {
         Self::new(opening_segment, Some(continuation_segments))
     }
+
+    /// Detaches the code block from its source, by copying the strings it borrows.
+    pub fn into_owned(self) -> IndentedCode<'static> {
+        IndentedCode {
+            opening_segment: self.opening_segment.into_owned(),
+            continuation_segments: self
+                .continuation_segments
+                .map(ContinuationSegments::into_owned),
+            range: self.range,
+        }
+    }
+
+    /// Returns the lines of code, stripped of their indentation.
+    pub fn content_lines(&'a self) -> impl Iterator<Item = &'a str> {
+        self.segments().map(unindent)
+    }
+}
+
+/// Strips the indentation of a line of code, which is up to 4 spaces.
+///
+/// Although the spec mentions that tabs are equivalent to 4 spaces of indent, the example
+/// [here](https://spec.commonmark.org/0.31.2/#example-2) shows that it is treated as *up to* 4 spaces
+/// and not literally 4 spaces as far as indented code goes. If it were exactly 4 spaces, the resulting
+/// text in the example would start with 2 spaces (2 + 4 for the tab - 4), but it doesn't have any
+/// spaces at the beginning.
+fn unindent(line: &str) -> &str {
+    let mut trim_index = 0;
+    for (index, character) in line.char_indices().take(4) {
+        if !character.is_whitespace() {
+            break;
+        }
+        if character == '\t' {
+            trim_index = index + 1;
+            break;
+        }
+        if character == ' ' {
+            trim_index = index + 1;
+        }
+    }
+    &line[trim_index..]
 }
 
 impl<'a> Segments<'a> for IndentedCode<'a> {
@@ -1,11 +1,18 @@
-use crate::ast::inline::link::{LinkDestination, LinkLabel, LinkTitle};
+use crate::{
+    Segments, SliceSegments,
+    ast::inline::link::{LinkDestination, LinkLabel, LinkTitle},
+    owned_all,
+};
+use std::{borrow::Cow, ops::Range};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LinkReferenceDefinition<'a> {
-    segments: Vec<&'a str>,
+    segments: Vec<Cow<'a, str>>,
     label: LinkLabel<'a>,
     destination: LinkDestination<'a>,
     title: Option<LinkTitle<'a>>,
+    pub(crate) range: Range<usize>,
 }
 
 impl<'a> LinkReferenceDefinition<'a> {
@@ -16,10 +23,42 @@ impl<'a> LinkReferenceDefinition<'a> {
         title: Option<LinkTitle<'a>>,
     ) -> Self {
         Self {
-            segments,
+            segments: segments.into_iter().map(Cow::Borrowed).collect(),
             label,
             destination,
             title,
+            range: 0..0,
         }
     }
+
+    /// Detaches the definition from its source, by copying the strings it borrows.
+    pub fn into_owned(self) -> LinkReferenceDefinition<'static> {
+        LinkReferenceDefinition {
+            segments: owned_all(self.segments),
+            label: self.label.into_owned(),
+            destination: self.destination.into_owned(),
+            title: self.title.map(LinkTitle::into_owned),
+            range: self.range,
+        }
+    }
+
+    pub fn label(&self) -> &LinkLabel<'a> {
+        &self.label
+    }
+
+    pub fn destination(&self) -> &LinkDestination<'a> {
+        &self.destination
+    }
+
+    pub fn title(&self) -> Option<&LinkTitle<'a>> {
+        self.title.as_ref()
+    }
+}
+
+impl<'a> Segments<'a> for LinkReferenceDefinition<'a> {
+    type SegmentsIter = SliceSegments<'a>;
+
+    fn segments(&'a self) -> Self::SegmentsIter {
+        self.segments.as_slice().into()
+    }
 }
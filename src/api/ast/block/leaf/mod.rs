@@ -6,6 +6,7 @@ mod indented_code;
 mod link_reference_definition;
 mod paragraph;
 mod setext_heading;
+mod table;
 mod thematic_break;
 
 use crate::Segments;
@@ -18,9 +19,12 @@ pub use link_reference_definition::*;
 pub use paragraph::*;
 pub use setext_heading::*;
 use std::iter::FusedIterator;
+pub use table::*;
 pub use thematic_break::*;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "kind", rename_all = "snake_case"))]
 pub enum Leaf<'a> {
     AtxHeading(AtxHeading<'a>),
     BlankLine(BlankLine<'a>),
@@ -28,9 +32,30 @@ pub enum Leaf<'a> {
     Html(Html<'a>),
     IndentedCode(IndentedCode<'a>),
     LinkReferenceDefinition(LinkReferenceDefinition<'a>),
+    Paragraph(Paragraph<'a>),
+    SetextHeading(SetextHeading<'a>),
+    Table(Table<'a>),
     ThematicBreak(thematic_break::ThematicBreak<'a>),
 }
 
+impl Leaf<'_> {
+    /// Detaches the leaf from its source, by copying the strings it and its content borrow.
+    pub fn into_owned(self) -> Leaf<'static> {
+        match self {
+            Leaf::AtxHeading(leaf) => Leaf::AtxHeading(leaf.into_owned()),
+            Leaf::BlankLine(leaf) => Leaf::BlankLine(leaf.into_owned()),
+            Leaf::FencedCode(leaf) => Leaf::FencedCode(leaf.into_owned()),
+            Leaf::Html(leaf) => Leaf::Html(leaf.into_owned()),
+            Leaf::IndentedCode(leaf) => Leaf::IndentedCode(leaf.into_owned()),
+            Leaf::LinkReferenceDefinition(leaf) => Leaf::LinkReferenceDefinition(leaf.into_owned()),
+            Leaf::Paragraph(leaf) => Leaf::Paragraph(leaf.into_owned()),
+            Leaf::SetextHeading(leaf) => Leaf::SetextHeading(leaf.into_owned()),
+            Leaf::Table(leaf) => Leaf::Table(leaf.into_owned()),
+            Leaf::ThematicBreak(leaf) => Leaf::ThematicBreak(leaf.into_owned()),
+        }
+    }
+}
+
 impl<'a> Segments<'a> for Leaf<'a> {
     type SegmentsIter = LeafSegmentsIterator<'a>;
 
@@ -58,9 +83,12 @@ impl<'a> From<&'a Leaf<'a>> for LeafSegmentsIterator<'a> {
             Leaf::FencedCode(fenced_code) => Self::new(Box::new(fenced_code.segments())),
             Leaf::Html(html) => Self::new(Box::new(html.segments())),
             Leaf::IndentedCode(indented_code) => Self::new(Box::new(indented_code.segments())),
-            Leaf::LinkReferenceDefinition(_link_reference_definition) => {
-                unimplemented!("LinkReferenceDefinition text() not implemented")
+            Leaf::LinkReferenceDefinition(link_reference_definition) => {
+                Self::new(Box::new(link_reference_definition.segments()))
             }
+            Leaf::Paragraph(paragraph) => Self::new(Box::new(paragraph.segments())),
+            Leaf::SetextHeading(setext_heading) => Self::new(Box::new(setext_heading.segments())),
+            Leaf::Table(table) => Self::new(Box::new(table.segments())),
             Leaf::ThematicBreak(thematic_break) => Self::new(Box::new(thematic_break.segments())),
         }
     }
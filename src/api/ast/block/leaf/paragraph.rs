@@ -1,3 +1,6 @@
+use crate::{Segments, SliceSegments, ast::inline::Inline, borrowed_all, owned_all};
+use std::{borrow::Cow, ops::Range};
+
 /// Parsing rules:
 /// - A paragraph can be interrupted by:
 ///   - Thematic breaks
@@ -27,6 +30,56 @@
 /// - The remainder is the paragraph. *Because the remainder is empty*, a setext heading cannot be formed.
 ///
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Paragraph<'a> {
-    segments: Vec<&'a str>,
+    segments: Vec<Cow<'a, str>>,
+    /// The inline content, parsed once all the blocks of the document are known.
+    inlines: Vec<Inline<'a>>,
+    pub(crate) range: Range<usize>,
+}
+
+impl<'a> Paragraph<'a> {
+    pub(crate) fn new(segments: Vec<&'a str>) -> Self {
+        Self {
+            segments: segments.into_iter().map(Cow::Borrowed).collect(),
+            inlines: Vec::new(),
+            range: 0..0,
+        }
+    }
+
+    /// Detaches the paragraph from its source, by copying the strings it and its content borrow.
+    pub fn into_owned(self) -> Paragraph<'static> {
+        Paragraph {
+            segments: owned_all(self.segments),
+            inlines: self.inlines.into_iter().map(Inline::into_owned).collect(),
+            range: self.range,
+        }
+    }
+
+    /// The lines of the paragraph, which are all content, unless the paragraph is detached from its
+    /// source.
+    pub(crate) fn content_lines(&self) -> Option<Vec<&'a str>> {
+        borrowed_all(&self.segments)
+    }
+
+    /// The inline content of the paragraph.
+    pub fn inlines(&self) -> &[Inline<'a>] {
+        &self.inlines
+    }
+
+    pub(crate) fn inlines_mut(&mut self) -> &mut [Inline<'a>] {
+        &mut self.inlines
+    }
+
+    pub(crate) fn set_inlines(&mut self, inlines: Vec<Inline<'a>>) {
+        self.inlines = inlines;
+    }
+}
+
+impl<'a> Segments<'a> for Paragraph<'a> {
+    type SegmentsIter = SliceSegments<'a>;
+
+    fn segments(&'a self) -> Self::SegmentsIter {
+        self.segments.as_slice().into()
+    }
 }
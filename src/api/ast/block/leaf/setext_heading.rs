@@ -1,237 +1,85 @@
-use crate::parse::segment::setext_heading::SetextHeadingUnderlineSegment;
+use crate::{
+    Segment, Segments, SliceSegments, ast::inline::Inline, borrowed_all, owned_all,
+    parse::segment::setext_heading::SetextHeadingUnderlineSegment,
+};
+use std::{
+    borrow::Cow,
+    iter::{self, Chain, Once},
+    ops::Range,
+};
 
-// TODO: finish this up.
 /// Setext heading block, as describe in the [spec](https://spec.commonmark.org/0.31.2/#setext-headings).
 ///
 /// Unlike most blocks, setext headings are not parsed directly from the input. Rather, they
 /// are a possible byproduct of parsing a paragraph.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SetextHeading<'a> {
-    /* pub paragraph_segments: ParagraphSegments<'a>, */
+    /// The paragraph lines preceding the underline, at least one.
+    content_segments: Vec<Cow<'a, str>>,
     underline_segment: SetextHeadingUnderlineSegment<'a>,
+    /// The inline content, parsed once all the blocks of the document are known.
+    inlines: Vec<Inline<'a>>,
+    pub(crate) range: Range<usize>,
 }
 
-impl SetextHeading<'_> {
-    /* pub fn new(
-        paragraph_segments: ParagraphSegments<'a>,
+impl<'a> SetextHeading<'a> {
+    pub(crate) fn new(
+        content_segments: Vec<&'a str>,
         underline_segment: SetextHeadingUnderlineSegment<'a>,
     ) -> Self {
         Self {
-            paragraph_segments,
+            content_segments: content_segments.into_iter().map(Cow::Borrowed).collect(),
             underline_segment,
+            inlines: Vec::new(),
+            range: 0..0,
         }
-    } */
-
-    #[allow(dead_code)]
-    pub fn level(&self) -> u8 {
-        self.underline_segment.level()
     }
-}
 
-// TODO: maybe this try from is overkill? We could just do it outside.
-/* impl<'a> TryFrom<ParagraphSegmentsAndNext<'a>> for SetextHeading<'a> {
-    type Error = ParagraphSegmentsAndNext<'a>;
-
-    fn try_from(value: ParagraphSegmentsAndNext<'a>) -> Result<Self, Self::Error> {
-        let underline_segment = SetextHeadingUnderlineSegment::try_from(value.next_segment);
-        match underline_segment {
-            Ok(underline_segment) => Ok(Self::new(value.paragraph_segments, underline_segment)),
-            Err(_) => Err(value),
+    /// Detaches the heading from its source, by copying the strings it and its content borrow.
+    pub fn into_owned(self) -> SetextHeading<'static> {
+        SetextHeading {
+            content_segments: owned_all(self.content_segments),
+            underline_segment: self.underline_segment.into_owned(),
+            inlines: self.inlines.into_iter().map(Inline::into_owned).collect(),
+            range: self.range,
         }
     }
-}
-
-#[cfg(test)]
-mod test {
-    use super::*;
-
-    mod try_from {
-        use crate::internal::parse::try_extract::TryExtract;
-        use segment::{Segment, SegmentStrExt};
-
-        use super::*;
-
-        macro_rules! failure_case {
-            ($name:ident, $paragraph_segments:expr, $next_segment:expr) => {
-                #[test]
-                fn $name() {
-                    let extract = ParagraphSegments::try_extract($paragraph_segments).unwrap();
-                    let paragraph_segments = extract.extracted;
-                    assert!(extract.remaining.is_none());
-
-                    let conversion_params =
-                        ParagraphSegmentsAndNext::new(paragraph_segments, $next_segment);
-
-                    assert_eq!(
-                        SetextHeading::try_from(conversion_params.clone()),
-                        Err(conversion_params)
-                    );
-                }
-            };
-        }
-
-        macro_rules! success_case {
-            ($name:ident, $all_segments:expr, $expected_level:expr) => {
-                #[test]
-                fn $name() {
-                    let all_segments: Vec<_> = $all_segments.collect();
-                    let mut paragraph_segments: Vec<_> = all_segments.clone();
-                    let next_segment = paragraph_segments.pop().unwrap();
-                    let extract =
-                        ParagraphSegments::try_extract(paragraph_segments.into_iter()).unwrap();
-                    assert!(extract.remaining.is_none());
-                    let conversion_params =
-                        ParagraphSegmentsAndNext::new(extract.extracted.clone(), next_segment);
-                    let setext_heading = SetextHeading::try_from(conversion_params).unwrap();
 
-                    assert_eq!(setext_heading.level(), $expected_level);
-                    assert_eq!(
-                        setext_heading,
-                        SetextHeading::new(extract.extracted, next_segment.try_into().unwrap()),
-                    );
-                }
-            };
-        }
-
-        failure_case!(
-            should_reject_an_empty_segment,
-            "aaa\n".lines(),
-            LineSegment::new(Segment::empty_at(location::Position::new(5, 2, 4)))
-        );
-        failure_case!(
-            should_reject_a_whitespace_segment,
-            "aaa\n".lines(),
-            LineSegment::new(Segment::new(location::Position::new(5, 2, 4), " \n"))
-        );
-        failure_case!(
-            should_reject_an_underline_segment_with_other_characters,
-            "aaa\n".lines(),
-            LineSegment::new(Segment::new(location::Position::new(5, 2, 4), "===a\n"))
-        );
-
-        success_case!(should_work_with_equals_underline, "aaa\n===\n".lines(), 1);
-        success_case!(should_work_with_hyphens_underline, "aaa\n---\n".lines(), 2);
-        success_case!(
-            should_work_with_a_single_character_underline,
-            "aaa\n=\n".lines(),
-            1
-        );
-        success_case!(
-            should_work_with_many_characters_underline,
-            "aaa\n============\n".lines(),
-            1
-        );
-        success_case!(
-            should_work_with_3_spaces_before_the_underline,
-            "aaa\n   ===\n".lines(),
-            1
-        );
-        success_case!(
-            should_work_with_trailing_whitespaces_underline,
-            "aaa\n===  \n".lines(),
-            1
-        );
-        success_case!(
-            should_work_with_multiline_paragraph,
-            "aaa\n         hello this is a continuation line\n===\n".lines(),
-            1
-        );
+    /// The segments of the heading content, without the underline.
+    pub fn content_segments(&'a self) -> SliceSegments<'a> {
+        self.content_segments.as_slice().into()
     }
-} */
-
-/* #[cfg(test)]
-mod test {
-    use crate::parser::Parser;
 
-    use super::*;
+    /// The lines of the heading content, unless the heading is detached from its source.
+    pub(crate) fn content_lines(&self) -> Option<Vec<&'a str>> {
+        borrowed_all(&self.content_segments)
+    }
 
-    mod try_from {
-        macro_rules! failure_case {
-            ($name:ident, $paragraph_segments:expr, $next_segment:expr) => {
-                #[test]
-                fn $name() {
-                    let paragraph = Paragraph::from($paragraph_segments);
+    /// The level of the heading, 1 for `=` underlines and 2 for `-` underlines.
+    pub fn level(&self) -> u8 {
+        self.underline_segment.level()
+    }
 
-                    assert_eq!(
-                        SetextHeading::try_from((paragraph.clone(), $next_segment)),
-                        Err(paragraph)
-                    );
-                }
-            };
-        }
+    /// The inline content of the heading.
+    pub fn inlines(&self) -> &[Inline<'a>] {
+        &self.inlines
+    }
 
-        macro_rules! success_case {
-            ($name:ident, $all_segments:expr, $expected_level:expr) => {
-                #[test]
-                fn $name() {
-                    let all_segments: Vec<_> = $all_segments.collect();
-                    let mut paragraph_segments: Vec<_> = all_segments.clone();
-                    let setex_heading_segment = paragraph_segments.pop().unwrap();
-                    let paragraph = Paragraph::from(paragraph_segments);
-                    let setext_heading =
-                        SetextHeading::try_from((paragraph.clone(), setex_heading_segment))
-                            .unwrap();
+    pub(crate) fn inlines_mut(&mut self) -> &mut [Inline<'a>] {
+        &mut self.inlines
+    }
 
-                    assert_eq!(setext_heading.level, $expected_level);
-                    assert_eq!(
-                        setext_heading.segments, all_segments,
-                        "unexpected setex heading segments"
-                    );
-                }
-            };
-        }
+    pub(crate) fn set_inlines(&mut self, inlines: Vec<Inline<'a>>) {
+        self.inlines = inlines;
+    }
+}
 
-        failure_case!(
-            should_reject_an_empty_segment,
-            "aaa\n".line_segments(),
-            Segment::empty_at(location::Position::new(5, 2, 4))
-        );
-        failure_case!(
-            should_reject_a_whitespace_segment,
-            "aaa\n".line_segments(),
-            Segment::new(location::Position::new(5, 2, 4), " \n")
-        );
-        failure_case!(
-            should_reject_an_underline_segment_with_other_characters,
-            "aaa\n".line_segments(),
-            Segment::new(location::Position::new(5, 2, 4), "===a\n")
-        );
+impl<'a> Segments<'a> for SetextHeading<'a> {
+    type SegmentsIter = Chain<SliceSegments<'a>, Once<&'a str>>;
 
-        success_case!(
-            should_work_with_equals_underline,
-            "aaa\n===\n".line_segments(),
-            1
-        );
-        success_case!(
-            should_work_with_hyphens_underline,
-            "aaa\n---\n".line_segments(),
-            2
-        );
-        success_case!(
-            should_work_with_a_single_character_underline,
-            "aaa\n=\n".line_segments(),
-            1
-        );
-        success_case!(
-            should_work_with_many_characters_underline,
-            "aaa\n============\n".line_segments(),
-            1
-        );
-        success_case!(
-            should_work_with_3_spaces_before_the_underline,
-            "aaa\n   ===\n".line_segments(),
-            1
-        );
-        success_case!(
-            should_work_with_trailing_whitespaces_underline,
-            "aaa\n===  \n".line_segments(),
-            1
-        );
-        success_case!(
-            should_work_with_multiline_paragraph,
-            "aaa\n         hello this is a continuation line\n===\n".line_segments(),
-            1
-        );
+    fn segments(&'a self) -> Self::SegmentsIter {
+        self.content_segments()
+            .chain(iter::once(self.underline_segment.segment()))
     }
 }
- */
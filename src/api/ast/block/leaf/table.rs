@@ -0,0 +1,203 @@
+use crate::{Segment, Segments, ast::inline::Inline, borrowed, owned};
+use std::{
+    borrow::Cow,
+    iter::{self, Chain, Map, Once},
+    ops::Range,
+    slice,
+};
+
+/// A table, as described in the [GFM spec](https://github.github.com/gfm/#tables-extension-).
+///
+/// A table starts with a header row followed by a delimiter row with as many cells. The header
+/// row can be paragraph continuation text, in which case the table interrupts the paragraph. Tables
+/// are only parsed when the [tables](crate::ParseOptions::tables) extension is enabled.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Table<'a> {
+    header: TableRow<'a>,
+    delimiter_row: Cow<'a, str>,
+    alignments: Vec<Alignment>,
+    /// The body rows, which can have more or fewer cells than the header.
+    rows: Vec<TableRow<'a>>,
+    pub(crate) range: Range<usize>,
+}
+
+impl<'a> Table<'a> {
+    pub(crate) fn new(
+        header: TableRow<'a>,
+        delimiter_row: &'a str,
+        alignments: Vec<Alignment>,
+        rows: Vec<TableRow<'a>>,
+    ) -> Self {
+        Self {
+            header,
+            delimiter_row: Cow::Borrowed(delimiter_row),
+            alignments,
+            rows,
+            range: 0..0,
+        }
+    }
+
+    /// Detaches the table from its source, by copying the strings it and its content borrow.
+    pub fn into_owned(self) -> Table<'static> {
+        Table {
+            header: self.header.into_owned(),
+            delimiter_row: owned(self.delimiter_row),
+            alignments: self.alignments,
+            rows: self.rows.into_iter().map(TableRow::into_owned).collect(),
+            range: self.range,
+        }
+    }
+
+    /// The header row, which has one cell per column.
+    pub fn header(&self) -> &TableRow<'a> {
+        &self.header
+    }
+
+    /// The alignment of every column, as given by the delimiter row.
+    pub fn alignments(&self) -> &[Alignment] {
+        &self.alignments
+    }
+
+    /// The body rows of the table, if any.
+    ///
+    /// They are rendered with as many cells as there are columns: the missing cells are empty, and
+    /// the excess ones are ignored.
+    pub fn rows(&self) -> &[TableRow<'a>] {
+        &self.rows
+    }
+
+    /// The header row followed by the body rows.
+    pub(crate) fn all_rows_mut(&mut self) -> impl Iterator<Item = &mut TableRow<'a>> {
+        iter::once(&mut self.header).chain(&mut self.rows)
+    }
+}
+
+type TableRowsSegments<'a> = Map<slice::Iter<'a, TableRow<'a>>, fn(&'a TableRow<'a>) -> &'a str>;
+
+impl<'a> Segments<'a> for Table<'a> {
+    type SegmentsIter = Chain<Chain<Once<&'a str>, Once<&'a str>>, TableRowsSegments<'a>>;
+
+    fn segments(&'a self) -> Self::SegmentsIter {
+        let row_segment: fn(&'a TableRow<'a>) -> &'a str = TableRow::segment;
+        iter::once(self.header.segment())
+            .chain(iter::once(self.delimiter_row.as_ref()))
+            .chain(self.rows.iter().map(row_segment))
+    }
+}
+
+/// The alignment of a table column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Alignment {
+    /// The delimiter cell has no colon, as in `---`.
+    None,
+    /// The delimiter cell starts with a colon, as in `:--`.
+    Left,
+    /// The delimiter cell starts and ends with a colon, as in `:-:`.
+    Center,
+    /// The delimiter cell ends with a colon, as in `--:`.
+    Right,
+}
+
+/// A row of a [Table], which is a single line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "kind", rename = "table_row"))]
+pub struct TableRow<'a> {
+    segment: Cow<'a, str>,
+    cells: Vec<TableCell<'a>>,
+    pub(crate) range: Range<usize>,
+}
+
+impl<'a> TableRow<'a> {
+    pub(crate) fn new(segment: &'a str, cells: Vec<TableCell<'a>>) -> Self {
+        Self {
+            segment: Cow::Borrowed(segment),
+            cells,
+            range: 0..0,
+        }
+    }
+
+    /// Detaches the row from its source, by copying the strings it and its content borrow.
+    pub fn into_owned(self) -> TableRow<'static> {
+        TableRow {
+            segment: owned(self.segment),
+            cells: self.cells.into_iter().map(TableCell::into_owned).collect(),
+            range: self.range,
+        }
+    }
+
+    /// The cells as they are written in the row.
+    pub fn cells(&self) -> &[TableCell<'a>] {
+        &self.cells
+    }
+
+    pub(crate) fn cells_mut(&mut self) -> &mut [TableCell<'a>] {
+        &mut self.cells
+    }
+}
+
+impl Segment for TableRow<'_> {
+    /// The whole line of the row, line ending included.
+    fn segment(&self) -> &str {
+        &self.segment
+    }
+}
+
+/// A cell of a [TableRow].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "kind", rename = "table_cell"))]
+pub struct TableCell<'a> {
+    segment: Cow<'a, str>,
+    /// The inline content, parsed once all the blocks of the document are known.
+    inlines: Vec<Inline<'a>>,
+    pub(crate) range: Range<usize>,
+}
+
+impl<'a> TableCell<'a> {
+    pub(crate) fn new(segment: &'a str) -> Self {
+        Self {
+            segment: Cow::Borrowed(segment),
+            inlines: Vec::new(),
+            range: 0..0,
+        }
+    }
+
+    /// Detaches the cell from its source, by copying the strings it and its content borrow.
+    pub fn into_owned(self) -> TableCell<'static> {
+        TableCell {
+            segment: owned(self.segment),
+            inlines: self.inlines.into_iter().map(Inline::into_owned).collect(),
+            range: self.range,
+        }
+    }
+
+    /// The content of the cell, as the single line of inline content, unless the cell is detached
+    /// from its source.
+    pub(crate) fn content_line(&self) -> Option<&'a str> {
+        borrowed(&self.segment)
+    }
+
+    /// The inline content of the cell.
+    pub fn inlines(&self) -> &[Inline<'a>] {
+        &self.inlines
+    }
+
+    pub(crate) fn inlines_mut(&mut self) -> &mut [Inline<'a>] {
+        &mut self.inlines
+    }
+
+    pub(crate) fn set_inlines(&mut self, inlines: Vec<Inline<'a>>) {
+        self.inlines = inlines;
+    }
+}
+
+impl Segment for TableCell<'_> {
+    /// The content of the cell, without the surrounding pipes and whitespace.
+    fn segment(&self) -> &str {
+        &self.segment
+    }
+}
@@ -1,16 +1,32 @@
-use crate::Segment;
+use crate::{Segment, owned};
+use std::{borrow::Cow, ops::Range};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct ThematicBreak<'a>(&'a str);
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ThematicBreak<'a> {
+    segment: Cow<'a, str>,
+    pub(crate) range: Range<usize>,
+}
 
 impl<'a> ThematicBreak<'a> {
     pub(crate) fn new(segment: &'a str) -> Self {
-        Self(segment)
+        Self {
+            segment: Cow::Borrowed(segment),
+            range: 0..0,
+        }
+    }
+
+    /// Detaches the thematic break from its source, by copying the strings it borrows.
+    pub fn into_owned(self) -> ThematicBreak<'static> {
+        ThematicBreak {
+            segment: owned(self.segment),
+            range: self.range,
+        }
     }
 }
 
-impl<'a> Segment<'a> for ThematicBreak<'a> {
-    fn segment(&self) -> &'a str {
-        self.0
+impl Segment for ThematicBreak<'_> {
+    fn segment(&self) -> &str {
+        &self.segment
     }
 }
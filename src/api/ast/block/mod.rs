@@ -1,16 +1,85 @@
 mod container;
 mod leaf;
 
+pub use container::*;
 pub use leaf::*;
 
 use crate::Segments;
-use std::iter::FusedIterator;
+use std::{fmt, iter::FusedIterator};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(untagged))]
 pub enum Block<'a> {
+    Container(Container<'a>),
     Leaf(Leaf<'a>),
 }
 
+impl Block<'_> {
+    /// Detaches the block from its source, by copying the strings it and its content borrow.
+    pub fn into_owned(self) -> Block<'static> {
+        match self {
+            Block::Container(container) => Block::Container(container.into_owned()),
+            Block::Leaf(leaf) => Block::Leaf(leaf.into_owned()),
+        }
+    }
+
+    /// Returns the kind of the block, which tells it apart without matching on the nested enums.
+    pub fn kind(&self) -> BlockKind {
+        match self {
+            Block::Container(Container::BlockQuote(_)) => BlockKind::BlockQuote,
+            Block::Container(Container::List(_)) => BlockKind::List,
+            Block::Leaf(Leaf::AtxHeading(_)) => BlockKind::AtxHeading,
+            Block::Leaf(Leaf::BlankLine(_)) => BlockKind::BlankLine,
+            Block::Leaf(Leaf::FencedCode(_)) => BlockKind::FencedCode,
+            Block::Leaf(Leaf::Html(_)) => BlockKind::Html,
+            Block::Leaf(Leaf::IndentedCode(_)) => BlockKind::IndentedCode,
+            Block::Leaf(Leaf::LinkReferenceDefinition(_)) => BlockKind::LinkReferenceDefinition,
+            Block::Leaf(Leaf::Paragraph(_)) => BlockKind::Paragraph,
+            Block::Leaf(Leaf::SetextHeading(_)) => BlockKind::SetextHeading,
+            Block::Leaf(Leaf::Table(_)) => BlockKind::Table,
+            Block::Leaf(Leaf::ThematicBreak(_)) => BlockKind::ThematicBreak,
+        }
+    }
+}
+
+/// The kind of a [Block], without its content.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BlockKind {
+    AtxHeading,
+    BlankLine,
+    BlockQuote,
+    FencedCode,
+    Html,
+    IndentedCode,
+    LinkReferenceDefinition,
+    List,
+    Paragraph,
+    SetextHeading,
+    Table,
+    ThematicBreak,
+}
+
+impl fmt::Display for BlockKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            BlockKind::AtxHeading => "ATX heading",
+            BlockKind::BlankLine => "blank line",
+            BlockKind::BlockQuote => "block quote",
+            BlockKind::FencedCode => "fenced code block",
+            BlockKind::Html => "HTML block",
+            BlockKind::IndentedCode => "indented code block",
+            BlockKind::LinkReferenceDefinition => "link reference definition",
+            BlockKind::List => "list",
+            BlockKind::Paragraph => "paragraph",
+            BlockKind::SetextHeading => "setext heading",
+            BlockKind::Table => "table",
+            BlockKind::ThematicBreak => "thematic break",
+        };
+        f.write_str(name)
+    }
+}
+
 impl<'a> Segments<'a> for Block<'a> {
     type SegmentsIter = BlockSegmentsIterator<'a>;
 
@@ -33,6 +102,7 @@ impl<'a> BlockSegmentsIterator<'a> {
 impl<'a> From<&'a Block<'a>> for BlockSegmentsIterator<'a> {
     fn from(block: &'a Block) -> Self {
         match block {
+            Block::Container(container) => Self::new(Box::new(container.segments())),
             Block::Leaf(leaf) => Self::new(Box::new(leaf.segments())),
         }
     }
@@ -0,0 +1,104 @@
+use crate::{Segment, owned, parse::unescape};
+use std::{borrow::Cow, ops::Range};
+
+/// An autolink, as described in the [spec](https://spec.commonmark.org/0.31.2/#autolinks).
+///
+/// The segment includes the angle brackets, unless the autolink is an
+/// [extended autolink](https://github.github.com/gfm/#autolinks-extension-) found in the text. Autolinks
+/// never span multiple lines.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Autolink<'a> {
+    segment: Cow<'a, str>,
+    #[cfg_attr(feature = "serde", serde(rename = "autolink_kind"))]
+    kind: AutolinkKind,
+    #[cfg_attr(feature = "serde", serde(default))]
+    extended: bool,
+    pub(crate) range: Range<usize>,
+}
+
+impl<'a> Autolink<'a> {
+    pub(crate) fn new(segment: &'a str, kind: AutolinkKind) -> Self {
+        Self {
+            segment: Cow::Borrowed(segment),
+            kind,
+            extended: false,
+            range: 0..0,
+        }
+    }
+
+    pub(crate) fn extended(segment: &'a str, kind: AutolinkKind) -> Self {
+        Self {
+            extended: true,
+            ..Self::new(segment, kind)
+        }
+    }
+
+    /// Detaches the autolink from its source, by copying the strings it borrows.
+    pub fn into_owned(self) -> Autolink<'static> {
+        Autolink {
+            segment: owned(self.segment),
+            kind: self.kind,
+            extended: self.extended,
+            range: self.range,
+        }
+    }
+
+    pub fn kind(&self) -> AutolinkKind {
+        self.kind
+    }
+
+    /// Whether the autolink is an extended autolink, recognized without angle brackets.
+    pub fn is_extended(&self) -> bool {
+        self.extended
+    }
+
+    /// The absolute URI or the email address, as written, without the angle brackets.
+    pub fn url(&self) -> &str {
+        if self.extended {
+            &self.segment
+        } else {
+            &self.segment[1..self.segment.len() - 1]
+        }
+    }
+
+    /// The text of the link: the URL, with its backslash escapes and character references decoded
+    /// when it is an extended autolink, which is found within the text.
+    pub fn text(&self) -> Cow<'_, str> {
+        if self.extended {
+            unescape(self.url())
+        } else {
+            Cow::Borrowed(self.url())
+        }
+    }
+
+    /// The destination of the link: the text, with `mailto:` prepended to email addresses and
+    /// `http://` to the extended autolinks starting with `www.`.
+    pub fn destination(&self) -> Cow<'_, str> {
+        let text = self.text();
+        match self.kind {
+            AutolinkKind::Email => Cow::Owned(format!("mailto:{text}")),
+            AutolinkKind::Uri if self.extended && text.starts_with("www.") => {
+                Cow::Owned(format!("http://{text}"))
+            }
+            AutolinkKind::Uri => text,
+        }
+    }
+}
+
+impl Segment for Autolink<'_> {
+    fn segment(&self) -> &str {
+        &self.segment
+    }
+}
+
+/// What an autolink points to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum AutolinkKind {
+    /// An [absolute URI](https://spec.commonmark.org/0.31.2/#absolute-uri).
+    Uri,
+    /// An [email address](https://spec.commonmark.org/0.31.2/#email-address), linked with `mailto:`.
+    Email,
+}
@@ -0,0 +1,144 @@
+use super::InlineSegmentsIterator;
+use crate::{Segments, SliceSegments, borrowed_all, owned, owned_all};
+use std::{borrow::Cow, iter, ops::Range};
+
+/// A code span, as described in the [spec](https://spec.commonmark.org/0.31.2/#code-spans).
+///
+/// The content is delimited by backtick strings of the same length. It can span multiple lines.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Code<'a> {
+    opening_backticks: Cow<'a, str>,
+    content: Vec<Cow<'a, str>>,
+    closing_backticks: Cow<'a, str>,
+    pub(crate) range: Range<usize>,
+}
+
+impl<'a> Code<'a> {
+    pub(crate) fn new(
+        opening_backticks: &'a str,
+        content: Vec<&'a str>,
+        closing_backticks: &'a str,
+    ) -> Self {
+        Self {
+            opening_backticks: Cow::Borrowed(opening_backticks),
+            content: content.into_iter().map(Cow::Borrowed).collect(),
+            closing_backticks: Cow::Borrowed(closing_backticks),
+            range: 0..0,
+        }
+    }
+
+    /// Detaches the code span from its source, by copying the strings it borrows.
+    pub fn into_owned(self) -> Code<'static> {
+        Code {
+            opening_backticks: owned(self.opening_backticks),
+            content: owned_all(self.content),
+            closing_backticks: owned(self.closing_backticks),
+            range: self.range,
+        }
+    }
+
+    /// The raw content between the backtick strings, one segment per line, line endings included.
+    ///
+    /// In table cells, the segments are also split around the backslashes escaping pipes, which
+    /// are left out.
+    pub fn content_segments(&'a self) -> SliceSegments<'a> {
+        self.content.as_slice().into()
+    }
+
+    /// Leaves out the backslashes escaping pipes from the content, as in table cells.
+    ///
+    /// The content of a code span detached from its source is left as it is.
+    pub(crate) fn unescape_pipes(&mut self) {
+        let Some(content) = borrowed_all(&self.content) else {
+            return;
+        };
+        self.content = content
+            .into_iter()
+            .flat_map(|segment| {
+                let mut pieces = vec![];
+                let mut start = 0;
+                while let Some(index) = segment[start..].find("\\|") {
+                    pieces.push(Cow::Borrowed(&segment[start..start + index]));
+                    start += index + 1;
+                }
+                pieces.push(Cow::Borrowed(&segment[start..]));
+                pieces
+            })
+            .collect();
+    }
+
+    /// Returns the content as it is rendered.
+    ///
+    /// The line endings are converted to spaces, then a single space is stripped from both sides
+    /// if the content both begins and ends with one, unless it is made of spaces only.
+    pub fn content(&self) -> String {
+        let content: String = self
+            .content
+            .iter()
+            .flat_map(|segment| {
+                let (line, line_ending) = match segment.strip_suffix('\n') {
+                    Some(line) => (line.strip_suffix('\r').unwrap_or(line), Some(" ")),
+                    None => (segment.as_ref(), None),
+                };
+                iter::once(line).chain(line_ending)
+            })
+            .collect();
+        match content.strip_prefix(' ').and_then(|c| c.strip_suffix(' ')) {
+            Some(stripped) if !content.chars().all(|c| c == ' ') => stripped.to_string(),
+            _ => content,
+        }
+    }
+}
+
+impl<'a> Segments<'a> for Code<'a> {
+    type SegmentsIter = InlineSegmentsIterator<'a>;
+
+    fn segments(&'a self) -> Self::SegmentsIter {
+        InlineSegmentsIterator::new(Box::new(
+            iter::once(self.opening_backticks.as_ref())
+                .chain(self.content_segments())
+                .chain(iter::once(self.closing_backticks.as_ref())),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod content {
+        use super::*;
+
+        #[test]
+        fn should_convert_line_endings_to_spaces() {
+            assert_eq!(
+                Code::new("``", vec!["foo\n", "bar  \r\n", "baz"], "``").content(),
+                "foo bar   baz"
+            );
+        }
+
+        #[test]
+        fn should_keep_carriage_returns_before_the_line_ending() {
+            assert_eq!(
+                Code::new("`", vec!["a\r\r\n", "b\r"], "`").content(),
+                "a\r b\r"
+            );
+        }
+
+        #[test]
+        fn should_strip_one_space_on_both_sides() {
+            assert_eq!(Code::new("`", vec!["  a  "], "`").content(), " a ");
+        }
+
+        #[test]
+        fn should_not_strip_one_side_only() {
+            assert_eq!(Code::new("`", vec![" a"], "`").content(), " a");
+        }
+
+        #[test]
+        fn should_not_strip_only_spaces() {
+            assert_eq!(Code::new("`", vec!["  "], "`").content(), "  ");
+        }
+    }
+}
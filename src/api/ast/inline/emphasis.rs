@@ -0,0 +1,66 @@
+use super::{Inline, InlineSegmentsIterator};
+use crate::{Segments, owned};
+use std::{borrow::Cow, iter, ops::Range};
+
+/// Emphasis, as described in the [spec](https://spec.commonmark.org/0.31.2/#emphasis-and-strong-emphasis).
+///
+/// The emphasis is delimited by a single `*` or `_` on each side.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Emphasis<'a> {
+    opening_delimiter: Cow<'a, str>,
+    children: Vec<Inline<'a>>,
+    closing_delimiter: Cow<'a, str>,
+    pub(crate) range: Range<usize>,
+}
+
+impl<'a> Emphasis<'a> {
+    pub(crate) fn new(
+        opening_delimiter: &'a str,
+        children: Vec<Inline<'a>>,
+        closing_delimiter: &'a str,
+    ) -> Self {
+        Self {
+            opening_delimiter: Cow::Borrowed(opening_delimiter),
+            children,
+            closing_delimiter: Cow::Borrowed(closing_delimiter),
+            range: 0..0,
+        }
+    }
+
+    /// Detaches the emphasis from its source, by copying the strings it and its content borrow.
+    pub fn into_owned(self) -> Emphasis<'static> {
+        Emphasis {
+            opening_delimiter: owned(self.opening_delimiter),
+            children: self.children.into_iter().map(Inline::into_owned).collect(),
+            closing_delimiter: owned(self.closing_delimiter),
+            range: self.range,
+        }
+    }
+
+    /// The character of the delimiters, either `*` or `_`.
+    pub fn delimiter(&self) -> char {
+        self.opening_delimiter.chars().next().unwrap()
+    }
+
+    /// The emphasized inlines.
+    pub fn children(&self) -> &[Inline<'a>] {
+        &self.children
+    }
+
+    pub(crate) fn children_mut(&mut self) -> &mut [Inline<'a>] {
+        &mut self.children
+    }
+}
+
+impl<'a> Segments<'a> for Emphasis<'a> {
+    type SegmentsIter = InlineSegmentsIterator<'a>;
+
+    fn segments(&'a self) -> Self::SegmentsIter {
+        InlineSegmentsIterator::new(Box::new(
+            iter::once(self.opening_delimiter.as_ref())
+                .chain(self.children.iter().flat_map(Inline::segments))
+                .chain(iter::once(self.closing_delimiter.as_ref())),
+        ))
+    }
+}
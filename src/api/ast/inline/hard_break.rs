@@ -0,0 +1,36 @@
+use crate::{Segment, owned};
+use std::{borrow::Cow, ops::Range};
+
+/// A hard line break, as described in the [spec](https://spec.commonmark.org/0.31.2/#hard-line-breaks).
+///
+/// The segment is the line ending, preceded by either the trailing spaces of the line, two or more,
+/// or a backslash.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HardBreak<'a> {
+    segment: Cow<'a, str>,
+    pub(crate) range: Range<usize>,
+}
+
+impl<'a> HardBreak<'a> {
+    pub(crate) fn new(segment: &'a str) -> Self {
+        Self {
+            segment: Cow::Borrowed(segment),
+            range: 0..0,
+        }
+    }
+
+    /// Detaches the line break from its source, by copying the strings it borrows.
+    pub fn into_owned(self) -> HardBreak<'static> {
+        HardBreak {
+            segment: owned(self.segment),
+            range: self.range,
+        }
+    }
+}
+
+impl Segment for HardBreak<'_> {
+    fn segment(&self) -> &str {
+        &self.segment
+    }
+}
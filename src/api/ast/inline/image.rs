@@ -0,0 +1,96 @@
+use super::{
+    Inline, InlineSegmentsIterator,
+    link::{LinkDestination, LinkKind, LinkTitle},
+};
+use crate::{Segments, owned, owned_all};
+use std::{borrow::Cow, iter, ops::Range};
+
+/// An image, as described in the [spec](https://spec.commonmark.org/0.31.2/#images).
+///
+/// Images are parsed like links, only the description is preceded by a `!`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Image<'a> {
+    opening_bracket: Cow<'a, str>,
+    children: Vec<Inline<'a>>,
+    closing: Vec<Cow<'a, str>>,
+    #[cfg_attr(feature = "serde", serde(rename = "link_kind"))]
+    kind: LinkKind,
+    destination: Option<LinkDestination<'a>>,
+    title: Option<LinkTitle<'a>>,
+    pub(crate) range: Range<usize>,
+}
+
+impl<'a> Image<'a> {
+    pub(crate) fn new(
+        opening_bracket: &'a str,
+        children: Vec<Inline<'a>>,
+        closing: Vec<&'a str>,
+        kind: LinkKind,
+        destination: Option<LinkDestination<'a>>,
+        title: Option<LinkTitle<'a>>,
+    ) -> Self {
+        Self {
+            opening_bracket: Cow::Borrowed(opening_bracket),
+            children,
+            closing: closing.into_iter().map(Cow::Borrowed).collect(),
+            kind,
+            destination,
+            title,
+            range: 0..0,
+        }
+    }
+
+    /// Detaches the image from its source, by copying the strings it and its content borrow.
+    pub fn into_owned(self) -> Image<'static> {
+        Image {
+            opening_bracket: owned(self.opening_bracket),
+            children: self.children.into_iter().map(Inline::into_owned).collect(),
+            closing: owned_all(self.closing),
+            kind: self.kind,
+            destination: self.destination.map(LinkDestination::into_owned),
+            title: self.title.map(LinkTitle::into_owned),
+            range: self.range,
+        }
+    }
+
+    /// The inlines of the image description, rendered as plain text in the `alt` attribute.
+    pub fn children(&self) -> &[Inline<'a>] {
+        &self.children
+    }
+
+    pub(crate) fn children_mut(&mut self) -> &mut [Inline<'a>] {
+        &mut self.children
+    }
+
+    pub fn kind(&self) -> LinkKind {
+        self.kind
+    }
+
+    pub fn destination(&self) -> Option<&LinkDestination<'a>> {
+        self.destination.as_ref()
+    }
+
+    pub fn title(&self) -> Option<&LinkTitle<'a>> {
+        self.title.as_ref()
+    }
+
+    /// Replaces the destination the image is rendered with.
+    ///
+    /// The segments are left untouched, so they still hold the original destination, if any.
+    pub fn set_destination(&mut self, destination: Option<LinkDestination<'a>>) {
+        self.destination = destination;
+    }
+}
+
+impl<'a> Segments<'a> for Image<'a> {
+    type SegmentsIter = InlineSegmentsIterator<'a>;
+
+    fn segments(&'a self) -> Self::SegmentsIter {
+        InlineSegmentsIterator::new(Box::new(
+            iter::once(self.opening_bracket.as_ref())
+                .chain(self.children.iter().flat_map(Inline::segments))
+                .chain(self.closing.iter().map(AsRef::as_ref)),
+        ))
+    }
+}
@@ -1,17 +1,24 @@
-use crate::api::Segment;
+use crate::api::{Segment, owned};
+use std::borrow::Cow;
 
 /// The bracketed variant of the link destination.
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct BracketedLinkDestination<'a>(&'a str);
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BracketedLinkDestination<'a>(Cow<'a, str>);
 
 impl<'a> BracketedLinkDestination<'a> {
     pub(crate) fn new(segment: &'a str) -> Self {
-        Self(segment)
+        Self(Cow::Borrowed(segment))
+    }
+
+    /// Detaches the destination from its source, by copying the strings it borrows.
+    pub fn into_owned(self) -> BracketedLinkDestination<'static> {
+        BracketedLinkDestination(owned(self.0))
     }
 }
 
-impl<'a> Segment<'a> for BracketedLinkDestination<'a> {
-    fn segment(&self) -> &'a str {
-        self.0
+impl Segment for BracketedLinkDestination<'_> {
+    fn segment(&self) -> &str {
+        &self.0
     }
 }
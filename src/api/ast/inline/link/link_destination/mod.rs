@@ -1,17 +1,49 @@
 mod bracketed;
 mod unbracketed;
 
-use crate::api::Segment;
+use crate::{api::Segment, parse::unescape};
 pub use bracketed::*;
+use std::borrow::Cow;
 pub use unbracketed::*;
 
 /// A link destination, as described in the [spec](https://spec.commonmark.org/0.31.2/#link-destination).
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(tag = "kind", content = "value", rename_all = "snake_case")
+)]
 pub enum LinkDestination<'a> {
     Bracketed(BracketedLinkDestination<'a>),
     Unbracketed(UnbracketedLinkDestination<'a>),
 }
 
+impl<'a> LinkDestination<'a> {
+    /// Returns the URL, without the angle brackets of the bracketed variant, and with the backslash
+    /// escapes and character references decoded.
+    pub fn url(&self) -> Cow<'_, str> {
+        match self {
+            Self::Bracketed(destination) => {
+                let segment = destination.segment();
+                unescape(&segment[1..segment.len() - 1])
+            }
+            Self::Unbracketed(destination) => unescape(destination.segment()),
+        }
+    }
+}
+
+impl LinkDestination<'_> {
+    /// Detaches the destination from its source, by copying the strings it borrows.
+    pub fn into_owned(self) -> LinkDestination<'static> {
+        match self {
+            Self::Bracketed(destination) => LinkDestination::Bracketed(destination.into_owned()),
+            Self::Unbracketed(destination) => {
+                LinkDestination::Unbracketed(destination.into_owned())
+            }
+        }
+    }
+}
+
 impl<'a> From<BracketedLinkDestination<'a>> for LinkDestination<'a> {
     fn from(segment: BracketedLinkDestination<'a>) -> Self {
         LinkDestination::Bracketed(segment)
@@ -24,8 +56,8 @@ impl<'a> From<UnbracketedLinkDestination<'a>> for LinkDestination<'a> {
     }
 }
 
-impl<'a> Segment<'a> for LinkDestination<'a> {
-    fn segment(&self) -> &'a str {
+impl Segment for LinkDestination<'_> {
+    fn segment(&self) -> &str {
         match self {
             Self::Bracketed(segment) => segment.segment(),
             Self::Unbracketed(segment) => segment.segment(),
@@ -1,17 +1,24 @@
-use crate::api::Segment;
+use crate::api::{Segment, owned};
+use std::borrow::Cow;
 
 /// The unbracketed variant of the link destination.
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct UnbracketedLinkDestination<'a>(&'a str);
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UnbracketedLinkDestination<'a>(Cow<'a, str>);
 
 impl<'a> UnbracketedLinkDestination<'a> {
     pub(crate) fn new(segment: &'a str) -> Self {
-        Self(segment)
+        Self(Cow::Borrowed(segment))
+    }
+
+    /// Detaches the destination from its source, by copying the strings it borrows.
+    pub fn into_owned(self) -> UnbracketedLinkDestination<'static> {
+        UnbracketedLinkDestination(owned(self.0))
     }
 }
 
-impl<'a> Segment<'a> for UnbracketedLinkDestination<'a> {
-    fn segment(&self) -> &'a str {
-        self.0
+impl Segment for UnbracketedLinkDestination<'_> {
+    fn segment(&self) -> &str {
+        &self.0
     }
 }
@@ -1,18 +1,74 @@
-use crate::api::traits::Segment;
+use crate::api::{owned, traits::Segment};
+use std::borrow::Cow;
 
 /// A link label, as described in the [spec][https://spec.commonmark.org/0.31.2/#link-label].
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct LinkLabel<'a>(&'a str);
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LinkLabel<'a>(Cow<'a, str>);
 
 impl<'a> LinkLabel<'a> {
     /// Creates a new `LinkLabel` from a `LinkLabelSegment`.
     pub(crate) fn new(segment: &'a str) -> Self {
-        Self(segment)
+        Self(Cow::Borrowed(segment))
+    }
+
+    /// Creates a new `LinkLabel` spanning several lines, from the concatenation of those.
+    pub(crate) fn joined(label: String) -> Self {
+        Self(Cow::Owned(label))
+    }
+
+    /// Detaches the label from its source, by copying the strings it borrows.
+    pub fn into_owned(self) -> LinkLabel<'static> {
+        LinkLabel(owned(self.0))
+    }
+
+    /// Returns the normalized form of the label, used to match link references with their definitions.
+    ///
+    /// The brackets are stripped, the surrounding whitespace is trimmed, consecutive internal whitespace
+    /// is collapsed into a single space and the Unicode case folding is applied.
+    pub fn normalized(&self) -> String {
+        let content = &self.0[1..self.0.len() - 1];
+        content
+            .split([' ', '\t', '\r', '\n'])
+            .filter(|word| !word.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ")
+            // Lowercasing then uppercasing is how folding is approximated, so that "ẞ" matches "SS".
+            .to_lowercase()
+            .to_uppercase()
     }
 }
 
-impl<'a> Segment<'a> for LinkLabel<'a> {
-    fn segment(&self) -> &'a str {
-        self.0
+impl Segment for LinkLabel<'_> {
+    fn segment(&self) -> &str {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod normalized {
+        use super::*;
+
+        #[test]
+        fn should_strip_brackets() {
+            assert_eq!(LinkLabel::new("[FOO]").normalized(), "FOO");
+        }
+
+        #[test]
+        fn should_case_fold() {
+            assert_eq!(LinkLabel::new("[Foo bar]").normalized(), "FOO BAR");
+            assert_eq!(
+                LinkLabel::new("[ẞ]").normalized(),
+                LinkLabel::new("[SS]").normalized()
+            );
+        }
+
+        #[test]
+        fn should_collapse_whitespace() {
+            assert_eq!(LinkLabel::new("[ foo \t\n bar ]").normalized(), "FOO BAR");
+        }
     }
 }
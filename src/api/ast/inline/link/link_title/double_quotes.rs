@@ -8,11 +8,28 @@ use std::iter::FusedIterator;
 
 /// The double quotes variant of a link title.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(tag = "kind", content = "value", rename_all = "snake_case")
+)]
 pub enum DoubleQuotesLinkTitle<'a> {
     Single(DoubleQuotesLinkTitleSingleSegment<'a>),
     Multi(DoubleQuotesLinkTitleMultiSegments<'a>),
 }
 
+impl DoubleQuotesLinkTitle<'_> {
+    /// Detaches the title from its source, by copying the strings it borrows.
+    pub fn into_owned(self) -> DoubleQuotesLinkTitle<'static> {
+        match self {
+            DoubleQuotesLinkTitle::Single(single) => {
+                DoubleQuotesLinkTitle::Single(single.into_owned())
+            }
+            DoubleQuotesLinkTitle::Multi(multi) => DoubleQuotesLinkTitle::Multi(multi.into_owned()),
+        }
+    }
+}
+
 impl<'a> From<DoubleQuotesLinkTitleSingleSegment<'a>> for DoubleQuotesLinkTitle<'a> {
     fn from(title: DoubleQuotesLinkTitleSingleSegment<'a>) -> Self {
         DoubleQuotesLinkTitle::Single(title)
@@ -2,18 +2,43 @@ mod double_quotes;
 mod parentheses;
 mod single_quotes;
 
+use crate::{Segments, parse::unescape};
 pub use double_quotes::*;
 pub use parentheses::*;
 pub use single_quotes::*;
+use std::iter::FusedIterator;
 
 /// A link title, as described in the [spec](https://spec.commonmark.org/0.31.2/#link-title).
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(tag = "kind", content = "value", rename_all = "snake_case")
+)]
 pub enum LinkTitle<'a> {
     DoubleQuotes(DoubleQuotesLinkTitle<'a>),
     Parentheses(ParenthesesLinkTitle<'a>),
     SingleQuotes(SingleQuotesLinkTitle<'a>),
 }
 
+impl<'a> LinkTitle<'a> {
+    /// Returns the text of the title, without its delimiters, and with the backslash escapes and
+    /// character references decoded.
+    pub fn content(&'a self) -> String {
+        let title: String = self.segments().collect();
+        unescape(&title[1..title.len() - 1]).into_owned()
+    }
+
+    /// Detaches the title from its source, by copying the strings it borrows.
+    pub fn into_owned(self) -> LinkTitle<'static> {
+        match self {
+            LinkTitle::DoubleQuotes(title) => LinkTitle::DoubleQuotes(title.into_owned()),
+            LinkTitle::Parentheses(title) => LinkTitle::Parentheses(title.into_owned()),
+            LinkTitle::SingleQuotes(title) => LinkTitle::SingleQuotes(title.into_owned()),
+        }
+    }
+}
+
 impl<'a> From<DoubleQuotesLinkTitle<'a>> for LinkTitle<'a> {
     fn from(title: DoubleQuotesLinkTitle<'a>) -> Self {
         LinkTitle::DoubleQuotes(title)
@@ -32,4 +57,40 @@ impl<'a> From<ParenthesesLinkTitle<'a>> for LinkTitle<'a> {
     }
 }
 
-// TODO: implement segments
+impl<'a> Segments<'a> for LinkTitle<'a> {
+    type SegmentsIter = LinkTitleSegmentsIter<'a>;
+
+    fn segments(&'a self) -> Self::SegmentsIter {
+        self.into()
+    }
+}
+
+pub enum LinkTitleSegmentsIter<'a> {
+    DoubleQuotes(<DoubleQuotesLinkTitle<'a> as Segments<'a>>::SegmentsIter),
+    Parentheses(<ParenthesesLinkTitle<'a> as Segments<'a>>::SegmentsIter),
+    SingleQuotes(<SingleQuotesLinkTitle<'a> as Segments<'a>>::SegmentsIter),
+}
+
+impl<'a> From<&'a LinkTitle<'a>> for LinkTitleSegmentsIter<'a> {
+    fn from(title: &'a LinkTitle<'a>) -> Self {
+        match title {
+            LinkTitle::DoubleQuotes(title) => LinkTitleSegmentsIter::DoubleQuotes(title.segments()),
+            LinkTitle::Parentheses(title) => LinkTitleSegmentsIter::Parentheses(title.segments()),
+            LinkTitle::SingleQuotes(title) => LinkTitleSegmentsIter::SingleQuotes(title.segments()),
+        }
+    }
+}
+
+impl FusedIterator for LinkTitleSegmentsIter<'_> {}
+
+impl<'a> Iterator for LinkTitleSegmentsIter<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            LinkTitleSegmentsIter::DoubleQuotes(iter) => iter.next(),
+            LinkTitleSegmentsIter::Parentheses(iter) => iter.next(),
+            LinkTitleSegmentsIter::SingleQuotes(iter) => iter.next(),
+        }
+    }
+}
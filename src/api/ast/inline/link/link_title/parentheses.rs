@@ -8,11 +8,28 @@ use std::iter::FusedIterator;
 
 /// The parentheses variant of a link title.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(tag = "kind", content = "value", rename_all = "snake_case")
+)]
 pub enum ParenthesesLinkTitle<'a> {
     Single(ParenthesesLinkTitleSingleSegment<'a>),
     Multi(ParenthesesLinkTitleMultiSegments<'a>),
 }
 
+impl ParenthesesLinkTitle<'_> {
+    /// Detaches the title from its source, by copying the strings it borrows.
+    pub fn into_owned(self) -> ParenthesesLinkTitle<'static> {
+        match self {
+            ParenthesesLinkTitle::Single(single) => {
+                ParenthesesLinkTitle::Single(single.into_owned())
+            }
+            ParenthesesLinkTitle::Multi(multi) => ParenthesesLinkTitle::Multi(multi.into_owned()),
+        }
+    }
+}
+
 impl<'a> From<ParenthesesLinkTitleSingleSegment<'a>> for ParenthesesLinkTitle<'a> {
     fn from(title: ParenthesesLinkTitleSingleSegment<'a>) -> Self {
         ParenthesesLinkTitle::Single(title)
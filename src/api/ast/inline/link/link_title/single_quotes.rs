@@ -8,11 +8,28 @@ use std::iter::FusedIterator;
 
 /// The single quotes variant of a link title.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(tag = "kind", content = "value", rename_all = "snake_case")
+)]
 pub enum SingleQuotesLinkTitle<'a> {
     Single(SingleQuotesLinkTitleSingleSegment<'a>),
     Multi(SingleQuotesLinkTitleMultiSegments<'a>),
 }
 
+impl SingleQuotesLinkTitle<'_> {
+    /// Detaches the title from its source, by copying the strings it borrows.
+    pub fn into_owned(self) -> SingleQuotesLinkTitle<'static> {
+        match self {
+            SingleQuotesLinkTitle::Single(single) => {
+                SingleQuotesLinkTitle::Single(single.into_owned())
+            }
+            SingleQuotesLinkTitle::Multi(multi) => SingleQuotesLinkTitle::Multi(multi.into_owned()),
+        }
+    }
+}
+
 impl<'a> From<SingleQuotesLinkTitleSingleSegment<'a>> for SingleQuotesLinkTitle<'a> {
     fn from(title: SingleQuotesLinkTitleSingleSegment<'a>) -> Self {
         SingleQuotesLinkTitle::Single(title)
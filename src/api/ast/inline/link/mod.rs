@@ -2,6 +2,115 @@ mod link_destination;
 mod link_label;
 mod link_title;
 
+use super::{Inline, InlineSegmentsIterator};
+use crate::{Segments, owned, owned_all};
 pub use link_destination::*;
 pub use link_label::*;
 pub use link_title::*;
+use std::{borrow::Cow, iter, ops::Range};
+
+/// A link, as described in the [spec](https://spec.commonmark.org/0.31.2/#links).
+///
+/// The destination and title are the ones of the matching definition for reference links.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Link<'a> {
+    opening_bracket: Cow<'a, str>,
+    children: Vec<Inline<'a>>,
+    closing: Vec<Cow<'a, str>>,
+    #[cfg_attr(feature = "serde", serde(rename = "link_kind"))]
+    kind: LinkKind,
+    destination: Option<LinkDestination<'a>>,
+    title: Option<LinkTitle<'a>>,
+    pub(crate) range: Range<usize>,
+}
+
+impl<'a> Link<'a> {
+    pub(crate) fn new(
+        opening_bracket: &'a str,
+        children: Vec<Inline<'a>>,
+        closing: Vec<&'a str>,
+        kind: LinkKind,
+        destination: Option<LinkDestination<'a>>,
+        title: Option<LinkTitle<'a>>,
+    ) -> Self {
+        Self {
+            opening_bracket: Cow::Borrowed(opening_bracket),
+            children,
+            closing: closing.into_iter().map(Cow::Borrowed).collect(),
+            kind,
+            destination,
+            title,
+            range: 0..0,
+        }
+    }
+
+    /// Detaches the link from its source, by copying the strings it and its content borrow.
+    pub fn into_owned(self) -> Link<'static> {
+        Link {
+            opening_bracket: owned(self.opening_bracket),
+            children: self.children.into_iter().map(Inline::into_owned).collect(),
+            closing: owned_all(self.closing),
+            kind: self.kind,
+            destination: self.destination.map(LinkDestination::into_owned),
+            title: self.title.map(LinkTitle::into_owned),
+            range: self.range,
+        }
+    }
+
+    /// The inlines of the link text.
+    pub fn children(&self) -> &[Inline<'a>] {
+        &self.children
+    }
+
+    pub(crate) fn children_mut(&mut self) -> &mut [Inline<'a>] {
+        &mut self.children
+    }
+
+    pub fn kind(&self) -> LinkKind {
+        self.kind
+    }
+
+    /// The destination is only missing for inline links with empty parentheses, as in `[link]()`.
+    pub fn destination(&self) -> Option<&LinkDestination<'a>> {
+        self.destination.as_ref()
+    }
+
+    pub fn title(&self) -> Option<&LinkTitle<'a>> {
+        self.title.as_ref()
+    }
+
+    /// Replaces the destination the link is rendered with.
+    ///
+    /// The segments are left untouched, so they still hold the original destination, if any.
+    pub fn set_destination(&mut self, destination: Option<LinkDestination<'a>>) {
+        self.destination = destination;
+    }
+}
+
+impl<'a> Segments<'a> for Link<'a> {
+    type SegmentsIter = InlineSegmentsIterator<'a>;
+
+    fn segments(&'a self) -> Self::SegmentsIter {
+        InlineSegmentsIterator::new(Box::new(
+            iter::once(self.opening_bracket.as_ref())
+                .chain(self.children.iter().flat_map(Inline::segments))
+                .chain(self.closing.iter().map(AsRef::as_ref)),
+        ))
+    }
+}
+
+/// How the destination of a link, or of an image, is provided.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum LinkKind {
+    /// `[text](destination "title")`
+    Inline,
+    /// `[text][label]`
+    Full,
+    /// `[label][]`
+    Collapsed,
+    /// `[label]`
+    Shortcut,
+}
@@ -1 +1,117 @@
+mod autolink;
+mod code;
+mod emphasis;
+mod hard_break;
+mod image;
 pub mod link;
+mod raw_html;
+mod soft_break;
+mod strikethrough;
+mod strong;
+mod text;
+
+use crate::Segments;
+pub use autolink::*;
+pub use code::*;
+pub use emphasis::*;
+pub use hard_break::*;
+pub use image::*;
+pub use link::{Link, LinkKind};
+pub use raw_html::*;
+pub use soft_break::*;
+use std::iter::FusedIterator;
+pub use strikethrough::*;
+pub use strong::*;
+pub use text::*;
+
+/// An inline element, as described in the [spec](https://spec.commonmark.org/0.31.2/#inlines).
+///
+/// Inlines are parsed from the content of paragraphs and headings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "kind", rename_all = "snake_case"))]
+pub enum Inline<'a> {
+    Autolink(Autolink<'a>),
+    Code(Code<'a>),
+    Emphasis(Emphasis<'a>),
+    HardBreak(HardBreak<'a>),
+    Image(Image<'a>),
+    Link(Link<'a>),
+    RawHtml(RawHtml<'a>),
+    SoftBreak(SoftBreak<'a>),
+    Strikethrough(Strikethrough<'a>),
+    Strong(Strong<'a>),
+    Text(Text<'a>),
+}
+
+impl Inline<'_> {
+    /// Detaches the inline from its source, by copying the strings it and its content borrow.
+    pub fn into_owned(self) -> Inline<'static> {
+        match self {
+            Inline::Autolink(inline) => Inline::Autolink(inline.into_owned()),
+            Inline::Code(inline) => Inline::Code(inline.into_owned()),
+            Inline::Emphasis(inline) => Inline::Emphasis(inline.into_owned()),
+            Inline::HardBreak(inline) => Inline::HardBreak(inline.into_owned()),
+            Inline::Image(inline) => Inline::Image(inline.into_owned()),
+            Inline::Link(inline) => Inline::Link(inline.into_owned()),
+            Inline::RawHtml(inline) => Inline::RawHtml(inline.into_owned()),
+            Inline::SoftBreak(inline) => Inline::SoftBreak(inline.into_owned()),
+            Inline::Strikethrough(inline) => Inline::Strikethrough(inline.into_owned()),
+            Inline::Strong(inline) => Inline::Strong(inline.into_owned()),
+            Inline::Text(inline) => Inline::Text(inline.into_owned()),
+        }
+    }
+}
+
+impl<'a> Segments<'a> for Inline<'a> {
+    type SegmentsIter = InlineSegmentsIterator<'a>;
+
+    fn segments(&'a self) -> Self::SegmentsIter {
+        InlineSegmentsIterator::from(self)
+    }
+}
+
+// TODO: turn into statically typed enum.
+pub struct InlineSegmentsIterator<'a> {
+    iter: Box<dyn DoubleEndedIterator<Item = &'a str> + 'a>,
+}
+
+impl<'a> InlineSegmentsIterator<'a> {
+    pub(super) fn new(iter: Box<dyn DoubleEndedIterator<Item = &'a str> + 'a>) -> Self {
+        Self { iter }
+    }
+}
+
+impl<'a> From<&'a Inline<'a>> for InlineSegmentsIterator<'a> {
+    fn from(inline: &'a Inline) -> Self {
+        match inline {
+            Inline::Autolink(autolink) => Self::new(Box::new(autolink.segments())),
+            Inline::Code(code) => code.segments(),
+            Inline::Emphasis(emphasis) => emphasis.segments(),
+            Inline::HardBreak(hard_break) => Self::new(Box::new(hard_break.segments())),
+            Inline::Image(image) => image.segments(),
+            Inline::Link(link) => link.segments(),
+            Inline::RawHtml(raw_html) => raw_html.segments(),
+            Inline::SoftBreak(soft_break) => Self::new(Box::new(soft_break.segments())),
+            Inline::Strikethrough(strikethrough) => strikethrough.segments(),
+            Inline::Strong(strong) => strong.segments(),
+            Inline::Text(text) => Self::new(Box::new(text.segments())),
+        }
+    }
+}
+
+impl FusedIterator for InlineSegmentsIterator<'_> {}
+
+impl<'a> Iterator for InlineSegmentsIterator<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+}
+
+impl DoubleEndedIterator for InlineSegmentsIterator<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back()
+    }
+}
@@ -0,0 +1,39 @@
+use super::InlineSegmentsIterator;
+use crate::{Segments, SliceSegments, owned_all};
+use std::{borrow::Cow, ops::Range};
+
+/// Raw HTML, as described in the [spec](https://spec.commonmark.org/0.31.2/#raw-html).
+///
+/// It is an open tag, a closing tag, a comment, a processing instruction, a declaration or a
+/// CDATA section, which can span multiple lines. It is rendered as is.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RawHtml<'a> {
+    segments: Vec<Cow<'a, str>>,
+    pub(crate) range: Range<usize>,
+}
+
+impl<'a> RawHtml<'a> {
+    pub(crate) fn new(segments: Vec<&'a str>) -> Self {
+        Self {
+            segments: segments.into_iter().map(Cow::Borrowed).collect(),
+            range: 0..0,
+        }
+    }
+
+    /// Detaches the raw HTML from its source, by copying the strings it borrows.
+    pub fn into_owned(self) -> RawHtml<'static> {
+        RawHtml {
+            segments: owned_all(self.segments),
+            range: self.range,
+        }
+    }
+}
+
+impl<'a> Segments<'a> for RawHtml<'a> {
+    type SegmentsIter = InlineSegmentsIterator<'a>;
+
+    fn segments(&'a self) -> Self::SegmentsIter {
+        InlineSegmentsIterator::new(Box::new(SliceSegments::from(self.segments.as_slice())))
+    }
+}
@@ -0,0 +1,35 @@
+use crate::{Segment, owned};
+use std::{borrow::Cow, ops::Range};
+
+/// A soft line break, as described in the [spec](https://spec.commonmark.org/0.31.2/#soft-line-breaks).
+///
+/// The segment is the line ending, preceded by the trailing spaces of the line, if any.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SoftBreak<'a> {
+    segment: Cow<'a, str>,
+    pub(crate) range: Range<usize>,
+}
+
+impl<'a> SoftBreak<'a> {
+    pub(crate) fn new(segment: &'a str) -> Self {
+        Self {
+            segment: Cow::Borrowed(segment),
+            range: 0..0,
+        }
+    }
+
+    /// Detaches the line break from its source, by copying the strings it borrows.
+    pub fn into_owned(self) -> SoftBreak<'static> {
+        SoftBreak {
+            segment: owned(self.segment),
+            range: self.range,
+        }
+    }
+}
+
+impl Segment for SoftBreak<'_> {
+    fn segment(&self) -> &str {
+        &self.segment
+    }
+}
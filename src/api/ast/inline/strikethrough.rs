@@ -0,0 +1,68 @@
+use super::{Inline, InlineSegmentsIterator};
+use crate::{Segments, owned};
+use std::{borrow::Cow, iter, ops::Range};
+
+/// Strikethrough, as described in the [GFM spec](https://github.github.com/gfm/#strikethrough-extension-).
+///
+/// The text is delimited by one or two `~` on each side, as many as on the other side. It is only
+/// parsed when the [strikethrough](crate::ParseOptions::strikethrough) extension is enabled.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Strikethrough<'a> {
+    opening_delimiter: Cow<'a, str>,
+    children: Vec<Inline<'a>>,
+    closing_delimiter: Cow<'a, str>,
+    pub(crate) range: Range<usize>,
+}
+
+impl<'a> Strikethrough<'a> {
+    pub(crate) fn new(
+        opening_delimiter: &'a str,
+        children: Vec<Inline<'a>>,
+        closing_delimiter: &'a str,
+    ) -> Self {
+        Self {
+            opening_delimiter: Cow::Borrowed(opening_delimiter),
+            children,
+            closing_delimiter: Cow::Borrowed(closing_delimiter),
+            range: 0..0,
+        }
+    }
+
+    /// Detaches the strikethrough from its source, by copying the strings it and its content
+    /// borrow.
+    pub fn into_owned(self) -> Strikethrough<'static> {
+        Strikethrough {
+            opening_delimiter: owned(self.opening_delimiter),
+            children: self.children.into_iter().map(Inline::into_owned).collect(),
+            closing_delimiter: owned(self.closing_delimiter),
+            range: self.range,
+        }
+    }
+
+    /// The number of `~` on each side, either 1 or 2.
+    pub fn delimiter_length(&self) -> usize {
+        self.opening_delimiter.len()
+    }
+
+    /// The struck through inlines.
+    pub fn children(&self) -> &[Inline<'a>] {
+        &self.children
+    }
+
+    pub(crate) fn children_mut(&mut self) -> &mut [Inline<'a>] {
+        &mut self.children
+    }
+}
+
+impl<'a> Segments<'a> for Strikethrough<'a> {
+    type SegmentsIter = InlineSegmentsIterator<'a>;
+
+    fn segments(&'a self) -> Self::SegmentsIter {
+        InlineSegmentsIterator::new(Box::new(
+            iter::once(self.opening_delimiter.as_ref())
+                .chain(self.children.iter().flat_map(Inline::segments))
+                .chain(iter::once(self.closing_delimiter.as_ref())),
+        ))
+    }
+}
@@ -0,0 +1,40 @@
+use crate::{Segment, owned, parse::unescape};
+use std::{borrow::Cow, ops::Range};
+
+/// Textual content, as described in the [spec](https://spec.commonmark.org/0.31.2/#textual-content).
+///
+/// Text never spans multiple lines.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Text<'a> {
+    segment: Cow<'a, str>,
+    pub(crate) range: Range<usize>,
+}
+
+impl<'a> Text<'a> {
+    pub(crate) fn new(segment: &'a str) -> Self {
+        Self {
+            segment: Cow::Borrowed(segment),
+            range: 0..0,
+        }
+    }
+
+    /// Returns the text, with the backslash escapes and character references decoded.
+    pub fn content(&self) -> Cow<'_, str> {
+        unescape(&self.segment)
+    }
+
+    /// Detaches the text from its source, by copying the strings it borrows.
+    pub fn into_owned(self) -> Text<'static> {
+        Text {
+            segment: owned(self.segment),
+            range: self.range,
+        }
+    }
+}
+
+impl Segment for Text<'_> {
+    fn segment(&self) -> &str {
+        &self.segment
+    }
+}
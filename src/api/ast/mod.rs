@@ -1,14 +1,33 @@
+//! The syntax tree of a markdown document.
+//!
+//! # Serialization
+//!
+//! With the `serde` feature, the nodes implement `Serialize`, and `Deserialize` into the owned
+//! form of the tree, as returned by [Document::into_owned]. The schema follows the structure of
+//! the types:
+//!
+//! - A document is an object with its `blocks` and its `link_reference_definitions`.
+//! - Every block and inline is an object tagged with its `kind` in snake case, like `paragraph`,
+//!   `atx_heading`, `list_item` or `text`. Fenced code blocks are also tagged with their `fence`,
+//!   either `backticks` or `tildes`.
+//! - The text fields hold the raw segments of the node, under the name of the field, like
+//!   `segment`, `segments` or `opening_delimiter`. Children are under `blocks`, `items`, `inlines`
+//!   or `children`.
+//! - Every node has a `range`, the `start` and `end` byte offsets of the node in the source it was
+//!   parsed from. It is kept by [Document::into_owned].
+//! - Other enums, like list markers or link destinations, are tagged with their `kind` and hold
+//!   their content under `value`.
 pub mod block;
 pub mod inline;
 
-use super::{Segments, ToHtml};
-use crate::render::DisplayHtml;
-use block::{Block, Leaf, LinkReferenceDefinition};
-use std::iter::FusedIterator;
+use super::{Edit, Segments, ToCommonMark, ToHtml};
+use crate::render::{DisplayCommonMark, DisplayHtml, HtmlOptions};
+use block::{Block, Container, Leaf, LinkReferenceDefinition, TaskListMarker};
+use std::{collections::HashSet, iter::FusedIterator};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Document<'a> {
-    /// TODO: hide those behind an interface.
     pub(crate) blocks: Vec<Block<'a>>,
     pub(crate) link_reference_definitions: Vec<LinkReferenceDefinition<'a>>,
 }
@@ -23,25 +42,124 @@ impl<'a> Document<'a> {
             link_reference_definitions,
         }
     }
+
+    /// Detaches the document from the source it was parsed from, by copying the strings it borrows.
+    ///
+    /// The resulting document can outlive the source, be cached or sent to another thread.
+    pub fn into_owned(self) -> Document<'static> {
+        Document {
+            blocks: self.blocks.into_iter().map(Block::into_owned).collect(),
+            link_reference_definitions: self
+                .link_reference_definitions
+                .into_iter()
+                .map(LinkReferenceDefinition::into_owned)
+                .collect(),
+        }
+    }
+
+    /// The top level blocks of the document.
+    pub fn blocks(&self) -> &[Block<'a>] {
+        &self.blocks
+    }
+
+    pub(crate) fn blocks_mut(&mut self) -> &mut [Block<'a>] {
+        &mut self.blocks
+    }
+
+    /// Returns the edit of the source that checks or unchecks the task list item at the index, if
+    /// there is one. The task list items are counted in document order, starting at 0.
+    ///
+    /// The edit replaces the checkbox in the source the document was parsed from.
+    pub fn toggle_task_list_item(&self, index: usize) -> Option<Edit> {
+        let mut task_list_markers = vec![];
+        collect_task_list_markers(&self.blocks, &mut task_list_markers);
+        let task_list_marker = task_list_markers.get(index)?;
+        let replacement = if task_list_marker.is_checked() {
+            "[ ]"
+        } else {
+            "[x]"
+        };
+        Some(Edit {
+            range: task_list_marker.range.clone(),
+            replacement: replacement.to_string(),
+        })
+    }
 }
 
 impl<'a> From<Vec<Block<'a>>> for Document<'a> {
     fn from(blocks: Vec<Block<'a>>) -> Self {
         let mut link_reference_definitions = vec![];
-        for block in &blocks {
-            let Block::Leaf(leaf) = block;
-            if let Leaf::LinkReferenceDefinition(link_reference_definition) = leaf {
-                link_reference_definitions.push(link_reference_definition.clone());
+        collect_link_reference_definitions(
+            &blocks,
+            &mut link_reference_definitions,
+            &mut HashSet::new(),
+        );
+        Self::new(blocks, link_reference_definitions)
+    }
+}
+
+/// Collects the link reference definitions in document order, including the ones nested in containers.
+pub(crate) fn collect_link_reference_definitions<'a>(
+    blocks: &[Block<'a>],
+    link_reference_definitions: &mut Vec<LinkReferenceDefinition<'a>>,
+    labels: &mut HashSet<String>,
+) {
+    for block in blocks {
+        match block {
+            Block::Container(Container::BlockQuote(block_quote)) => {
+                collect_link_reference_definitions(
+                    block_quote.blocks(),
+                    link_reference_definitions,
+                    labels,
+                );
+            }
+            Block::Container(Container::List(list)) => {
+                for item in list.items() {
+                    collect_link_reference_definitions(
+                        item.blocks(),
+                        link_reference_definitions,
+                        labels,
+                    );
+                }
+            }
+            Block::Leaf(Leaf::LinkReferenceDefinition(link_reference_definition)) => {
+                // When multiple definitions share the same label, the first one wins.
+                if labels.insert(link_reference_definition.label().normalized()) {
+                    link_reference_definitions.push(link_reference_definition.clone());
+                }
             }
+            Block::Leaf(_) => {}
         }
+    }
+}
 
-        Self::new(blocks, link_reference_definitions)
+/// Collects the checkboxes of the task list items in document order, including the ones of nested
+/// lists.
+fn collect_task_list_markers<'s, 'a>(
+    blocks: &'s [Block<'a>],
+    task_list_markers: &mut Vec<&'s TaskListMarker<'a>>,
+) {
+    for block in blocks {
+        match block {
+            Block::Container(Container::BlockQuote(block_quote)) => {
+                collect_task_list_markers(block_quote.blocks(), task_list_markers);
+            }
+            Block::Container(Container::List(list)) => {
+                for item in list.items() {
+                    task_list_markers.extend(item.task_list_marker());
+                    collect_task_list_markers(item.blocks(), task_list_markers);
+                }
+            }
+            Block::Leaf(_) => {}
+        }
     }
 }
 
 impl<'a> Segments<'a> for Document<'a> {
     type SegmentsIter = DocumentSegmentsIterator<'a>;
 
+    /// Returns the segments of the blocks, which reproduce the source exactly when concatenated,
+    /// line endings and link reference definitions included.
     fn segments(&'a self) -> Self::SegmentsIter {
         DocumentSegmentsIterator::from(self)
     }
@@ -76,9 +194,197 @@ impl<'a> Iterator for DocumentSegmentsIterator<'a> {
 }
 
 impl ToHtml for Document<'_> {
-    fn to_html(&self) -> String {
+    fn to_html_with_options(&self, options: &HtmlOptions) -> String {
         let mut buffer = String::new();
-        self.display_html(&mut buffer, &self.link_reference_definitions);
+        self.display_html(&mut buffer, options);
+        // Every block ends with a line ending, except the last one.
+        if buffer.ends_with('\n') {
+            buffer.pop();
+        }
         buffer
     }
 }
+
+impl ToCommonMark for Document<'_> {
+    fn to_commonmark(&self) -> String {
+        let mut buffer = String::new();
+        self.display_commonmark(&mut buffer);
+        buffer
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::parse;
+
+    /// Has every kind of node.
+    const SOURCE: &str = "# *foo*\nbar\n===\n\n> - `baz`\n>   [qux][]\n\n```rust\nfn main() {}\n```\n\n    indented\n\n<div>\n\n---\n[qux]: </url> 'title'\n![image](url \"multi\nline\") <https://a.b> <a href=\"x\">  \nfoo\\\nbar\n";
+
+    mod segments {
+        use super::*;
+
+        fn assert_lossless(source: &str) {
+            assert_eq!(parse(source).segments().collect::<String>(), source);
+        }
+
+        #[test]
+        fn should_reproduce_every_kind_of_node() {
+            assert_lossless(SOURCE);
+        }
+
+        #[test]
+        fn should_reproduce_crlf_line_endings() {
+            assert_lossless(&SOURCE.replace('\n', "\r\n"));
+        }
+
+        #[test]
+        fn should_reproduce_a_missing_final_line_ending() {
+            assert_lossless(SOURCE.trim_end());
+            assert_lossless("[foo]:\n/url\n'title\n\ncontinued'");
+        }
+
+        #[test]
+        fn should_reproduce_link_reference_definitions() {
+            assert_lossless(
+                "[foo]: /url\n[bar]:\n  <baz>\n  \"multi\nline\"  \n> [qux]: /url (title)\nfoo\n",
+            );
+        }
+    }
+
+    mod toggle_task_list_item {
+        use super::*;
+        use crate::{ParseOptions, parse_with_options};
+
+        const SOURCE: &str = "- [ ] foo\n  > 1. [X] bar\n- baz\n- [x] qux\n";
+
+        fn toggle(index: usize) -> Option<String> {
+            parse_with_options(SOURCE, &ParseOptions::gfm())
+                .toggle_task_list_item(index)
+                .map(|edit| edit.apply(SOURCE))
+        }
+
+        #[test]
+        fn should_check_unchecked_items() {
+            assert_eq!(
+                toggle(0).as_deref(),
+                Some("- [x] foo\n  > 1. [X] bar\n- baz\n- [x] qux\n")
+            );
+        }
+
+        #[test]
+        fn should_uncheck_checked_items_in_document_order() {
+            assert_eq!(
+                toggle(1).as_deref(),
+                Some("- [ ] foo\n  > 1. [ ] bar\n- baz\n- [x] qux\n")
+            );
+            assert_eq!(
+                toggle(2).as_deref(),
+                Some("- [ ] foo\n  > 1. [X] bar\n- baz\n- [ ] qux\n")
+            );
+        }
+
+        #[test]
+        fn should_return_nothing_past_the_last_task_list_item() {
+            assert_eq!(toggle(3), None);
+        }
+
+        #[test]
+        fn should_return_the_range_of_the_checkbox() {
+            let document = parse_with_options(SOURCE, &ParseOptions::gfm());
+            assert_eq!(
+                document.toggle_task_list_item(1),
+                Some(Edit {
+                    range: 17..20,
+                    replacement: "[ ]".to_string(),
+                })
+            );
+        }
+    }
+
+    mod into_owned {
+        use super::*;
+
+        fn owned_document() -> Document<'static> {
+            let source = String::from(SOURCE);
+            parse(&source).into_owned()
+        }
+
+        #[test]
+        fn should_outlive_the_source() {
+            assert_eq!(owned_document(), parse(SOURCE));
+        }
+
+        #[test]
+        fn should_keep_the_segments() {
+            assert_eq!(owned_document().segments().collect::<String>(), SOURCE);
+        }
+
+        #[test]
+        fn should_render_the_same_html() {
+            assert_eq!(owned_document().to_html(), parse(SOURCE).to_html());
+        }
+
+        #[test]
+        fn should_be_sendable_to_another_thread() {
+            let document = owned_document();
+            let html = std::thread::spawn(move || document.to_html())
+                .join()
+                .unwrap();
+            assert_eq!(html, parse(SOURCE).to_html());
+        }
+    }
+    #[cfg(feature = "serde")]
+    mod serde {
+        use super::*;
+        use serde_json::json;
+
+        #[test]
+        fn should_tag_the_nodes_with_their_kind_and_range() {
+            assert_eq!(
+                serde_json::to_value(parse("foo *bar*\n")).unwrap(),
+                json!({
+                    "blocks": [{
+                        "kind": "paragraph",
+                        "segments": ["foo *bar*\n"],
+                        "inlines": [
+                            {
+                                "kind": "text",
+                                "segment": "foo ",
+                                "range": { "start": 0, "end": 4 },
+                            },
+                            {
+                                "kind": "emphasis",
+                                "opening_delimiter": "*",
+                                "children": [{
+                                    "kind": "text",
+                                    "segment": "bar",
+                                    "range": { "start": 5, "end": 8 },
+                                }],
+                                "closing_delimiter": "*",
+                                "range": { "start": 4, "end": 9 },
+                            },
+                        ],
+                        "range": { "start": 0, "end": 10 },
+                    }],
+                    "link_reference_definitions": [],
+                })
+            );
+        }
+
+        #[test]
+        fn should_deserialize_into_the_owned_document() {
+            let json = serde_json::to_string(&parse(SOURCE)).unwrap();
+            let document: Document<'static> = serde_json::from_str(&json).unwrap();
+            assert_eq!(document, parse(SOURCE));
+        }
+
+        #[test]
+        fn should_keep_the_ranges_of_the_owned_document() {
+            assert_eq!(
+                serde_json::to_value(parse(SOURCE).into_owned()).unwrap(),
+                serde_json::to_value(parse(SOURCE)).unwrap()
+            );
+        }
+    }
+}
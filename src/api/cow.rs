@@ -0,0 +1,27 @@
+use std::borrow::Cow;
+
+/// Detaches the string from the source it borrows from, if any.
+pub(crate) fn owned(string: Cow<'_, str>) -> Cow<'static, str> {
+    Cow::Owned(string.into_owned())
+}
+
+pub(crate) fn owned_all(strings: Vec<Cow<'_, str>>) -> Vec<Cow<'static, str>> {
+    strings.into_iter().map(owned).collect()
+}
+
+/// Returns the slice of the source that the string borrows, if it does.
+///
+/// The parser builds new nodes out of the strings of the nodes it parsed, which all borrow from the
+/// source. The strings of nodes detached from their source are owned, and there is nothing left to
+/// build out of them.
+pub(crate) fn borrowed<'a>(string: &Cow<'a, str>) -> Option<&'a str> {
+    match string {
+        Cow::Borrowed(string) => Some(string),
+        Cow::Owned(_) => None,
+    }
+}
+
+/// Returns the slices of the source that the strings borrow, if they all do.
+pub(crate) fn borrowed_all<'a>(strings: &[Cow<'a, str>]) -> Option<Vec<&'a str>> {
+    strings.iter().map(borrowed).collect()
+}
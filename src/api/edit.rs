@@ -0,0 +1,23 @@
+use std::ops::Range;
+
+/// A change of the source a document was parsed from, replacing a range of bytes.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Edit {
+    /// The byte range of the source to replace.
+    pub range: Range<usize>,
+    /// The text replacing the range.
+    pub replacement: String,
+}
+
+impl Edit {
+    /// Returns the source with the edit applied.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of the bounds of the source, or not on character boundaries.
+    pub fn apply(&self, source: &str) -> String {
+        let mut edited = source.to_string();
+        edited.replace_range(self.range.clone(), &self.replacement);
+        edited
+    }
+}
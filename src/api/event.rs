@@ -0,0 +1,470 @@
+use crate::{
+    ParseOptions, Segments,
+    ast::{
+        block::{Alignment, Block, Container, Leaf, Table, TableRow},
+        collect_link_reference_definitions,
+        inline::{Inline, link::LinkDestination, link::LinkTitle},
+    },
+    parse::{
+        Lines,
+        ast::{block::next_blocks, inline::link::Definitions, parse_inlines},
+        input::Input,
+        unescape,
+    },
+};
+use parser::{IsEmpty, SubsetRange};
+use std::{
+    borrow::Cow,
+    collections::{HashSet, VecDeque},
+};
+
+/// An event of the stream produced by [events](crate::events).
+///
+/// Every [Start](Event::Start) event is eventually followed by the matching [End](Event::End) event,
+/// and the events between them are the content of the element.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Event<'a> {
+    Start(Tag<'a>),
+    End(Tag<'a>),
+    /// Textual content, with the backslash escapes and character references decoded.
+    ///
+    /// In code blocks, it is a line of code, line ending included.
+    Text(Cow<'a, str>),
+    /// The content of a code span.
+    Code(Cow<'a, str>),
+    /// A line of an HTML block, line ending included.
+    Html(Cow<'a, str>),
+    /// Raw HTML found in inline content, one event per line.
+    InlineHtml(Cow<'a, str>),
+    SoftBreak,
+    HardBreak,
+    /// A thematic break.
+    Rule,
+    /// The checkbox of a task list item, right after the start of the item, with whether it is
+    /// checked.
+    TaskListMarker(bool),
+}
+
+/// The elements that have content, found in [Start](Event::Start) and [End](Event::End) events.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Tag<'a> {
+    /// The paragraphs of tight lists have no tags, only their content.
+    Paragraph,
+    /// An ATX or setext heading, with its level.
+    Heading(u8),
+    BlockQuote,
+    CodeBlock(CodeBlockKind<'a>),
+    HtmlBlock,
+    /// A list, with the number of its first item when it is ordered.
+    List(Option<u32>),
+    Item,
+    Emphasis,
+    Strong,
+    Strikethrough,
+    /// A link, autolinks included, with its decoded destination and title.
+    Link {
+        destination: Cow<'a, str>,
+        title: Option<Cow<'a, str>>,
+    },
+    /// An image, with its decoded destination and title. The content is the image description.
+    Image {
+        destination: Cow<'a, str>,
+        title: Option<Cow<'a, str>>,
+    },
+    /// A table, with the alignment of every column.
+    Table(Vec<Alignment>),
+    /// The header row of a table, whose cells are its direct content.
+    TableHead,
+    /// A body row of a table, with as many cells as there are columns.
+    TableRow,
+    /// A cell of a table, with the alignment of its column.
+    TableCell(Alignment),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CodeBlockKind<'a> {
+    Indented,
+    /// A fenced code block, with its decoded info string.
+    Fenced(Cow<'a, str>),
+}
+
+/// An iterator over the [events](Event) of a markdown source.
+///
+/// The top level blocks are parsed one at a time, as the events are consumed, so the document is
+/// never held in memory as a whole. The link reference definitions are the exception, as they are
+/// collected beforehand: links can reference definitions found anywhere in the source.
+#[derive(Debug, Clone)]
+pub struct Events<'a> {
+    source: &'a str,
+    remaining: Lines<'a>,
+    definitions: Definitions<'a>,
+    queue: VecDeque<Event<'a>>,
+}
+
+impl<'a> Events<'a> {
+    /// Collects the link reference definitions of the source.
+    ///
+    /// Returns the input that could not be parsed as an error.
+    pub(crate) fn new(source: &'a str, options: &ParseOptions) -> Result<Self, Lines<'a>> {
+        let mut link_reference_definitions = vec![];
+        let mut labels = HashSet::new();
+        let mut remaining = Lines::with_options(source, *options);
+        while !remaining.is_empty() {
+            let (rest, blocks) = next_blocks(remaining)?;
+            collect_link_reference_definitions(
+                &blocks,
+                &mut link_reference_definitions,
+                &mut labels,
+            );
+            remaining = rest;
+        }
+        Ok(Self {
+            source,
+            remaining: Lines::with_options(source, *options),
+            definitions: link_reference_definitions.into_iter().collect(),
+            queue: VecDeque::new(),
+        })
+    }
+
+    /// Returns the same string with the lifetime of the source, when it is a slice of the source.
+    ///
+    /// The blocks only live as long as it takes to produce their events, but the strings they
+    /// borrow are slices of the source, which outlives them.
+    fn reborrow(&self, string: Cow<'_, str>) -> Cow<'a, str> {
+        match string {
+            Cow::Borrowed(slice) => {
+                let (start, end) = self.source.subset_range(slice);
+                Cow::Borrowed(&self.source[start..end])
+            }
+            Cow::Owned(string) => Cow::Owned(string),
+        }
+    }
+
+    fn destination_url(&self, destination: Option<&LinkDestination>) -> Cow<'a, str> {
+        destination.map_or(Cow::Borrowed(""), |destination| {
+            self.reborrow(destination.url())
+        })
+    }
+
+    fn push(&mut self, event: Event<'a>) {
+        self.queue.push_back(event);
+    }
+
+    /// Pushes the events of the block, whose paragraphs have no tags when it is the direct child of
+    /// a tight list item.
+    fn push_block(&mut self, block: &Block<'a>, tight: bool) {
+        match block {
+            Block::Container(Container::BlockQuote(block_quote)) => {
+                self.push(Event::Start(Tag::BlockQuote));
+                for block in block_quote.blocks() {
+                    self.push_block(block, false);
+                }
+                self.push(Event::End(Tag::BlockQuote));
+            }
+            Block::Container(Container::List(list)) => {
+                self.push(Event::Start(Tag::List(list.start())));
+                for item in list.items() {
+                    self.push(Event::Start(Tag::Item));
+                    if let Some(checked) = item.checked() {
+                        self.push(Event::TaskListMarker(checked));
+                    }
+                    for block in item.blocks() {
+                        self.push_block(block, list.is_tight());
+                    }
+                    self.push(Event::End(Tag::Item));
+                }
+                self.push(Event::End(Tag::List(list.start())));
+            }
+            Block::Leaf(Leaf::AtxHeading(atx_heading)) => {
+                self.push_tagged(Tag::Heading(atx_heading.level()), atx_heading.inlines())
+            }
+            Block::Leaf(Leaf::BlankLine(_) | Leaf::LinkReferenceDefinition(_)) => {}
+            Block::Leaf(Leaf::FencedCode(fenced_code)) => {
+                let tag = Tag::CodeBlock(CodeBlockKind::Fenced(
+                    self.reborrow(unescape(fenced_code.info_string())),
+                ));
+                self.push(Event::Start(tag.clone()));
+                for segment in fenced_code.content_segments() {
+                    let text = self.reborrow(segment.into());
+                    self.push(Event::Text(text));
+                }
+                self.push(Event::End(tag));
+            }
+            Block::Leaf(Leaf::Html(html)) => {
+                self.push(Event::Start(Tag::HtmlBlock));
+                for segment in html.segments() {
+                    let html = self.reborrow(segment.into());
+                    self.push(Event::Html(html));
+                }
+                self.push(Event::End(Tag::HtmlBlock));
+            }
+            Block::Leaf(Leaf::IndentedCode(indented_code)) => {
+                let tag = Tag::CodeBlock(CodeBlockKind::Indented);
+                self.push(Event::Start(tag.clone()));
+                for line in indented_code.content_lines() {
+                    let text = self.reborrow(line.into());
+                    self.push(Event::Text(text));
+                }
+                self.push(Event::End(tag));
+            }
+            Block::Leaf(Leaf::Paragraph(paragraph)) if tight => {
+                self.push_inlines(paragraph.inlines())
+            }
+            Block::Leaf(Leaf::Paragraph(paragraph)) => {
+                self.push_tagged(Tag::Paragraph, paragraph.inlines())
+            }
+            Block::Leaf(Leaf::SetextHeading(setext_heading)) => self.push_tagged(
+                Tag::Heading(setext_heading.level()),
+                setext_heading.inlines(),
+            ),
+            Block::Leaf(Leaf::Table(table)) => self.push_table(table),
+            Block::Leaf(Leaf::ThematicBreak(_)) => self.push(Event::Rule),
+        }
+    }
+
+    fn push_table(&mut self, table: &Table<'a>) {
+        let tag = Tag::Table(table.alignments().to_vec());
+        self.push(Event::Start(tag.clone()));
+        self.push(Event::Start(Tag::TableHead));
+        self.push_cells(table, table.header());
+        self.push(Event::End(Tag::TableHead));
+        for row in table.rows() {
+            self.push(Event::Start(Tag::TableRow));
+            self.push_cells(table, row);
+            self.push(Event::End(Tag::TableRow));
+        }
+        self.push(Event::End(tag));
+    }
+
+    /// Pushes one cell per column, the missing ones being empty.
+    fn push_cells(&mut self, table: &Table<'a>, row: &TableRow<'a>) {
+        for (index, alignment) in table.alignments().iter().enumerate() {
+            let tag = Tag::TableCell(*alignment);
+            self.push(Event::Start(tag.clone()));
+            if let Some(cell) = row.cells().get(index) {
+                self.push_inlines(cell.inlines());
+            }
+            self.push(Event::End(tag));
+        }
+    }
+
+    fn push_tagged(&mut self, tag: Tag<'a>, inlines: &[Inline<'a>]) {
+        self.push(Event::Start(tag.clone()));
+        self.push_inlines(inlines);
+        self.push(Event::End(tag));
+    }
+
+    fn push_inlines(&mut self, inlines: &[Inline<'a>]) {
+        for inline in inlines {
+            match inline {
+                Inline::Autolink(autolink) => {
+                    let destination = self.reborrow(autolink.destination());
+                    let tag = Tag::Link {
+                        destination,
+                        title: None,
+                    };
+                    self.push(Event::Start(tag.clone()));
+                    let text = self.reborrow(autolink.text());
+                    self.push(Event::Text(text));
+                    self.push(Event::End(tag));
+                }
+                Inline::Code(code) => self.push(Event::Code(Cow::Owned(code.content()))),
+                Inline::Emphasis(emphasis) => self.push_tagged(Tag::Emphasis, emphasis.children()),
+                Inline::HardBreak(_) => self.push(Event::HardBreak),
+                Inline::Image(image) => {
+                    let tag = Tag::Image {
+                        destination: self.destination_url(image.destination()),
+                        title: title_content(image.title()),
+                    };
+                    self.push_tagged(tag, image.children());
+                }
+                Inline::Link(link) => {
+                    let tag = Tag::Link {
+                        destination: self.destination_url(link.destination()),
+                        title: title_content(link.title()),
+                    };
+                    self.push_tagged(tag, link.children());
+                }
+                Inline::RawHtml(raw_html) => {
+                    for segment in raw_html.segments() {
+                        let html = self.reborrow(segment.into());
+                        self.push(Event::InlineHtml(html));
+                    }
+                }
+                Inline::SoftBreak(_) => self.push(Event::SoftBreak),
+                Inline::Strikethrough(strikethrough) => {
+                    self.push_tagged(Tag::Strikethrough, strikethrough.children())
+                }
+                Inline::Strong(strong) => self.push_tagged(Tag::Strong, strong.children()),
+                Inline::Text(text) => {
+                    let text = self.reborrow(text.content());
+                    self.push(Event::Text(text));
+                }
+            }
+        }
+    }
+}
+
+fn title_content<'a>(title: Option<&LinkTitle>) -> Option<Cow<'a, str>> {
+    title.map(|title| Cow::Owned(title.content()))
+}
+
+impl<'a> Iterator for Events<'a> {
+    type Item = Event<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.queue.is_empty() && !self.remaining.is_empty() {
+            let options = *self.remaining.options();
+            let (remaining, mut blocks) = next_blocks(self.remaining)
+                .expect("unexpected error parsing markdown that was parsed before");
+            parse_inlines(&mut blocks, &self.definitions, &options);
+            for block in &blocks {
+                self.push_block(block, false);
+            }
+            self.remaining = remaining;
+        }
+        self.queue.pop_front()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{events, events_with_options};
+
+    #[test]
+    fn should_return_nothing_for_empty_source() {
+        assert_eq!(events("").collect::<Vec<_>>(), vec![]);
+    }
+
+    #[test]
+    fn should_work_with_blocks() {
+        assert_eq!(
+            events("# foo\n\n---\n\n    code\n").collect::<Vec<_>>(),
+            vec![
+                Event::Start(Tag::Heading(1)),
+                Event::Text(Cow::Borrowed("foo")),
+                Event::End(Tag::Heading(1)),
+                Event::Rule,
+                Event::Start(Tag::CodeBlock(CodeBlockKind::Indented)),
+                Event::Text(Cow::Borrowed("code\n")),
+                Event::End(Tag::CodeBlock(CodeBlockKind::Indented)),
+            ]
+        );
+    }
+
+    #[test]
+    fn should_omit_paragraph_tags_in_tight_lists() {
+        assert_eq!(
+            events("- foo\n- bar\n").collect::<Vec<_>>(),
+            vec![
+                Event::Start(Tag::List(None)),
+                Event::Start(Tag::Item),
+                Event::Text(Cow::Borrowed("foo")),
+                Event::End(Tag::Item),
+                Event::Start(Tag::Item),
+                Event::Text(Cow::Borrowed("bar")),
+                Event::End(Tag::Item),
+                Event::End(Tag::List(None)),
+            ]
+        );
+    }
+
+    #[test]
+    fn should_follow_the_start_of_task_list_items_with_their_checkbox() {
+        let options = ParseOptions {
+            task_lists: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            events_with_options("- [x] foo\n", &options).collect::<Vec<_>>(),
+            vec![
+                Event::Start(Tag::List(None)),
+                Event::Start(Tag::Item),
+                Event::TaskListMarker(true),
+                Event::Text(Cow::Borrowed("foo")),
+                Event::End(Tag::Item),
+                Event::End(Tag::List(None)),
+            ]
+        );
+    }
+
+    #[test]
+    fn should_work_with_inlines() {
+        let link = Tag::Link {
+            destination: Cow::Borrowed("/url"),
+            title: Some(Cow::Borrowed("title")),
+        };
+        assert_eq!(
+            events("*foo* `bar`\n[baz](/url \"title\")").collect::<Vec<_>>(),
+            vec![
+                Event::Start(Tag::Paragraph),
+                Event::Start(Tag::Emphasis),
+                Event::Text(Cow::Borrowed("foo")),
+                Event::End(Tag::Emphasis),
+                Event::Text(Cow::Borrowed(" ")),
+                Event::Code(Cow::Borrowed("bar")),
+                Event::SoftBreak,
+                Event::Start(link.clone()),
+                Event::Text(Cow::Borrowed("baz")),
+                Event::End(link),
+                Event::End(Tag::Paragraph),
+            ]
+        );
+    }
+
+    #[test]
+    fn should_resolve_references_to_later_definitions() {
+        let events: Vec<_> = events("[foo]\n\n[foo]: /url\n").collect();
+        assert_eq!(
+            events[1],
+            Event::Start(Tag::Link {
+                destination: Cow::Borrowed("/url"),
+                title: None
+            })
+        );
+    }
+
+    #[test]
+    fn should_prefix_email_autolinks_with_mailto() {
+        let events: Vec<_> = events("<foo@bar.com>").collect();
+        assert_eq!(
+            events[1],
+            Event::Start(Tag::Link {
+                destination: Cow::Borrowed("mailto:foo@bar.com"),
+                title: None
+            })
+        );
+    }
+
+    #[test]
+    fn should_pad_the_rows_of_tables() {
+        let events: Vec<_> =
+            events_with_options("a | b\n:- | -\nc\n", &ParseOptions::gfm()).collect();
+        let table = Tag::Table(vec![Alignment::Left, Alignment::None]);
+        let left = Tag::TableCell(Alignment::Left);
+        let none = Tag::TableCell(Alignment::None);
+        assert_eq!(
+            events,
+            vec![
+                Event::Start(table.clone()),
+                Event::Start(Tag::TableHead),
+                Event::Start(left.clone()),
+                Event::Text(Cow::Borrowed("a")),
+                Event::End(left.clone()),
+                Event::Start(none.clone()),
+                Event::Text(Cow::Borrowed("b")),
+                Event::End(none.clone()),
+                Event::End(Tag::TableHead),
+                Event::Start(Tag::TableRow),
+                Event::Start(left.clone()),
+                Event::Text(Cow::Borrowed("c")),
+                Event::End(left),
+                Event::Start(none.clone()),
+                Event::End(none),
+                Event::End(Tag::TableRow),
+                Event::End(table),
+            ]
+        );
+    }
+}
@@ -1,6 +1,20 @@
 pub mod ast;
+mod cow;
+mod edit;
+mod event;
+mod parse_error;
+mod parse_options;
 mod slice_segments;
+mod span;
 mod traits;
+pub mod visit;
+pub mod visit_mut;
 
+pub(crate) use cow::*;
+pub use edit::*;
+pub use event::*;
+pub use parse_error::*;
+pub use parse_options::*;
 pub use slice_segments::*;
+pub use span::*;
 pub use traits::*;
@@ -0,0 +1,90 @@
+use super::LineColumn;
+use crate::ast::block::BlockKind;
+use std::{error::Error, fmt};
+
+/// The error returned when the input could not be parsed entirely.
+///
+/// It points at the start of the first line that could not be parsed, along with the kind of block
+/// that line starts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    offset: usize,
+    line: usize,
+    column: usize,
+    block_kind: BlockKind,
+}
+
+impl ParseError {
+    /// Creates an error at the given byte offset of the source.
+    pub(crate) fn new(source: &str, offset: usize, block_kind: BlockKind) -> Self {
+        let LineColumn { line, column } = LineColumn::of(source, offset);
+        Self {
+            offset,
+            line,
+            column,
+            block_kind,
+        }
+    }
+
+    /// The byte offset of the error in the source.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// The line of the error, starting at 1.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// The column of the error, in characters, starting at 1.
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    /// The kind of block that failed to parse.
+    pub fn block_kind(&self) -> BlockKind {
+        self.block_kind
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "failed to parse {} at line {}, column {} (offset {})",
+            self.block_kind, self.line, self.column, self.offset
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod new {
+        use super::*;
+
+        #[test]
+        fn should_work_at_the_start() {
+            let error = ParseError::new("foo\n", 0, BlockKind::Paragraph);
+            assert_eq!((error.line(), error.column()), (1, 1));
+        }
+
+        #[test]
+        fn should_count_lines_and_characters() {
+            let error = ParseError::new("foo\r\nbär baz\n", 10, BlockKind::Paragraph);
+            assert_eq!(error.offset(), 10);
+            assert_eq!((error.line(), error.column()), (2, 5));
+        }
+    }
+
+    #[test]
+    fn should_display_the_position_and_block_kind() {
+        assert_eq!(
+            ParseError::new("> foo\n", 2, BlockKind::BlockQuote).to_string(),
+            "failed to parse block quote at line 1, column 3 (offset 2)"
+        );
+    }
+}
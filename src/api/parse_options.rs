@@ -0,0 +1,29 @@
+/// The options of the parsing, which enable the extensions of
+/// [GitHub Flavored Markdown](https://github.github.com/gfm/) on top of CommonMark.
+///
+/// All the extensions are disabled by default, so the source is parsed as strict CommonMark.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ParseOptions {
+    /// Whether [tables](https://github.github.com/gfm/#tables-extension-) are parsed.
+    pub tables: bool,
+    /// Whether [strikethrough](https://github.github.com/gfm/#strikethrough-extension-) is parsed.
+    pub strikethrough: bool,
+    /// Whether [task list items](https://github.github.com/gfm/#task-list-items-extension-) are
+    /// parsed.
+    pub task_lists: bool,
+    /// Whether [extended autolinks](https://github.github.com/gfm/#autolinks-extension-) are
+    /// recognized in the text.
+    pub autolinks: bool,
+}
+
+impl ParseOptions {
+    /// Returns the options enabling every extension of GitHub Flavored Markdown.
+    pub fn gfm() -> Self {
+        Self {
+            tables: true,
+            strikethrough: true,
+            task_lists: true,
+            autolinks: true,
+        }
+    }
+}
@@ -1,13 +1,13 @@
 use core::slice;
-use std::iter::FusedIterator;
+use std::{borrow::Cow, iter::FusedIterator};
 
 #[derive(Debug, Clone)]
 pub struct SliceSegments<'a> {
-    iter: slice::Iter<'a, &'a str>,
+    iter: slice::Iter<'a, Cow<'a, str>>,
 }
 
 impl<'a> SliceSegments<'a> {
-    pub fn new(iter: slice::Iter<'a, &'a str>) -> Self {
+    pub fn new(iter: slice::Iter<'a, Cow<'a, str>>) -> Self {
         Self { iter }
     }
 }
@@ -16,12 +16,18 @@ impl<'a> Iterator for SliceSegments<'a> {
     type Item = &'a str;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.iter.next().copied()
+        self.iter.next().map(AsRef::as_ref)
     }
 }
 
-impl<'a> From<&'a [&'a str]> for SliceSegments<'a> {
-    fn from(slice: &'a [&'a str]) -> Self {
+impl DoubleEndedIterator for SliceSegments<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(AsRef::as_ref)
+    }
+}
+
+impl<'a> From<&'a [Cow<'a, str>]> for SliceSegments<'a> {
+    fn from(slice: &'a [Cow<'a, str>]) -> Self {
         Self::new(slice.iter())
     }
 }
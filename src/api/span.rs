@@ -0,0 +1,526 @@
+use crate::{
+    Segments,
+    ast::{
+        Document,
+        block::{
+            AtxHeading, BackticksFencedCode, BlankLine, Block, BlockQuote, Container, FencedCode,
+            Html, IndentedCode, Leaf, LinkReferenceDefinition, List, ListItem, Paragraph,
+            SetextHeading, Table, TableCell, TableRow, TaskListMarker, ThematicBreak,
+            TildesFencedCode,
+        },
+        inline::{
+            Autolink, Code, Emphasis, HardBreak, Image, Inline, InlineSegmentsIterator, Link,
+            RawHtml, SoftBreak, Strikethrough, Strong, Text,
+        },
+    },
+    visit_mut::{
+        VisitorMut, walk_atx_heading_mut, walk_block_quote_mut, walk_emphasis_mut, walk_image_mut,
+        walk_link_mut, walk_list_item_mut, walk_list_mut, walk_paragraph_mut,
+        walk_setext_heading_mut, walk_strikethrough_mut, walk_strong_mut, walk_table_cell_mut,
+        walk_table_mut, walk_table_row_mut,
+    },
+};
+use parser::SubsetRange;
+use std::ops::Range;
+
+/// A position in the source, as a line and a column, both starting at 1.
+///
+/// Columns are counted in characters, and lines are terminated by `\n`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LineColumn {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl LineColumn {
+    /// Returns the line and column of the byte offset in the source.
+    pub(crate) fn of(source: &str, offset: usize) -> Self {
+        let before = &source[..offset];
+        let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+        Self {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+        }
+    }
+}
+
+/// Where a node is located in the source it was parsed from.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Span {
+    /// The byte range of the node in the source.
+    pub range: Range<usize>,
+    /// The position of the first character of the node.
+    pub start: LineColumn,
+    /// The position following the last character of the node, which is on the next line when the
+    /// node ends with a line ending.
+    pub end: LineColumn,
+}
+
+impl Span {
+    fn new(source: &str, range: Range<usize>) -> Self {
+        Self {
+            start: LineColumn::of(source, range.start),
+            end: LineColumn::of(source, range.end),
+            range,
+        }
+    }
+}
+
+/// For nodes that know where they are located in the source.
+///
+/// The range of every node is recorded when the document is parsed, so it is kept by
+/// [Document::into_owned] and serialization. It goes from the start of the first segment of the
+/// node to the end of the last one, so it also covers what is between the segments, like the
+/// container markers stripped from the lines of a nested block.
+pub trait Spanned {
+    /// Returns the byte range of the node in the source it was parsed from.
+    ///
+    /// A node without segments, like the document of an empty source, has an empty range at the
+    /// start of the source.
+    fn range(&self) -> Range<usize>;
+
+    /// Returns the span of the node in the source, which must be the one the node was parsed from.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range of the node is not within the source.
+    fn span(&self, source: &str) -> Span {
+        Span::new(source, self.range())
+    }
+}
+
+macro_rules! impl_spanned {
+    ($($node:ident),* $(,)?) => {
+        $(
+            impl Spanned for $node<'_> {
+                fn range(&self) -> Range<usize> {
+                    self.range.clone()
+                }
+            }
+        )*
+    };
+}
+
+impl_spanned!(
+    BlockQuote,
+    List,
+    ListItem,
+    TaskListMarker,
+    AtxHeading,
+    BlankLine,
+    BackticksFencedCode,
+    TildesFencedCode,
+    Html,
+    IndentedCode,
+    LinkReferenceDefinition,
+    Paragraph,
+    SetextHeading,
+    Table,
+    TableRow,
+    TableCell,
+    ThematicBreak,
+    Autolink,
+    Code,
+    Emphasis,
+    HardBreak,
+    Image,
+    Link,
+    RawHtml,
+    SoftBreak,
+    Strikethrough,
+    Strong,
+    Text,
+);
+
+impl Spanned for Document<'_> {
+    fn range(&self) -> Range<usize> {
+        match (self.blocks.first(), self.blocks.last()) {
+            (Some(first), Some(last)) => first.range().start..last.range().end,
+            _ => 0..0,
+        }
+    }
+}
+
+impl Spanned for Block<'_> {
+    fn range(&self) -> Range<usize> {
+        match self {
+            Block::Container(container) => container.range(),
+            Block::Leaf(leaf) => leaf.range(),
+        }
+    }
+}
+
+impl Spanned for Container<'_> {
+    fn range(&self) -> Range<usize> {
+        match self {
+            Container::BlockQuote(block_quote) => block_quote.range(),
+            Container::List(list) => list.range(),
+        }
+    }
+}
+
+impl Spanned for Leaf<'_> {
+    fn range(&self) -> Range<usize> {
+        match self {
+            Leaf::AtxHeading(atx_heading) => atx_heading.range(),
+            Leaf::BlankLine(blank_line) => blank_line.range(),
+            Leaf::FencedCode(fenced_code) => fenced_code.range(),
+            Leaf::Html(html) => html.range(),
+            Leaf::IndentedCode(indented_code) => indented_code.range(),
+            Leaf::LinkReferenceDefinition(link_reference_definition) => {
+                link_reference_definition.range()
+            }
+            Leaf::Paragraph(paragraph) => paragraph.range(),
+            Leaf::SetextHeading(setext_heading) => setext_heading.range(),
+            Leaf::Table(table) => table.range(),
+            Leaf::ThematicBreak(thematic_break) => thematic_break.range(),
+        }
+    }
+}
+
+impl Spanned for FencedCode<'_> {
+    fn range(&self) -> Range<usize> {
+        match self {
+            FencedCode::Backticks(backticks) => backticks.range(),
+            FencedCode::Tildes(tildes) => tildes.range(),
+        }
+    }
+}
+
+impl Spanned for Inline<'_> {
+    fn range(&self) -> Range<usize> {
+        match self {
+            Inline::Autolink(autolink) => autolink.range(),
+            Inline::Code(code) => code.range(),
+            Inline::Emphasis(emphasis) => emphasis.range(),
+            Inline::HardBreak(hard_break) => hard_break.range(),
+            Inline::Image(image) => image.range(),
+            Inline::Link(link) => link.range(),
+            Inline::RawHtml(raw_html) => raw_html.range(),
+            Inline::SoftBreak(soft_break) => soft_break.range(),
+            Inline::Strikethrough(strikethrough) => strikethrough.range(),
+            Inline::Strong(strong) => strong.range(),
+            Inline::Text(text) => text.range(),
+        }
+    }
+}
+
+/// Returns the byte range of the node in the source, which is empty at the start of the source
+/// when the node has no segments.
+fn range<'a, T: Segments<'a>>(node: &'a T, source: &str) -> Range<usize> {
+    let mut segments = node.segments();
+    let Some(first) = segments.next() else {
+        return 0..0;
+    };
+    let (start, first_end) = source.subset_range(first);
+    let end = segments
+        .last()
+        .map_or(first_end, |last| source.subset_range(last).1);
+    start..end
+}
+
+/// Returns the byte range of the inline in the source, like [range], but only looking at its first
+/// and last segments, as going through all of them takes as long as the inline has descendants.
+fn inline_range<'a, T>(inline: &'a T, source: &str) -> Range<usize>
+where
+    T: Segments<'a, SegmentsIter = InlineSegmentsIterator<'a>>,
+{
+    let mut segments = inline.segments();
+    let Some(first) = segments.next() else {
+        return 0..0;
+    };
+    let (start, first_end) = source.subset_range(first);
+    let end = segments
+        .next_back()
+        .map_or(first_end, |last| source.subset_range(last).1);
+    start..end
+}
+
+/// Records the range of every node of the document, which must have been parsed from the source.
+///
+/// The ranges are stored in the nodes, rather than computed from the source when needed, so that
+/// they survive [Document::into_owned] and serialization.
+pub(crate) fn set_ranges(document: &mut Document, source: &str) {
+    let mut setter = RangeSetter { source };
+    setter.visit_document_mut(document);
+    for link_reference_definition in &mut document.link_reference_definitions {
+        setter.visit_link_reference_definition_mut(link_reference_definition);
+    }
+}
+
+struct RangeSetter<'s> {
+    source: &'s str,
+}
+
+impl<'a> VisitorMut<'a> for RangeSetter<'_> {
+    fn visit_block_quote_mut(&mut self, block_quote: &mut BlockQuote<'a>) {
+        block_quote.range = range(&*block_quote, self.source);
+        walk_block_quote_mut(self, block_quote);
+    }
+
+    fn visit_list_mut(&mut self, list: &mut List<'a>) {
+        list.range = range(&*list, self.source);
+        walk_list_mut(self, list);
+    }
+
+    fn visit_list_item_mut(&mut self, list_item: &mut ListItem<'a>) {
+        list_item.range = range(&*list_item, self.source);
+        if let Some(task_list_marker) = list_item.task_list_marker_mut() {
+            task_list_marker.range = range(&*task_list_marker, self.source);
+        }
+        walk_list_item_mut(self, list_item);
+    }
+
+    fn visit_atx_heading_mut(&mut self, atx_heading: &mut AtxHeading<'a>) {
+        atx_heading.range = range(&*atx_heading, self.source);
+        walk_atx_heading_mut(self, atx_heading);
+    }
+
+    fn visit_blank_line_mut(&mut self, blank_line: &mut BlankLine<'a>) {
+        blank_line.range = range(&*blank_line, self.source);
+    }
+
+    fn visit_fenced_code_mut(&mut self, fenced_code: &mut FencedCode<'a>) {
+        match fenced_code {
+            FencedCode::Backticks(backticks) => backticks.range = range(&*backticks, self.source),
+            FencedCode::Tildes(tildes) => tildes.range = range(&*tildes, self.source),
+        }
+    }
+
+    fn visit_html_mut(&mut self, html: &mut Html<'a>) {
+        html.range = range(&*html, self.source);
+    }
+
+    fn visit_indented_code_mut(&mut self, indented_code: &mut IndentedCode<'a>) {
+        indented_code.range = range(&*indented_code, self.source);
+    }
+
+    fn visit_link_reference_definition_mut(
+        &mut self,
+        link_reference_definition: &mut LinkReferenceDefinition<'a>,
+    ) {
+        link_reference_definition.range = range(&*link_reference_definition, self.source);
+    }
+
+    fn visit_paragraph_mut(&mut self, paragraph: &mut Paragraph<'a>) {
+        paragraph.range = range(&*paragraph, self.source);
+        walk_paragraph_mut(self, paragraph);
+    }
+
+    fn visit_setext_heading_mut(&mut self, setext_heading: &mut SetextHeading<'a>) {
+        setext_heading.range = range(&*setext_heading, self.source);
+        walk_setext_heading_mut(self, setext_heading);
+    }
+
+    fn visit_table_mut(&mut self, table: &mut Table<'a>) {
+        table.range = range(&*table, self.source);
+        walk_table_mut(self, table);
+    }
+
+    fn visit_table_row_mut(&mut self, table_row: &mut TableRow<'a>) {
+        table_row.range = range(&*table_row, self.source);
+        walk_table_row_mut(self, table_row);
+    }
+
+    fn visit_table_cell_mut(&mut self, table_cell: &mut TableCell<'a>) {
+        table_cell.range = range(&*table_cell, self.source);
+        walk_table_cell_mut(self, table_cell);
+    }
+
+    fn visit_thematic_break_mut(&mut self, thematic_break: &mut ThematicBreak<'a>) {
+        thematic_break.range = range(&*thematic_break, self.source);
+    }
+
+    fn visit_autolink_mut(&mut self, autolink: &mut Autolink<'a>) {
+        autolink.range = range(&*autolink, self.source);
+    }
+
+    fn visit_code_mut(&mut self, code: &mut Code<'a>) {
+        code.range = inline_range(&*code, self.source);
+    }
+
+    fn visit_emphasis_mut(&mut self, emphasis: &mut Emphasis<'a>) {
+        emphasis.range = inline_range(&*emphasis, self.source);
+        walk_emphasis_mut(self, emphasis);
+    }
+
+    fn visit_hard_break_mut(&mut self, hard_break: &mut HardBreak<'a>) {
+        hard_break.range = range(&*hard_break, self.source);
+    }
+
+    fn visit_image_mut(&mut self, image: &mut Image<'a>) {
+        image.range = inline_range(&*image, self.source);
+        walk_image_mut(self, image);
+    }
+
+    fn visit_link_mut(&mut self, link: &mut Link<'a>) {
+        link.range = inline_range(&*link, self.source);
+        walk_link_mut(self, link);
+    }
+
+    fn visit_raw_html_mut(&mut self, raw_html: &mut RawHtml<'a>) {
+        raw_html.range = inline_range(&*raw_html, self.source);
+    }
+
+    fn visit_soft_break_mut(&mut self, soft_break: &mut SoftBreak<'a>) {
+        soft_break.range = range(&*soft_break, self.source);
+    }
+
+    fn visit_strikethrough_mut(&mut self, strikethrough: &mut Strikethrough<'a>) {
+        strikethrough.range = inline_range(&*strikethrough, self.source);
+        walk_strikethrough_mut(self, strikethrough);
+    }
+
+    fn visit_strong_mut(&mut self, strong: &mut Strong<'a>) {
+        strong.range = inline_range(&*strong, self.source);
+        walk_strong_mut(self, strong);
+    }
+
+    fn visit_text_mut(&mut self, text: &mut Text<'a>) {
+        text.range = range(&*text, self.source);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Segment, parse};
+
+    mod line_column {
+        use super::*;
+
+        #[test]
+        fn should_start_at_1() {
+            assert_eq!(LineColumn::of("foo", 0), LineColumn { line: 1, column: 1 });
+        }
+
+        #[test]
+        fn should_count_lines_and_characters() {
+            assert_eq!(
+                LineColumn::of("foo\r\nbär baz\n", 10),
+                LineColumn { line: 2, column: 5 }
+            );
+        }
+    }
+
+    mod span {
+        use super::*;
+
+        #[test]
+        fn should_work_with_blocks() {
+            let source = "# foo\n\nbar\nbaz\n";
+            let document = parse(source);
+            let span = document.blocks[2].span(source);
+            assert_eq!(span.range, 7..15);
+            assert_eq!(span.start, LineColumn { line: 3, column: 1 });
+            assert_eq!(span.end, LineColumn { line: 5, column: 1 });
+        }
+
+        #[test]
+        fn should_work_with_nested_blocks() {
+            let source = "> - foo\n>   bar\n";
+            let document = parse(source);
+            let Block::Container(Container::BlockQuote(block_quote)) = &document.blocks[0] else {
+                panic!("expected a block quote: {:?}", document.blocks[0]);
+            };
+            let Block::Container(Container::List(list)) = &block_quote.blocks()[0] else {
+                panic!("expected a list: {:?}", block_quote.blocks()[0]);
+            };
+            let Block::Leaf(Leaf::Paragraph(paragraph)) = &list.items()[0].blocks()[0] else {
+                panic!("expected a paragraph: {:?}", list.items()[0].blocks()[0]);
+            };
+            let span = paragraph.span(source);
+            assert_eq!(&source[span.range], "foo\n>   bar\n");
+            assert_eq!(span.start, LineColumn { line: 1, column: 5 });
+        }
+
+        #[test]
+        fn should_work_with_inlines() {
+            let source = "foo *bar*\nbaz\n";
+            let document = parse(source);
+            let Block::Leaf(Leaf::Paragraph(paragraph)) = &document.blocks[0] else {
+                panic!("expected a paragraph: {:?}", document.blocks[0]);
+            };
+            let Inline::Emphasis(emphasis) = &paragraph.inlines()[1] else {
+                panic!("expected emphasis: {:?}", paragraph.inlines());
+            };
+            let span = emphasis.span(source);
+            assert_eq!(span.range, 4..9);
+            assert_eq!(span.start, LineColumn { line: 1, column: 5 });
+            assert_eq!(
+                span.end,
+                LineColumn {
+                    line: 1,
+                    column: 10
+                }
+            );
+            let Inline::Text(text) = &paragraph.inlines()[3] else {
+                panic!("expected text: {:?}", paragraph.inlines());
+            };
+            assert_eq!(text.segment(), "baz");
+            assert_eq!(text.span(source).start, LineColumn { line: 2, column: 1 });
+        }
+
+        #[test]
+        fn should_be_recorded_in_the_nodes() {
+            let source = "> foo *bar*\n";
+            let document = parse(source);
+            let Block::Container(Container::BlockQuote(block_quote)) = &document.blocks[0] else {
+                panic!("expected a block quote: {:?}", document.blocks[0]);
+            };
+            let Block::Leaf(Leaf::Paragraph(paragraph)) = &block_quote.blocks()[0] else {
+                panic!("expected a paragraph: {:?}", block_quote.blocks()[0]);
+            };
+            assert_eq!(block_quote.range, block_quote.span(source).range);
+            assert_eq!(paragraph.range, 2..12);
+            let Inline::Emphasis(emphasis) = &paragraph.inlines()[1] else {
+                panic!("expected emphasis: {:?}", paragraph.inlines());
+            };
+            assert_eq!(emphasis.range, 6..11);
+        }
+
+        #[test]
+        fn should_be_recorded_in_nested_inlines() {
+            let source = "**a ![b *c*](d\n'e')**\n";
+            let document = parse(source);
+            let Block::Leaf(Leaf::Paragraph(paragraph)) = &document.blocks[0] else {
+                panic!("expected a paragraph: {:?}", document.blocks[0]);
+            };
+            let Inline::Strong(strong) = &paragraph.inlines()[0] else {
+                panic!("expected strong emphasis: {:?}", paragraph.inlines());
+            };
+            assert_eq!(strong.range, strong.span(source).range);
+            let Inline::Image(image) = &strong.children()[1] else {
+                panic!("expected an image: {:?}", strong.children());
+            };
+            assert_eq!(&source[image.range.clone()], "![b *c*](d\n'e')");
+            let Inline::Emphasis(emphasis) = &image.children()[1] else {
+                panic!("expected emphasis: {:?}", image.children());
+            };
+            assert_eq!(&source[emphasis.range.clone()], "*c*");
+        }
+
+        #[test]
+        fn should_be_empty_without_segments() {
+            assert_eq!(parse("").span("").range, 0..0);
+        }
+
+        #[test]
+        fn should_work_with_owned_documents() {
+            let source = String::from("foo\n\n> *bar*\n");
+            let document = parse(&source).into_owned();
+            let Block::Container(Container::BlockQuote(block_quote)) = &document.blocks()[2] else {
+                panic!("expected a block quote: {:?}", document.blocks()[2]);
+            };
+            assert_eq!(document.blocks()[0].span(&source).range, 0..4);
+            assert_eq!(block_quote.range(), 5..13);
+            assert_eq!(document.range(), 0..13);
+        }
+
+        #[test]
+        #[should_panic]
+        fn should_panic_with_a_shorter_source() {
+            parse("foo\n").span("");
+        }
+    }
+}
@@ -1,12 +1,13 @@
+use crate::render::HtmlOptions;
 use std::iter;
 
 /// This trait is for struct can be represented as a single segment.
 ///
 /// Those can be blocks that are single segment by definition, such as [crate::parse::ast::block::leaf::atx_heading::AtxHeading],
 /// or straight up segment types, such as [crate::parse::segment::atx_heading::AtxHeadingSegment].
-pub trait Segment<'a> {
+pub trait Segment {
     /// Returns the single segment of the block.
-    fn segment(&self) -> &'a str;
+    fn segment(&self) -> &str;
 }
 
 /// This trait is for structs that can be represented as multiple segments.
@@ -22,17 +23,31 @@ pub trait Segments<'a> {
 
 impl<'a, T> Segments<'a> for T
 where
-    T: Segment<'a>,
+    T: Segment + 'a,
 {
     type SegmentsIter = iter::Once<&'a str>;
 
-    fn segments(&self) -> Self::SegmentsIter {
+    fn segments(&'a self) -> Self::SegmentsIter {
         iter::once(self.segment())
     }
 }
 
 /// Produces an HTML string from a reference to the implementer.
 pub trait ToHtml {
-    /// Produce a valid HTML string from this instance.
-    fn to_html(&self) -> String;
+    /// Produce a valid HTML string from this instance, with the default options.
+    fn to_html(&self) -> String {
+        self.to_html_with_options(&HtmlOptions::default())
+    }
+
+    /// Produce a valid HTML string from this instance, rendered according to the options.
+    fn to_html_with_options(&self, options: &HtmlOptions) -> String;
+}
+
+/// Produces CommonMark from a reference to the implementer.
+pub trait ToCommonMark {
+    /// Produces normalized markdown, which parses back to the same content.
+    ///
+    /// The blocks are separated by blank lines, code blocks keep their content byte for byte, and
+    /// the characters of the text that would otherwise be taken for markup are escaped.
+    fn to_commonmark(&self) -> String;
 }
@@ -0,0 +1,336 @@
+//! Traversal of the syntax tree of a [Document].
+//!
+//! Every `visit_*` method of the [Visitor] trait defaults to visiting the children of the node, in
+//! document order, through the matching `walk_*` function. A visitor overriding a method can call
+//! that function to keep visiting the children.
+use crate::ast::{
+    Document,
+    block::{
+        AtxHeading, BlankLine, Block, BlockQuote, Container, FencedCode, Html, IndentedCode, Leaf,
+        LinkReferenceDefinition, List, ListItem, Paragraph, SetextHeading, Table, TableCell,
+        TableRow, ThematicBreak,
+    },
+    inline::{
+        Autolink, Code, Emphasis, HardBreak, Image, Inline, Link, RawHtml, SoftBreak,
+        Strikethrough, Strong, Text,
+    },
+};
+
+/// A visitor of the nodes of a [Document].
+pub trait Visitor<'a> {
+    fn visit_document(&mut self, document: &Document<'a>) {
+        walk_document(self, document);
+    }
+
+    fn visit_block(&mut self, block: &Block<'a>) {
+        walk_block(self, block);
+    }
+
+    fn visit_container(&mut self, container: &Container<'a>) {
+        walk_container(self, container);
+    }
+
+    fn visit_block_quote(&mut self, block_quote: &BlockQuote<'a>) {
+        walk_block_quote(self, block_quote);
+    }
+
+    fn visit_list(&mut self, list: &List<'a>) {
+        walk_list(self, list);
+    }
+
+    fn visit_list_item(&mut self, list_item: &ListItem<'a>) {
+        walk_list_item(self, list_item);
+    }
+
+    fn visit_leaf(&mut self, leaf: &Leaf<'a>) {
+        walk_leaf(self, leaf);
+    }
+
+    fn visit_atx_heading(&mut self, atx_heading: &AtxHeading<'a>) {
+        walk_atx_heading(self, atx_heading);
+    }
+
+    fn visit_blank_line(&mut self, _blank_line: &BlankLine<'a>) {}
+
+    fn visit_fenced_code(&mut self, _fenced_code: &FencedCode<'a>) {}
+
+    fn visit_html(&mut self, _html: &Html<'a>) {}
+
+    fn visit_indented_code(&mut self, _indented_code: &IndentedCode<'a>) {}
+
+    fn visit_link_reference_definition(
+        &mut self,
+        _link_reference_definition: &LinkReferenceDefinition<'a>,
+    ) {
+    }
+
+    fn visit_paragraph(&mut self, paragraph: &Paragraph<'a>) {
+        walk_paragraph(self, paragraph);
+    }
+
+    fn visit_setext_heading(&mut self, setext_heading: &SetextHeading<'a>) {
+        walk_setext_heading(self, setext_heading);
+    }
+
+    fn visit_table(&mut self, table: &Table<'a>) {
+        walk_table(self, table);
+    }
+
+    fn visit_table_row(&mut self, table_row: &TableRow<'a>) {
+        walk_table_row(self, table_row);
+    }
+
+    fn visit_table_cell(&mut self, table_cell: &TableCell<'a>) {
+        walk_table_cell(self, table_cell);
+    }
+
+    fn visit_thematic_break(&mut self, _thematic_break: &ThematicBreak<'a>) {}
+
+    fn visit_inline(&mut self, inline: &Inline<'a>) {
+        walk_inline(self, inline);
+    }
+
+    fn visit_autolink(&mut self, _autolink: &Autolink<'a>) {}
+
+    fn visit_code(&mut self, _code: &Code<'a>) {}
+
+    fn visit_emphasis(&mut self, emphasis: &Emphasis<'a>) {
+        walk_emphasis(self, emphasis);
+    }
+
+    fn visit_hard_break(&mut self, _hard_break: &HardBreak<'a>) {}
+
+    fn visit_image(&mut self, image: &Image<'a>) {
+        walk_image(self, image);
+    }
+
+    fn visit_link(&mut self, link: &Link<'a>) {
+        walk_link(self, link);
+    }
+
+    fn visit_raw_html(&mut self, _raw_html: &RawHtml<'a>) {}
+
+    fn visit_soft_break(&mut self, _soft_break: &SoftBreak<'a>) {}
+
+    fn visit_strikethrough(&mut self, strikethrough: &Strikethrough<'a>) {
+        walk_strikethrough(self, strikethrough);
+    }
+
+    fn visit_strong(&mut self, strong: &Strong<'a>) {
+        walk_strong(self, strong);
+    }
+
+    fn visit_text(&mut self, _text: &Text<'a>) {}
+}
+
+pub fn walk_document<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, document: &Document<'a>) {
+    for block in document.blocks() {
+        visitor.visit_block(block);
+    }
+}
+
+pub fn walk_block<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, block: &Block<'a>) {
+    match block {
+        Block::Container(container) => visitor.visit_container(container),
+        Block::Leaf(leaf) => visitor.visit_leaf(leaf),
+    }
+}
+
+pub fn walk_container<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, container: &Container<'a>) {
+    match container {
+        Container::BlockQuote(block_quote) => visitor.visit_block_quote(block_quote),
+        Container::List(list) => visitor.visit_list(list),
+    }
+}
+
+pub fn walk_block_quote<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    block_quote: &BlockQuote<'a>,
+) {
+    for block in block_quote.blocks() {
+        visitor.visit_block(block);
+    }
+}
+
+pub fn walk_list<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, list: &List<'a>) {
+    for list_item in list.items() {
+        visitor.visit_list_item(list_item);
+    }
+}
+
+pub fn walk_list_item<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, list_item: &ListItem<'a>) {
+    for block in list_item.blocks() {
+        visitor.visit_block(block);
+    }
+}
+
+pub fn walk_leaf<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, leaf: &Leaf<'a>) {
+    match leaf {
+        Leaf::AtxHeading(atx_heading) => visitor.visit_atx_heading(atx_heading),
+        Leaf::BlankLine(blank_line) => visitor.visit_blank_line(blank_line),
+        Leaf::FencedCode(fenced_code) => visitor.visit_fenced_code(fenced_code),
+        Leaf::Html(html) => visitor.visit_html(html),
+        Leaf::IndentedCode(indented_code) => visitor.visit_indented_code(indented_code),
+        Leaf::LinkReferenceDefinition(link_reference_definition) => {
+            visitor.visit_link_reference_definition(link_reference_definition)
+        }
+        Leaf::Paragraph(paragraph) => visitor.visit_paragraph(paragraph),
+        Leaf::SetextHeading(setext_heading) => visitor.visit_setext_heading(setext_heading),
+        Leaf::Table(table) => visitor.visit_table(table),
+        Leaf::ThematicBreak(thematic_break) => visitor.visit_thematic_break(thematic_break),
+    }
+}
+
+pub fn walk_atx_heading<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    atx_heading: &AtxHeading<'a>,
+) {
+    for inline in atx_heading.inlines() {
+        visitor.visit_inline(inline);
+    }
+}
+
+pub fn walk_paragraph<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, paragraph: &Paragraph<'a>) {
+    for inline in paragraph.inlines() {
+        visitor.visit_inline(inline);
+    }
+}
+
+pub fn walk_setext_heading<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    setext_heading: &SetextHeading<'a>,
+) {
+    for inline in setext_heading.inlines() {
+        visitor.visit_inline(inline);
+    }
+}
+
+pub fn walk_table<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, table: &Table<'a>) {
+    visitor.visit_table_row(table.header());
+    for table_row in table.rows() {
+        visitor.visit_table_row(table_row);
+    }
+}
+
+pub fn walk_table_row<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, table_row: &TableRow<'a>) {
+    for table_cell in table_row.cells() {
+        visitor.visit_table_cell(table_cell);
+    }
+}
+
+pub fn walk_table_cell<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, table_cell: &TableCell<'a>) {
+    for inline in table_cell.inlines() {
+        visitor.visit_inline(inline);
+    }
+}
+
+pub fn walk_inline<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, inline: &Inline<'a>) {
+    match inline {
+        Inline::Autolink(autolink) => visitor.visit_autolink(autolink),
+        Inline::Code(code) => visitor.visit_code(code),
+        Inline::Emphasis(emphasis) => visitor.visit_emphasis(emphasis),
+        Inline::HardBreak(hard_break) => visitor.visit_hard_break(hard_break),
+        Inline::Image(image) => visitor.visit_image(image),
+        Inline::Link(link) => visitor.visit_link(link),
+        Inline::RawHtml(raw_html) => visitor.visit_raw_html(raw_html),
+        Inline::SoftBreak(soft_break) => visitor.visit_soft_break(soft_break),
+        Inline::Strikethrough(strikethrough) => visitor.visit_strikethrough(strikethrough),
+        Inline::Strong(strong) => visitor.visit_strong(strong),
+        Inline::Text(text) => visitor.visit_text(text),
+    }
+}
+
+pub fn walk_emphasis<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, emphasis: &Emphasis<'a>) {
+    for inline in emphasis.children() {
+        visitor.visit_inline(inline);
+    }
+}
+
+pub fn walk_image<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, image: &Image<'a>) {
+    for inline in image.children() {
+        visitor.visit_inline(inline);
+    }
+}
+
+pub fn walk_link<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, link: &Link<'a>) {
+    for inline in link.children() {
+        visitor.visit_inline(inline);
+    }
+}
+
+pub fn walk_strikethrough<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    strikethrough: &Strikethrough<'a>,
+) {
+    for inline in strikethrough.children() {
+        visitor.visit_inline(inline);
+    }
+}
+
+pub fn walk_strong<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, strong: &Strong<'a>) {
+    for inline in strong.children() {
+        visitor.visit_inline(inline);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Segment, parse};
+
+    #[derive(Default)]
+    struct HeadingCollector(Vec<u8>);
+
+    impl Visitor<'_> for HeadingCollector {
+        fn visit_atx_heading(&mut self, atx_heading: &AtxHeading) {
+            self.0.push(atx_heading.level());
+        }
+
+        fn visit_setext_heading(&mut self, setext_heading: &SetextHeading) {
+            self.0.push(setext_heading.level());
+        }
+    }
+
+    #[derive(Default)]
+    struct TextCollector(Vec<String>);
+
+    impl Visitor<'_> for TextCollector {
+        fn visit_text(&mut self, text: &Text) {
+            self.0.push(text.segment().to_string());
+        }
+    }
+
+    #[test]
+    fn should_visit_nested_blocks() {
+        let document = parse("# foo\n\n> bar\n> ===\n\n- ## baz\n");
+        let mut collector = HeadingCollector::default();
+        collector.visit_document(&document);
+        assert_eq!(collector.0, vec![1, 1, 2]);
+    }
+
+    #[test]
+    fn should_visit_nested_inlines_in_order() {
+        let document = parse("foo *bar [baz](/url)*\n# **qux**");
+        let mut collector = TextCollector::default();
+        collector.visit_document(&document);
+        assert_eq!(collector.0, vec!["foo ", "bar ", "baz", "qux"]);
+    }
+
+    #[test]
+    fn should_stop_descending_when_not_walking() {
+        struct Skipper(TextCollector);
+
+        impl Visitor<'_> for Skipper {
+            fn visit_block_quote(&mut self, _: &BlockQuote) {}
+
+            fn visit_text(&mut self, text: &Text) {
+                self.0.visit_text(text);
+            }
+        }
+
+        let document = parse("foo\n\n> bar\n");
+        let mut skipper = Skipper(TextCollector::default());
+        skipper.visit_document(&document);
+        assert_eq!(skipper.0.0, vec!["foo"]);
+    }
+}
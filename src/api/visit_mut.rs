@@ -0,0 +1,351 @@
+//! Mutable traversal of the syntax tree of a [Document].
+//!
+//! This is the mutable counterpart of the [visit](crate::visit) module. Every `visit_*_mut` method
+//! of the [VisitorMut] trait defaults to visiting the children of the node, in
+//! document order, through the matching `walk_*_mut` function. A visitor overriding a method can call
+//! that function to keep visiting the children.
+use crate::ast::{
+    Document,
+    block::{
+        AtxHeading, BlankLine, Block, BlockQuote, Container, FencedCode, Html, IndentedCode, Leaf,
+        LinkReferenceDefinition, List, ListItem, Paragraph, SetextHeading, Table, TableCell,
+        TableRow, ThematicBreak,
+    },
+    inline::{
+        Autolink, Code, Emphasis, HardBreak, Image, Inline, Link, RawHtml, SoftBreak,
+        Strikethrough, Strong, Text,
+    },
+};
+
+/// A visitor of the nodes of a [Document], which can modify them in place.
+pub trait VisitorMut<'a> {
+    fn visit_document_mut(&mut self, document: &mut Document<'a>) {
+        walk_document_mut(self, document);
+    }
+
+    fn visit_block_mut(&mut self, block: &mut Block<'a>) {
+        walk_block_mut(self, block);
+    }
+
+    fn visit_container_mut(&mut self, container: &mut Container<'a>) {
+        walk_container_mut(self, container);
+    }
+
+    fn visit_block_quote_mut(&mut self, block_quote: &mut BlockQuote<'a>) {
+        walk_block_quote_mut(self, block_quote);
+    }
+
+    fn visit_list_mut(&mut self, list: &mut List<'a>) {
+        walk_list_mut(self, list);
+    }
+
+    fn visit_list_item_mut(&mut self, list_item: &mut ListItem<'a>) {
+        walk_list_item_mut(self, list_item);
+    }
+
+    fn visit_leaf_mut(&mut self, leaf: &mut Leaf<'a>) {
+        walk_leaf_mut(self, leaf);
+    }
+
+    fn visit_atx_heading_mut(&mut self, atx_heading: &mut AtxHeading<'a>) {
+        walk_atx_heading_mut(self, atx_heading);
+    }
+
+    fn visit_blank_line_mut(&mut self, _blank_line: &mut BlankLine<'a>) {}
+
+    fn visit_fenced_code_mut(&mut self, _fenced_code: &mut FencedCode<'a>) {}
+
+    fn visit_html_mut(&mut self, _html: &mut Html<'a>) {}
+
+    fn visit_indented_code_mut(&mut self, _indented_code: &mut IndentedCode<'a>) {}
+
+    fn visit_link_reference_definition_mut(
+        &mut self,
+        _link_reference_definition: &mut LinkReferenceDefinition<'a>,
+    ) {
+    }
+
+    fn visit_paragraph_mut(&mut self, paragraph: &mut Paragraph<'a>) {
+        walk_paragraph_mut(self, paragraph);
+    }
+
+    fn visit_setext_heading_mut(&mut self, setext_heading: &mut SetextHeading<'a>) {
+        walk_setext_heading_mut(self, setext_heading);
+    }
+
+    fn visit_table_mut(&mut self, table: &mut Table<'a>) {
+        walk_table_mut(self, table);
+    }
+
+    fn visit_table_row_mut(&mut self, table_row: &mut TableRow<'a>) {
+        walk_table_row_mut(self, table_row);
+    }
+
+    fn visit_table_cell_mut(&mut self, table_cell: &mut TableCell<'a>) {
+        walk_table_cell_mut(self, table_cell);
+    }
+
+    fn visit_thematic_break_mut(&mut self, _thematic_break: &mut ThematicBreak<'a>) {}
+
+    fn visit_inline_mut(&mut self, inline: &mut Inline<'a>) {
+        walk_inline_mut(self, inline);
+    }
+
+    fn visit_autolink_mut(&mut self, _autolink: &mut Autolink<'a>) {}
+
+    fn visit_code_mut(&mut self, _code: &mut Code<'a>) {}
+
+    fn visit_emphasis_mut(&mut self, emphasis: &mut Emphasis<'a>) {
+        walk_emphasis_mut(self, emphasis);
+    }
+
+    fn visit_hard_break_mut(&mut self, _hard_break: &mut HardBreak<'a>) {}
+
+    fn visit_image_mut(&mut self, image: &mut Image<'a>) {
+        walk_image_mut(self, image);
+    }
+
+    fn visit_link_mut(&mut self, link: &mut Link<'a>) {
+        walk_link_mut(self, link);
+    }
+
+    fn visit_raw_html_mut(&mut self, _raw_html: &mut RawHtml<'a>) {}
+
+    fn visit_soft_break_mut(&mut self, _soft_break: &mut SoftBreak<'a>) {}
+
+    fn visit_strikethrough_mut(&mut self, strikethrough: &mut Strikethrough<'a>) {
+        walk_strikethrough_mut(self, strikethrough);
+    }
+
+    fn visit_strong_mut(&mut self, strong: &mut Strong<'a>) {
+        walk_strong_mut(self, strong);
+    }
+
+    fn visit_text_mut(&mut self, _text: &mut Text<'a>) {}
+}
+
+pub fn walk_document_mut<'a, V: VisitorMut<'a> + ?Sized>(
+    visitor: &mut V,
+    document: &mut Document<'a>,
+) {
+    for block in document.blocks_mut() {
+        visitor.visit_block_mut(block);
+    }
+}
+
+pub fn walk_block_mut<'a, V: VisitorMut<'a> + ?Sized>(visitor: &mut V, block: &mut Block<'a>) {
+    match block {
+        Block::Container(container) => visitor.visit_container_mut(container),
+        Block::Leaf(leaf) => visitor.visit_leaf_mut(leaf),
+    }
+}
+
+pub fn walk_container_mut<'a, V: VisitorMut<'a> + ?Sized>(
+    visitor: &mut V,
+    container: &mut Container<'a>,
+) {
+    match container {
+        Container::BlockQuote(block_quote) => visitor.visit_block_quote_mut(block_quote),
+        Container::List(list) => visitor.visit_list_mut(list),
+    }
+}
+
+pub fn walk_block_quote_mut<'a, V: VisitorMut<'a> + ?Sized>(
+    visitor: &mut V,
+    block_quote: &mut BlockQuote<'a>,
+) {
+    for block in block_quote.blocks_mut() {
+        visitor.visit_block_mut(block);
+    }
+}
+
+pub fn walk_list_mut<'a, V: VisitorMut<'a> + ?Sized>(visitor: &mut V, list: &mut List<'a>) {
+    for list_item in list.items_mut() {
+        visitor.visit_list_item_mut(list_item);
+    }
+}
+
+pub fn walk_list_item_mut<'a, V: VisitorMut<'a> + ?Sized>(
+    visitor: &mut V,
+    list_item: &mut ListItem<'a>,
+) {
+    for block in list_item.blocks_mut() {
+        visitor.visit_block_mut(block);
+    }
+}
+
+pub fn walk_leaf_mut<'a, V: VisitorMut<'a> + ?Sized>(visitor: &mut V, leaf: &mut Leaf<'a>) {
+    match leaf {
+        Leaf::AtxHeading(atx_heading) => visitor.visit_atx_heading_mut(atx_heading),
+        Leaf::BlankLine(blank_line) => visitor.visit_blank_line_mut(blank_line),
+        Leaf::FencedCode(fenced_code) => visitor.visit_fenced_code_mut(fenced_code),
+        Leaf::Html(html) => visitor.visit_html_mut(html),
+        Leaf::IndentedCode(indented_code) => visitor.visit_indented_code_mut(indented_code),
+        Leaf::LinkReferenceDefinition(link_reference_definition) => {
+            visitor.visit_link_reference_definition_mut(link_reference_definition)
+        }
+        Leaf::Paragraph(paragraph) => visitor.visit_paragraph_mut(paragraph),
+        Leaf::SetextHeading(setext_heading) => visitor.visit_setext_heading_mut(setext_heading),
+        Leaf::Table(table) => visitor.visit_table_mut(table),
+        Leaf::ThematicBreak(thematic_break) => visitor.visit_thematic_break_mut(thematic_break),
+    }
+}
+
+pub fn walk_atx_heading_mut<'a, V: VisitorMut<'a> + ?Sized>(
+    visitor: &mut V,
+    atx_heading: &mut AtxHeading<'a>,
+) {
+    for inline in atx_heading.inlines_mut() {
+        visitor.visit_inline_mut(inline);
+    }
+}
+
+pub fn walk_paragraph_mut<'a, V: VisitorMut<'a> + ?Sized>(
+    visitor: &mut V,
+    paragraph: &mut Paragraph<'a>,
+) {
+    for inline in paragraph.inlines_mut() {
+        visitor.visit_inline_mut(inline);
+    }
+}
+
+pub fn walk_setext_heading_mut<'a, V: VisitorMut<'a> + ?Sized>(
+    visitor: &mut V,
+    setext_heading: &mut SetextHeading<'a>,
+) {
+    for inline in setext_heading.inlines_mut() {
+        visitor.visit_inline_mut(inline);
+    }
+}
+
+pub fn walk_table_mut<'a, V: VisitorMut<'a> + ?Sized>(visitor: &mut V, table: &mut Table<'a>) {
+    for table_row in table.all_rows_mut() {
+        visitor.visit_table_row_mut(table_row);
+    }
+}
+
+pub fn walk_table_row_mut<'a, V: VisitorMut<'a> + ?Sized>(
+    visitor: &mut V,
+    table_row: &mut TableRow<'a>,
+) {
+    for table_cell in table_row.cells_mut() {
+        visitor.visit_table_cell_mut(table_cell);
+    }
+}
+
+pub fn walk_table_cell_mut<'a, V: VisitorMut<'a> + ?Sized>(
+    visitor: &mut V,
+    table_cell: &mut TableCell<'a>,
+) {
+    for inline in table_cell.inlines_mut() {
+        visitor.visit_inline_mut(inline);
+    }
+}
+
+pub fn walk_inline_mut<'a, V: VisitorMut<'a> + ?Sized>(visitor: &mut V, inline: &mut Inline<'a>) {
+    match inline {
+        Inline::Autolink(autolink) => visitor.visit_autolink_mut(autolink),
+        Inline::Code(code) => visitor.visit_code_mut(code),
+        Inline::Emphasis(emphasis) => visitor.visit_emphasis_mut(emphasis),
+        Inline::HardBreak(hard_break) => visitor.visit_hard_break_mut(hard_break),
+        Inline::Image(image) => visitor.visit_image_mut(image),
+        Inline::Link(link) => visitor.visit_link_mut(link),
+        Inline::RawHtml(raw_html) => visitor.visit_raw_html_mut(raw_html),
+        Inline::SoftBreak(soft_break) => visitor.visit_soft_break_mut(soft_break),
+        Inline::Strikethrough(strikethrough) => visitor.visit_strikethrough_mut(strikethrough),
+        Inline::Strong(strong) => visitor.visit_strong_mut(strong),
+        Inline::Text(text) => visitor.visit_text_mut(text),
+    }
+}
+
+pub fn walk_emphasis_mut<'a, V: VisitorMut<'a> + ?Sized>(
+    visitor: &mut V,
+    emphasis: &mut Emphasis<'a>,
+) {
+    for inline in emphasis.children_mut() {
+        visitor.visit_inline_mut(inline);
+    }
+}
+
+pub fn walk_image_mut<'a, V: VisitorMut<'a> + ?Sized>(visitor: &mut V, image: &mut Image<'a>) {
+    for inline in image.children_mut() {
+        visitor.visit_inline_mut(inline);
+    }
+}
+
+pub fn walk_link_mut<'a, V: VisitorMut<'a> + ?Sized>(visitor: &mut V, link: &mut Link<'a>) {
+    for inline in link.children_mut() {
+        visitor.visit_inline_mut(inline);
+    }
+}
+
+pub fn walk_strikethrough_mut<'a, V: VisitorMut<'a> + ?Sized>(
+    visitor: &mut V,
+    strikethrough: &mut Strikethrough<'a>,
+) {
+    for inline in strikethrough.children_mut() {
+        visitor.visit_inline_mut(inline);
+    }
+}
+
+pub fn walk_strong_mut<'a, V: VisitorMut<'a> + ?Sized>(visitor: &mut V, strong: &mut Strong<'a>) {
+    for inline in strong.children_mut() {
+        visitor.visit_inline_mut(inline);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Segments, ToHtml, ast::inline::link::LinkDestination, parse};
+
+    struct UrlRewriter;
+
+    impl<'a> VisitorMut<'a> for UrlRewriter {
+        fn visit_link_mut(&mut self, link: &mut Link<'a>) {
+            link.set_destination(LinkDestination::try_from("https://example.com").ok());
+            walk_link_mut(self, link);
+        }
+    }
+
+    #[test]
+    fn should_modify_nested_nodes() {
+        let mut document = parse("> - [foo](/url) *[bar][]*\n\n[bar]: /other");
+        UrlRewriter.visit_document_mut(&mut document);
+        assert_eq!(
+            document.to_html(),
+            "<blockquote>\n<ul>\n<li><a href=\"https://example.com\">foo</a> <em><a href=\"https://example.com\">bar</a></em></li>\n</ul>\n</blockquote>"
+        );
+    }
+
+    /// Prefixes the destinations with a base URL, making new strings that outlive the visit.
+    struct BaseUrl<'s>(&'s str);
+
+    impl<'a> VisitorMut<'a> for BaseUrl<'_> {
+        fn visit_link_mut(&mut self, link: &mut Link<'a>) {
+            if let Some(destination) = link.destination() {
+                let url = format!("{}{}", self.0, destination.url());
+                link.set_destination(LinkDestination::try_from(url).ok());
+            }
+            walk_link_mut(self, link);
+        }
+    }
+
+    #[test]
+    fn should_take_destinations_made_at_runtime() {
+        let mut document = parse("[foo](/url)");
+        BaseUrl("https://example.com").visit_document_mut(&mut document);
+        assert_eq!(
+            document.to_html(),
+            "<p><a href=\"https://example.com/url\">foo</a></p>"
+        );
+    }
+
+    #[test]
+    fn should_leave_the_segments_untouched() {
+        let source = "[foo](/url)";
+        let mut document = parse(source);
+        UrlRewriter.visit_document_mut(&mut document);
+        assert_eq!(document.segments().collect::<String>(), source);
+    }
+}
@@ -4,14 +4,108 @@ pub mod render;
 
 use api::ast::Document;
 pub use api::*;
-use parse::{lines, traits::Parse};
+use parse::{Lines, ast::block::block_kind, traits::Parse};
 use parser::IsEmpty;
 
+/// Parses the markdown source into a [Document].
+///
+/// # Panics
+///
+/// Panics if the source cannot be parsed entirely. See [try_parse] for the fallible version.
 pub fn parse(input: &str) -> Document {
-    let (remaining, parsed) =
-        Document::parse(lines(input)).expect("unexpected error parsing markdown");
-    if !remaining.is_empty() {
-        panic!("unexpected remaining input: {remaining:?}");
+    parse_with_options(input, &ParseOptions::default())
+}
+
+/// Parses the markdown source into a [Document], with the extensions enabled by the options.
+///
+/// # Panics
+///
+/// Panics if the source cannot be parsed entirely. See [try_parse_with_options] for the fallible
+/// version.
+pub fn parse_with_options<'a>(input: &'a str, options: &ParseOptions) -> Document<'a> {
+    try_parse_with_options(input, options)
+        .unwrap_or_else(|error| panic!("unexpected error parsing markdown: {error}"))
+}
+
+/// Parses the markdown source into a [Document], or returns an error pointing at the first line
+/// that could not be parsed.
+pub fn try_parse(input: &str) -> Result<Document, ParseError> {
+    try_parse_with_options(input, &ParseOptions::default())
+}
+
+/// Same as [try_parse], with the extensions enabled by the options.
+pub fn try_parse_with_options<'a>(
+    input: &'a str,
+    options: &ParseOptions,
+) -> Result<Document<'a>, ParseError> {
+    let remaining = match Document::parse(Lines::with_options(input, *options)) {
+        Ok((remaining, mut document)) if remaining.is_empty() => {
+            set_ranges(&mut document, input);
+            return Ok(document);
+        }
+        Ok((remaining, _)) | Err(remaining) => remaining,
+    };
+    Err(parse_error(input, remaining))
+}
+
+/// Returns the [events](Event) of the markdown source, parsed as they are consumed.
+///
+/// # Panics
+///
+/// Panics if the source cannot be parsed entirely. See [try_events] for the fallible version.
+pub fn events(input: &str) -> Events {
+    events_with_options(input, &ParseOptions::default())
+}
+
+/// Returns the [events](Event) of the markdown source, with the extensions enabled by the options.
+///
+/// # Panics
+///
+/// Panics if the source cannot be parsed entirely. See [try_events_with_options] for the fallible
+/// version.
+pub fn events_with_options<'a>(input: &'a str, options: &ParseOptions) -> Events<'a> {
+    try_events_with_options(input, options)
+        .unwrap_or_else(|error| panic!("unexpected error parsing markdown: {error}"))
+}
+
+/// Returns the [events](Event) of the markdown source, or an error pointing at the first line that
+/// could not be parsed.
+///
+/// The error is found upfront, before any event is produced.
+pub fn try_events(input: &str) -> Result<Events, ParseError> {
+    try_events_with_options(input, &ParseOptions::default())
+}
+
+/// Same as [try_events], with the extensions enabled by the options.
+pub fn try_events_with_options<'a>(
+    input: &'a str,
+    options: &ParseOptions,
+) -> Result<Events<'a>, ParseError> {
+    Events::new(input, options).map_err(|remaining| parse_error(input, remaining))
+}
+
+fn parse_error(input: &str, remaining: Lines) -> ParseError {
+    let offset = input.len() - remaining.len();
+    let line = input[offset..].split_inclusive('\n').next().unwrap_or("");
+    ParseError::new(input, offset, block_kind(line))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod try_parse {
+        use super::*;
+
+        #[test]
+        fn should_parse_the_whole_source() {
+            let document = try_parse("# foo\n\n> bar\n").unwrap();
+            assert_eq!(document.segments().collect::<String>(), "# foo\n\n> bar\n");
+        }
+
+        #[test]
+        fn should_work_with_empty_source() {
+            assert!(try_parse("").is_ok());
+        }
     }
-    parsed
 }
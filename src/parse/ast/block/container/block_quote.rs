@@ -0,0 +1,203 @@
+use super::ContainerLines;
+use crate::{
+    ast::block::BlockQuote,
+    parse::{
+        input::Input, parsers::indented_by_less_than_4, predicates::is_space_or_tab, traits::Parse,
+    },
+};
+use parser::{ItemsIndices, ParseResult, Parser, maybe, recognize, tag, take};
+
+/// Parses a block quote marker at the beginning of the line.
+///
+/// The marker is made of up to 3 spaces of indentation, a `>` character and an optional space or tab.
+/// On success, the remaining of the line is the block quote content.
+// TODO: a tab following the `>` should only count for one column of whitespace.
+pub fn block_quote_marker(input: &str) -> ParseResult<&str, &str> {
+    recognize((
+        indented_by_less_than_4,
+        tag(">"),
+        maybe(take(1).that(is_space_or_tab)),
+    ))
+    .parse(input)
+}
+
+impl<'a> Parse<'a> for BlockQuote<'a> {
+    fn parse<I: Input<'a>>(input: I) -> ParseResult<I, Self> {
+        let Some((lines, end)) = block_quote_lines(&input) else {
+            return Err(input);
+        };
+        let (segments, blocks) = lines.into_parts();
+        let (_, remaining) = input.split_at(end);
+        Ok((remaining, BlockQuote::new(segments, blocks)))
+    }
+}
+
+/// Returns the lines of the block quote starting the input, if any, with the index where it ends.
+pub(super) fn block_quote_lines<'a, I: Input<'a>>(
+    input: &I,
+) -> Option<(ContainerLines<'a>, I::Index)> {
+    let mut lines = ContainerLines::new(input);
+    let mut enumerator = <I as ItemsIndices<&'a str>>::enumerate(input);
+    let end = loop {
+        let next_index = enumerator.next_index();
+        match enumerator.next() {
+            Some((index, line))
+                if push_line(&mut lines, line, input.is_lazy_continuation(index)) => {}
+            _ => break next_index,
+        }
+    };
+    (!lines.is_empty()).then_some((lines, end))
+}
+
+/// Pushes the line to the lines of the block quote, returning whether it is part of it.
+///
+/// The line is lazy when the container holding the block quote took it as a lazy continuation line.
+pub(super) fn push_line<'a>(lines: &mut ContainerLines<'a>, line: &'a str, lazy: bool) -> bool {
+    if let Ok((content, _)) = block_quote_marker(line) {
+        // Only the last line can be emptied by removing the marker.
+        lines.push_explicit(line, content);
+    } else if !lines.is_empty() && (lazy || lines.is_lazy_continuation(line)) {
+        lines.push_lazy(line);
+    } else {
+        // The first line must have the marker.
+        return false;
+    }
+    true
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod block_quote_marker {
+        use super::*;
+
+        #[test]
+        fn should_reject_empty() {
+            assert!(block_quote_marker("").is_err());
+        }
+
+        #[test]
+        fn should_reject_4_spaces_of_indentation() {
+            assert!(block_quote_marker("    > foo").is_err());
+        }
+
+        #[test]
+        fn should_work_without_space() {
+            assert_eq!(block_quote_marker(">foo"), Ok(("foo", ">")));
+        }
+
+        #[test]
+        fn should_consume_a_single_space() {
+            assert_eq!(block_quote_marker(">  foo"), Ok((" foo", "> ")));
+        }
+
+        #[test]
+        fn should_work_with_3_spaces_of_indentation() {
+            assert_eq!(block_quote_marker("   > foo"), Ok(("foo", "   > ")));
+        }
+    }
+
+    mod parse {
+        use super::*;
+        use crate::{
+            Segments,
+            ast::block::{AtxHeading, Block, Container, Leaf, Paragraph, SetextHeading},
+            parse::{
+                ast::block::container::MAX_DEPTH,
+                test_utils::{StrictParse, test_parse_macros},
+            },
+        };
+
+        test_parse_macros!(BlockQuote);
+
+        failure_case!(should_reject_empty, "");
+        failure_case!(should_reject_paragraph, "foo\n");
+        failure_case!(should_reject_indented_code, "    > foo\n");
+
+        success_case!(
+            should_work_with_empty_block_quote,
+            ">",
+            parsed => BlockQuote::new(vec![">"], vec![])
+        );
+        success_case!(
+            should_work_with_heading_and_paragraph,
+            "> # Foo\n> bar\n",
+            parsed => BlockQuote::new(
+                vec!["> # Foo\n", "> bar\n"],
+                vec![
+                    Block::Leaf(Leaf::AtxHeading(AtxHeading::strict_parse("# Foo\n"))),
+                    Block::Leaf(Leaf::Paragraph(Paragraph::new(vec!["bar\n"]))),
+                ]
+            )
+        );
+        success_case!(
+            should_include_lazy_continuation_lines,
+            "> foo\nbar\n",
+            parsed => BlockQuote::new(
+                vec!["> foo\n", "bar\n"],
+                vec![Block::Leaf(Leaf::Paragraph(Paragraph::new(vec!["foo\n", "bar\n"])))]
+            )
+        );
+        success_case!(
+            should_stop_at_blank_line,
+            "> foo\n\n> bar\n",
+            parsed => BlockQuote::new(
+                vec!["> foo\n"],
+                vec![Block::Leaf(Leaf::Paragraph(Paragraph::new(vec!["foo\n"])))]
+            ),
+            "\n> bar\n"
+        );
+        success_case!(
+            should_not_continue_lazily_after_a_closed_block,
+            "> # Foo\nbar\n",
+            parsed => BlockQuote::new(
+                vec!["> # Foo\n"],
+                vec![Block::Leaf(Leaf::AtxHeading(AtxHeading::strict_parse("# Foo\n")))]
+            ),
+            "bar\n"
+        );
+        success_case!(
+            should_not_continue_lazily_after_a_paragraph_turned_into_a_heading,
+            "> foo\n> bar\n> ---\nbaz\n",
+            parsed => BlockQuote::new(
+                vec!["> foo\n", "> bar\n", "> ---\n"],
+                vec![Block::Leaf(Leaf::SetextHeading(SetextHeading::strict_parse(
+                    "foo\nbar\n---\n"
+                )))]
+            ),
+            "baz\n"
+        );
+        success_case!(
+            should_continue_nested_paragraph_lazily,
+            "> > foo\nbar\n",
+            parsed => BlockQuote::new(
+                vec!["> > foo\n", "bar\n"],
+                vec![Block::Container(Container::BlockQuote(BlockQuote::new(
+                    vec!["> foo\n", "bar\n"],
+                    vec![Block::Leaf(Leaf::Paragraph(Paragraph::new(vec!["foo\n", "bar\n"])))]
+                )))]
+            )
+        );
+
+        #[test]
+        fn should_take_markers_beyond_the_maximum_depth_for_paragraph_text() {
+            let source = "> ".repeat(10_000) + "a";
+            let document = crate::parse(&source);
+            let mut blocks = document.blocks();
+            for _ in 0..MAX_DEPTH {
+                let [Block::Container(Container::BlockQuote(block_quote))] = blocks else {
+                    panic!("expected a block quote: {blocks:?}");
+                };
+                blocks = block_quote.blocks();
+            }
+            let [Block::Leaf(Leaf::Paragraph(paragraph))] = blocks else {
+                panic!("expected a paragraph: {blocks:?}");
+            };
+            assert_eq!(
+                paragraph.segments().collect::<String>(),
+                "> ".repeat(10_000 - MAX_DEPTH) + "a"
+            );
+        }
+    }
+}
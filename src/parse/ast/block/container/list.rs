@@ -0,0 +1,113 @@
+use crate::{
+    ast::block::{Block, Leaf, List, ListItem},
+    parse::{input::Input, predicates::is_blank_line, traits::Parse},
+};
+use parser::ParseResult;
+
+impl<'a> Parse<'a> for List<'a> {
+    fn parse<I: Input<'a>>(input: I) -> ParseResult<I, Self> {
+        let Ok((mut remaining, first_item)) = ListItem::parse(input.clone()) else {
+            return Err(input);
+        };
+        let marker = first_item.marker();
+        let mut items = vec![first_item];
+        let mut loose = false;
+        loop {
+            // Items can be separated by blank lines, but that makes the list loose.
+            let blank_lines: Vec<&str> = remaining
+                .lines()
+                .take_while(|line| is_blank_line(line))
+                .collect();
+            let after_blank_lines = match blank_lines.last() {
+                Some(last) => {
+                    let (_, end) = remaining.subset_range(*last);
+                    remaining.split_at(end).1
+                }
+                None => remaining.clone(),
+            };
+            match ListItem::parse(after_blank_lines) {
+                Ok((after_item, item)) if item.marker().is_same_type(&marker) => {
+                    loose |= !blank_lines.is_empty();
+                    items
+                        .last_mut()
+                        .expect("unexpected empty list")
+                        .extend_segments(blank_lines);
+                    items.push(item);
+                    remaining = after_item;
+                }
+                _ => break,
+            }
+        }
+        loose |= items
+            .iter()
+            .any(|item| has_blank_line_between_blocks(item.blocks()));
+        Ok((remaining, List::new(items, !loose)))
+    }
+}
+
+/// Returns whether the blocks of an item are separated by a blank line.
+///
+/// List items never start nor end with a blank line, so any blank line is between blocks.
+fn has_blank_line_between_blocks(blocks: &[Block]) -> bool {
+    blocks
+        .iter()
+        .any(|block| matches!(block, Block::Leaf(Leaf::BlankLine(_))))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::parse::test_utils::StrictParse;
+
+    mod parse {
+        use super::*;
+        use crate::parse::Lines;
+
+        #[test]
+        fn should_reject_paragraph() {
+            assert!(List::parse(Lines::from("foo\n")).is_err());
+        }
+
+        #[test]
+        fn should_work_with_tight_list() {
+            let list = List::strict_parse("- foo\n- bar\n");
+            assert_eq!(list.items().len(), 2);
+            assert!(list.is_tight());
+            assert_eq!(list.start(), None);
+        }
+
+        #[test]
+        fn should_be_loose_with_blank_line_between_items() {
+            let list = List::strict_parse("1. foo\n\n2. bar\n");
+            assert_eq!(list.items().len(), 2);
+            assert!(!list.is_tight());
+            assert_eq!(list.start(), Some(1));
+        }
+
+        #[test]
+        fn should_be_loose_with_blank_line_between_blocks_of_an_item() {
+            let list = List::strict_parse("- foo\n\n  bar\n- baz\n");
+            assert!(!list.is_tight());
+        }
+
+        #[test]
+        fn should_stay_tight_with_blank_line_in_nested_list() {
+            let list = List::strict_parse("- foo\n  - bar\n\n    baz\n");
+            assert!(list.is_tight());
+        }
+
+        #[test]
+        fn should_stop_at_item_of_another_type() {
+            let (remaining, list) = List::parse(Lines::from("- foo\n+ bar\n")).unwrap();
+            assert_eq!(list.items().len(), 1);
+            assert_eq!(remaining, Lines::from("+ bar\n"));
+        }
+
+        #[test]
+        fn should_leave_trailing_blank_lines() {
+            let (remaining, list) = List::parse(Lines::from("3) foo\n\n")).unwrap();
+            assert_eq!(list.start(), Some(3));
+            assert_eq!(remaining, Lines::from("\n"));
+        }
+    }
+}
@@ -0,0 +1,535 @@
+use super::ContainerLines;
+use crate::{
+    ast::block::{ListItem, ListMarker, TaskListMarker, ThematicBreak},
+    parse::{
+        input::Input,
+        parsers::indented_by_less_than_4,
+        predicates::is_blank_line,
+        traits::{Parse, ParseLine},
+    },
+};
+use parser::{ItemsIndices, Map, ParseResult, Parser, is_one_of, one_of, take, take_while};
+
+/// The first line of a list item.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ListItemStart<'a> {
+    pub marker: ListMarker,
+    /// The content following the marker, stripped of the whitespace separating them.
+    pub content: &'a str,
+    /// The amount of columns continuation lines must be indented by to be part of the item.
+    pub width: usize,
+}
+
+impl ListItemStart<'_> {
+    /// Returns whether the item starts with a blank line.
+    pub fn is_empty(&self) -> bool {
+        self.content.is_empty() || is_blank_line(self.content)
+    }
+}
+
+/// Parses a bullet or ordered list marker.
+fn list_marker(input: &str) -> ParseResult<&str, ListMarker> {
+    one_of((
+        take(1)
+            .that(is_one_of(&['-', '+', '*']))
+            .map(|bullet: &str| ListMarker::Bullet(first_char(bullet))),
+        (
+            take_while(|c: char| c.is_ascii_digit())
+                .at_least(1)
+                .at_most(9),
+            take(1).that(is_one_of(&['.', ')'])),
+        )
+            .map(|(digits, delimiter): (&str, &str)| ListMarker::Ordered {
+                number: digits.parse().expect("unexpected invalid list item number"),
+                delimiter: first_char(delimiter),
+            }),
+    ))
+    .parse(input)
+}
+
+fn first_char(segment: &str) -> char {
+    segment.chars().next().expect("unexpected empty segment")
+}
+
+/// Returns the start of the list item opened by the line, if any.
+///
+/// Thematic breaks that could be mistaken for bullet list items, like `* * *`, are not list items.
+// TODO: tabs are expanded relative to the line, rather than to the container content.
+pub fn list_item_start(line: &str) -> Option<ListItemStart<'_>> {
+    if ThematicBreak::parse_line(line).is_ok() {
+        return None;
+    }
+    let (after_marker, (_, marker)) = (indented_by_less_than_4, list_marker).parse(line).ok()?;
+    let marker_width = line.len() - after_marker.len();
+    let (whitespace, whitespace_width) = leading_whitespace(after_marker, marker_width);
+    let rest = &after_marker[whitespace.len()..];
+    let (content, width) = if rest.is_empty() || is_blank_line(rest) {
+        // The item starts with a blank line.
+        (after_marker, marker_width + 1)
+    } else if whitespace.is_empty() {
+        return None;
+    } else if whitespace_width >= 5 {
+        // The content starts with indented code, and only one space is part of the marker.
+        (&after_marker[1..], marker_width + 1)
+    } else {
+        (rest, marker_width + whitespace_width)
+    };
+    Some(ListItemStart {
+        marker,
+        content,
+        width,
+    })
+}
+
+/// Returns whether the line starts a list item that can interrupt a paragraph.
+///
+/// Only the items that don't start with a blank line can, and ordered ones must start with 1.
+pub fn list_item_interrupts_paragraph(line: &str) -> bool {
+    match list_item_start(line) {
+        Some(start) if !start.is_empty() => match start.marker {
+            ListMarker::Bullet(_) => true,
+            ListMarker::Ordered { number, .. } => number == 1,
+        },
+        _ => false,
+    }
+}
+
+/// Splits the checkbox of a task list item from the content following the list marker, if it
+/// starts with one, like `[ ]` or `[x]`.
+///
+/// The checkbox is followed by whitespace, or ends the line. Returns the checkbox and the content
+/// following it, stripped of the whitespace separating them.
+pub fn task_list_marker(content: &str) -> Option<(&str, &str)> {
+    let bytes = content.as_bytes();
+    if bytes.len() < 3 || bytes[0] != b'[' || !b" \txX".contains(&bytes[1]) || bytes[2] != b']' {
+        return None;
+    }
+    let (checkbox, rest) = content.split_at(3);
+    if !rest.is_empty() && !rest.starts_with([' ', '\t', '\r', '\n']) {
+        return None;
+    }
+    Some((checkbox, rest.trim_start_matches([' ', '\t'])))
+}
+
+/// Returns the leading spaces and tabs of the segment, with their width in columns.
+///
+/// Tabs stop at multiples of 4, the first character of the segment being at the given column.
+fn leading_whitespace(segment: &str, column: usize) -> (&str, usize) {
+    let mut end_column = column;
+    for (index, char) in segment.char_indices() {
+        match char {
+            ' ' => end_column += 1,
+            '\t' => end_column += 4 - end_column % 4,
+            _ => return (&segment[..index], end_column - column),
+        }
+    }
+    (segment, end_column - column)
+}
+
+/// Strips at most `width` columns of indentation from the line, returning the remaining and the
+/// amount of columns stripped.
+///
+/// A tab overflowing the width is stripped entirely.
+fn strip_indentation(line: &str, width: usize) -> (&str, usize) {
+    let mut column = 0;
+    for (index, char) in line.char_indices() {
+        if column >= width {
+            return (&line[index..], column);
+        }
+        match char {
+            ' ' => column += 1,
+            '\t' => column += 4 - column % 4,
+            _ => return (&line[index..], column),
+        }
+    }
+    ("", column)
+}
+
+impl<'a> Parse<'a> for ListItem<'a> {
+    fn parse<I: Input<'a>>(input: I) -> ParseResult<I, Self> {
+        let Some((lines, end)) = list_item_lines(&input) else {
+            return Err(input);
+        };
+        let (_, remaining) = input.split_at(end);
+        Ok((remaining, lines.into_item()))
+    }
+}
+
+/// The lines of a list item, accumulated while it is being parsed.
+pub(super) struct ListItemLines<'a> {
+    lines: ContainerLines<'a>,
+    marker: ListMarker,
+    /// The checkbox of a task list item.
+    checkbox: Option<&'a str>,
+    /// The amount of columns continuation lines must be indented by to be part of the item.
+    width: usize,
+    /// The blank lines following the content, which are only part of the item when followed by
+    /// more content.
+    blank_lines: Vec<&'a str>,
+}
+
+impl<'a> ListItemLines<'a> {
+    /// Creates the lines of the list item started by the first line of the input.
+    fn new<I: Input<'a>>(input: &I, first_line: &'a str, start: ListItemStart<'a>) -> Self {
+        let task_list = if input.options().task_lists && !start.is_empty() {
+            task_list_marker(start.content)
+        } else {
+            None
+        };
+        let content = task_list.map_or(start.content, |(_, content)| content);
+        let mut lines = ContainerLines::new(input);
+        // A first blank line is not part of the content, even when it follows a checkbox.
+        lines.push_explicit(
+            first_line,
+            if content.is_empty() || is_blank_line(content) {
+                ""
+            } else {
+                content
+            },
+        );
+        Self {
+            lines,
+            marker: start.marker,
+            checkbox: task_list.map(|(checkbox, _)| checkbox),
+            width: start.width,
+            blank_lines: Vec::new(),
+        }
+    }
+
+    /// Pushes the line to the lines of the item, returning whether it is part of it, or could be
+    /// for a blank line.
+    ///
+    /// The line is lazy when the container holding the item took it as a lazy continuation line.
+    pub(super) fn push(&mut self, line: &'a str, lazy: bool) -> bool {
+        if is_blank_line(line) {
+            // A list item can begin with at most one blank line.
+            if !self.lines.has_content() {
+                return false;
+            }
+            self.blank_lines.push(line);
+            return true;
+        }
+        match strip_indentation(line, self.width) {
+            (content, stripped) if stripped >= self.width => {
+                for blank_line in self.blank_lines.drain(..) {
+                    self.lines
+                        .push_explicit(blank_line, strip_indentation(blank_line, self.width).0);
+                }
+                self.lines.push_explicit(line, content);
+            }
+            _ if self.blank_lines.is_empty() && (lazy || self.lines.is_lazy_continuation(line)) => {
+                self.lines.push_lazy(line);
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    /// Returns whether the content ends with a paragraph that a lazy continuation line could
+    /// extend, which blank lines end.
+    pub(super) fn ends_with_open_paragraph(&mut self) -> bool {
+        self.blank_lines.is_empty() && self.lines.ends_with_open_paragraph()
+    }
+
+    fn into_item(self) -> ListItem<'a> {
+        let (segments, blocks) = self.lines.into_parts();
+        let item = ListItem::new(segments, self.marker, blocks);
+        match self.checkbox {
+            Some(checkbox) => item.with_task_list_marker(TaskListMarker::new(checkbox)),
+            None => item,
+        }
+    }
+}
+
+/// Returns the lines of the list item starting the input, if any, with the index where it ends.
+pub(super) fn list_item_lines<'a, I: Input<'a>>(
+    input: &I,
+) -> Option<(ListItemLines<'a>, I::Index)> {
+    let mut enumerator = <I as ItemsIndices<&'a str>>::enumerate(input);
+    let (first_line, start) = enumerator
+        .next()
+        .and_then(|(_, line)| Some((line, list_item_start(line)?)))?;
+    let mut lines = ListItemLines::new(input, first_line, start);
+    let mut end = enumerator.next_index();
+    while let Some((index, line)) = enumerator.next() {
+        if !lines.push(line, input.is_lazy_continuation(index)) {
+            break;
+        }
+        // Blank lines are only part of the item when followed by more content.
+        if !is_blank_line(line) {
+            end = enumerator.next_index();
+        }
+    }
+    Some((lines, end))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod list_item_start {
+        use super::*;
+
+        #[test]
+        fn should_reject_empty() {
+            assert_eq!(list_item_start(""), None);
+        }
+
+        #[test]
+        fn should_reject_marker_without_space() {
+            assert_eq!(list_item_start("-foo\n"), None);
+        }
+
+        #[test]
+        fn should_reject_thematic_break() {
+            assert_eq!(list_item_start("* * *\n"), None);
+        }
+
+        #[test]
+        fn should_reject_4_spaces_of_indentation() {
+            assert_eq!(list_item_start("    - foo\n"), None);
+        }
+
+        #[test]
+        fn should_reject_10_digits() {
+            assert_eq!(list_item_start("1234567890. foo\n"), None);
+        }
+
+        #[test]
+        fn should_work_with_bullet() {
+            assert_eq!(
+                list_item_start("- foo\n"),
+                Some(ListItemStart {
+                    marker: ListMarker::Bullet('-'),
+                    content: "foo\n",
+                    width: 2,
+                })
+            );
+        }
+
+        #[test]
+        fn should_work_with_ordered_marker() {
+            assert_eq!(
+                list_item_start(" 12)  foo\n"),
+                Some(ListItemStart {
+                    marker: ListMarker::Ordered {
+                        number: 12,
+                        delimiter: ')'
+                    },
+                    content: "foo\n",
+                    width: 6,
+                })
+            );
+        }
+
+        #[test]
+        fn should_only_count_one_space_before_indented_code() {
+            assert_eq!(
+                list_item_start("-     foo\n"),
+                Some(ListItemStart {
+                    marker: ListMarker::Bullet('-'),
+                    content: "    foo\n",
+                    width: 2,
+                })
+            );
+        }
+
+        #[test]
+        fn should_work_with_blank_content() {
+            assert_eq!(
+                list_item_start("*   \n"),
+                Some(ListItemStart {
+                    marker: ListMarker::Bullet('*'),
+                    content: "   \n",
+                    width: 2,
+                })
+            );
+        }
+    }
+
+    mod task_list_marker {
+        use super::*;
+
+        #[test]
+        fn should_split_the_checkbox_from_the_content() {
+            assert_eq!(task_list_marker("[x]  foo\n"), Some(("[x]", "foo\n")));
+        }
+
+        #[test]
+        fn should_work_with_uppercase_x_and_whitespace() {
+            assert_eq!(task_list_marker("[X]\tfoo\n"), Some(("[X]", "foo\n")));
+            assert_eq!(task_list_marker("[\t] foo\n"), Some(("[\t]", "foo\n")));
+        }
+
+        #[test]
+        fn should_work_at_the_end_of_the_line() {
+            assert_eq!(task_list_marker("[ ]\n"), Some(("[ ]", "\n")));
+            assert_eq!(task_list_marker("[ ]"), Some(("[ ]", "")));
+        }
+
+        #[test]
+        fn should_reject_checkbox_not_followed_by_whitespace() {
+            assert_eq!(task_list_marker("[ ]foo\n"), None);
+        }
+
+        #[test]
+        fn should_reject_other_characters_in_brackets() {
+            assert_eq!(task_list_marker("[y] foo\n"), None);
+            assert_eq!(task_list_marker("[  ] foo\n"), None);
+        }
+    }
+
+    mod list_item_interrupts_paragraph {
+        use super::*;
+
+        #[test]
+        fn should_return_true_for_bullet() {
+            assert!(list_item_interrupts_paragraph("+ foo\n"));
+        }
+
+        #[test]
+        fn should_return_true_for_ordered_item_starting_with_1() {
+            assert!(list_item_interrupts_paragraph("1. foo\n"));
+        }
+
+        #[test]
+        fn should_return_false_for_ordered_item_not_starting_with_1() {
+            assert!(!list_item_interrupts_paragraph("2. foo\n"));
+        }
+
+        #[test]
+        fn should_return_false_for_empty_item() {
+            assert!(!list_item_interrupts_paragraph("-\n"));
+        }
+    }
+
+    mod parse {
+        use super::*;
+        use crate::{
+            Segments,
+            ast::block::{BlankLine, Block, Container, IndentedCode, Leaf, Paragraph},
+            parse::{
+                ast::block::container::MAX_DEPTH,
+                test_utils::{StrictParse, test_parse_macros},
+            },
+        };
+
+        test_parse_macros!(ListItem);
+
+        failure_case!(should_reject_empty, "");
+        failure_case!(should_reject_paragraph, "foo\n");
+        failure_case!(should_reject_thematic_break, "- - -\n");
+
+        success_case!(
+            should_work_with_empty_item,
+            "-\n",
+            parsed => ListItem::new(vec!["-\n"], ListMarker::Bullet('-'), vec![])
+        );
+        success_case!(
+            should_work_with_indented_continuation,
+            "- foo\n\n  bar\n",
+            parsed => ListItem::new(
+                vec!["- foo\n", "\n", "  bar\n"],
+                ListMarker::Bullet('-'),
+                vec![
+                    Block::Leaf(Leaf::Paragraph(Paragraph::new(vec!["foo\n"]))),
+                    Block::Leaf(Leaf::BlankLine(BlankLine::strict_parse("\n"))),
+                    Block::Leaf(Leaf::Paragraph(Paragraph::new(vec!["bar\n"]))),
+                ]
+            )
+        );
+        success_case!(
+            should_include_lazy_continuation_lines,
+            "- foo\nbar\n",
+            parsed => ListItem::new(
+                vec!["- foo\n", "bar\n"],
+                ListMarker::Bullet('-'),
+                vec![Block::Leaf(Leaf::Paragraph(Paragraph::new(vec!["foo\n", "bar\n"])))]
+            )
+        );
+        success_case!(
+            should_leave_trailing_blank_lines,
+            "1. foo\n\n",
+            parsed => ListItem::new(
+                vec!["1. foo\n"],
+                ListMarker::Ordered { number: 1, delimiter: '.' },
+                vec![Block::Leaf(Leaf::Paragraph(Paragraph::new(vec!["foo\n"])))]
+            ),
+            "\n"
+        );
+        success_case!(
+            should_stop_at_insufficient_indentation,
+            "- foo\n\n bar\n",
+            parsed => ListItem::new(
+                vec!["- foo\n"],
+                ListMarker::Bullet('-'),
+                vec![Block::Leaf(Leaf::Paragraph(Paragraph::new(vec!["foo\n"])))]
+            ),
+            "\n bar\n"
+        );
+        success_case!(
+            should_stop_after_two_blank_lines_at_start,
+            "-\n\n  foo\n",
+            parsed => ListItem::new(vec!["-\n"], ListMarker::Bullet('-'), vec![]),
+            "\n  foo\n"
+        );
+        success_case!(
+            should_work_with_indented_code,
+            "-     foo\n",
+            parsed => ListItem::new(
+                vec!["-     foo\n"],
+                ListMarker::Bullet('-'),
+                vec![Block::Leaf(Leaf::IndentedCode(IndentedCode::strict_parse("    foo\n")))]
+            )
+        );
+
+        #[test]
+        fn should_take_markers_beyond_the_maximum_depth_for_paragraph_text() {
+            let source = "- ".repeat(10_000) + "a";
+            let document = crate::parse(&source);
+            let mut blocks = document.blocks();
+            for _ in 0..MAX_DEPTH {
+                let [Block::Container(Container::List(list))] = blocks else {
+                    panic!("expected a list: {blocks:?}");
+                };
+                let [item] = list.items() else {
+                    panic!("expected a single item: {list:?}");
+                };
+                blocks = item.blocks();
+            }
+            let [Block::Leaf(Leaf::Paragraph(paragraph))] = blocks else {
+                panic!("expected a paragraph: {blocks:?}");
+            };
+            assert_eq!(
+                paragraph.segments().collect::<String>(),
+                "- ".repeat(10_000 - MAX_DEPTH) + "a"
+            );
+        }
+
+        #[test]
+        fn should_count_every_kind_of_container_towards_the_maximum_depth() {
+            let source = "> 1. ".repeat(10_000) + "a";
+            let document = crate::parse(&source);
+            let mut blocks = document.blocks();
+            for depth in 0..MAX_DEPTH {
+                blocks = match blocks {
+                    [Block::Container(Container::BlockQuote(block_quote))] if depth % 2 == 0 => {
+                        block_quote.blocks()
+                    }
+                    [Block::Container(Container::List(list))] if depth % 2 == 1 => {
+                        list.items()[0].blocks()
+                    }
+                    _ => panic!("unexpected blocks at depth {depth}: {blocks:?}"),
+                };
+            }
+            let [Block::Leaf(Leaf::Paragraph(paragraph))] = blocks else {
+                panic!("expected a paragraph: {blocks:?}");
+            };
+            assert!(
+                paragraph
+                    .segments()
+                    .all(|segment| segment.starts_with("> 1. "))
+            );
+        }
+    }
+}
@@ -1 +1,243 @@
+pub mod block_quote;
+pub mod list;
+pub mod list_item;
 
+use super::{
+    blocks,
+    leaf::{
+        paragraph::{is_paragraph_line, is_setext_heading_underline},
+        table::delimiter_row,
+    },
+    next_blocks,
+};
+use crate::{
+    ParseOptions, Segments,
+    ast::block::{Block, BlockQuote, Container, Leaf, List},
+    parse::{StrippedLine, StrippedLines, input::Input, traits::Parse},
+};
+use block_quote::block_quote_marker;
+use list_item::{ListItemLines, list_item_start};
+use parser::{IsEmpty, Map, ParseResult, Parser, one_of};
+
+/// How many containers can be nested in one another.
+///
+/// The content of a container is parsed recursively, so deeper containers could overflow the
+/// stack. Beyond this depth, what would start a container is paragraph text instead.
+pub const MAX_DEPTH: usize = 64;
+
+impl<'a> Parse<'a> for Container<'a> {
+    fn parse<I: Input<'a>>(input: I) -> ParseResult<I, Self> {
+        if input.depth() >= MAX_DEPTH {
+            return Err(input);
+        }
+        one_of((
+            BlockQuote::parse.map(Container::BlockQuote),
+            List::parse.map(Container::List),
+        ))
+        .parse(input)
+    }
+}
+
+/// Returns whether the line would start a container if the input was not nested too deep, in which
+/// case it is paragraph text.
+pub fn is_too_deep_container_start<'a, I: Input<'a>>(input: &I, line: &str) -> bool {
+    input.depth() >= MAX_DEPTH
+        && (block_quote_marker(line).is_ok() || list_item_start(line).is_some())
+}
+
+/// The lines of a container, accumulated while it is being parsed.
+struct ContainerLines<'a> {
+    /// The source lines.
+    segments: Vec<&'a str>,
+    /// The content lines, stripped of the container markers and indentation.
+    lines: Vec<StrippedLine<'a>>,
+    /// The last block of the content, tracked as the lines are pushed.
+    last_block: LastBlock<'a>,
+    /// The options of the parsing, which carry over to the content.
+    options: ParseOptions,
+    /// How many containers the content is nested in, this one included.
+    depth: usize,
+}
+
+/// The last block of a container's content, as much as it takes to tell whether a lazy
+/// continuation line could extend it.
+///
+/// It is updated with every pushed line, so that the content is only parsed again from the start
+/// of its last block, and only when a line turns it into something else than a paragraph.
+enum LastBlock<'a> {
+    /// A block starting at the given line, which takes parsing the lines from there to know.
+    Unknown(usize),
+    /// A block starting at the given line, which is known not to be a paragraph.
+    Closed(usize),
+    /// A paragraph starting at the given line, with its last line, which could be the header of
+    /// a table.
+    Paragraph(usize, &'a str),
+    /// A block quote, whose content is tracked in turn.
+    BlockQuote(Box<ContainerLines<'a>>),
+    /// A list, of which only the last item, whose content is tracked in turn, matters.
+    List(Box<ListItemLines<'a>>),
+}
+
+impl<'a> ContainerLines<'a> {
+    /// Creates the lines of a container starting in the input.
+    fn new<I: Input<'a>>(input: &I) -> Self {
+        Self {
+            segments: Vec::new(),
+            lines: Vec::new(),
+            last_block: LastBlock::Unknown(0),
+            options: *input.options(),
+            depth: input.depth() + 1,
+        }
+    }
+
+    /// Returns whether no line was pushed yet.
+    fn is_empty(&self) -> bool {
+        self.segments.is_empty()
+    }
+
+    /// Returns whether some content was pushed yet.
+    fn has_content(&self) -> bool {
+        !self.lines.is_empty()
+    }
+
+    /// Pushes a line explicitly marked as part of the container, along with its stripped content.
+    ///
+    /// Empty content is ignored.
+    fn push_explicit(&mut self, segment: &'a str, content: &'a str) {
+        self.segments.push(segment);
+        if !content.is_empty() {
+            self.lines.push(StrippedLine::explicit(content));
+            self.update_last_block(content, false);
+        }
+    }
+
+    /// Pushes a lazy continuation line, which continues the paragraph ending the content.
+    fn push_lazy(&mut self, line: &'a str) {
+        self.segments.push(line);
+        self.lines.push(StrippedLine::lazy(line));
+        self.update_last_block(line, true);
+    }
+
+    /// Updates the last block with the content line that was just pushed.
+    fn update_last_block(&mut self, line: &'a str, lazy: bool) {
+        let index = self.lines.len() - 1;
+        self.last_block = match std::mem::replace(&mut self.last_block, LastBlock::Unknown(index)) {
+            LastBlock::Unknown(start) | LastBlock::Closed(start) => LastBlock::Unknown(start),
+            // The paragraph becomes a setext heading.
+            LastBlock::Paragraph(start, _) if !lazy && is_setext_heading_underline(line) => {
+                LastBlock::Unknown(start)
+            }
+            // The paragraph could become a table, with its last line as header.
+            LastBlock::Paragraph(start, last)
+                if self.options.tables
+                    && (lazy || is_paragraph_line(line))
+                    && delimiter_row(last, line).is_some() =>
+            {
+                LastBlock::Unknown(start)
+            }
+            LastBlock::Paragraph(start, _) if lazy || is_paragraph_line(line) => {
+                LastBlock::Paragraph(start, line)
+            }
+            // The line ends the paragraph, and starts the next block.
+            LastBlock::Paragraph(..) => LastBlock::Unknown(index),
+            LastBlock::BlockQuote(mut lines) => {
+                if block_quote::push_line(&mut lines, line, lazy) {
+                    LastBlock::BlockQuote(lines)
+                } else {
+                    LastBlock::Unknown(index)
+                }
+            }
+            LastBlock::List(mut item) => {
+                if item.push(line, lazy) {
+                    LastBlock::List(item)
+                } else {
+                    LastBlock::Unknown(index)
+                }
+            }
+        };
+    }
+
+    /// Returns whether the line, missing the container markers, is a lazy continuation line.
+    fn is_lazy_continuation(&mut self, line: &str) -> bool {
+        // List item starts are excluded, as any of them can start a new list at this point.
+        if !is_paragraph_line(line) || list_item_start(line).is_some() {
+            return false;
+        }
+        self.ends_with_open_paragraph()
+    }
+
+    /// Returns whether the content ends with a paragraph that a lazy continuation line could
+    /// extend.
+    ///
+    /// The paragraph could be nested in containers, in which case the line would be lazy for all of
+    /// them.
+    fn ends_with_open_paragraph(&mut self) -> bool {
+        if let LastBlock::Unknown(start) = self.last_block {
+            self.last_block = self.parse_last_block(start);
+        }
+        match &mut self.last_block {
+            LastBlock::Unknown(_) => unreachable!("unexpected unknown last block"),
+            LastBlock::Closed(_) => false,
+            LastBlock::Paragraph(..) => true,
+            LastBlock::BlockQuote(lines) => lines.ends_with_open_paragraph(),
+            LastBlock::List(item) => item.ends_with_open_paragraph(),
+        }
+    }
+
+    /// Parses the content from the given line, which starts a block, to find the last block.
+    ///
+    /// The lines of a container ending the content are pushed again to track its own content.
+    fn parse_last_block(&self, start: usize) -> LastBlock<'a> {
+        let mut input = self.content(start);
+        let mut last = None;
+        while !input.is_empty() {
+            let (remaining, blocks) =
+                next_blocks(input).expect("unexpected error parsing container content");
+            last = Some((input, blocks));
+            input = remaining;
+        }
+        let Some((input, blocks)) = last else {
+            return LastBlock::Closed(start);
+        };
+        // Parsing again from the start of the last step gives the same blocks.
+        let start = self.lines.len() - input.lines().count();
+        match blocks.last() {
+            // Link reference definitions are extracted from paragraphs, and the paragraph
+            // continues after them.
+            Some(Block::Leaf(Leaf::Paragraph(_) | Leaf::LinkReferenceDefinition(_))) => {
+                let last = input.lines().last().expect("unexpected empty block");
+                LastBlock::Paragraph(start, last)
+            }
+            Some(Block::Container(Container::BlockQuote(_))) => {
+                let (lines, _) = block_quote::block_quote_lines(&input)
+                    .expect("unexpected error pushing block quote lines");
+                LastBlock::BlockQuote(Box::new(lines))
+            }
+            Some(Block::Container(Container::List(list))) => {
+                let item = list.items().last().expect("unexpected empty list");
+                let item_start = self.lines.len() - item.segments().count();
+                let (item, _) = list_item::list_item_lines(&self.content(item_start))
+                    .expect("unexpected error pushing list item lines");
+                LastBlock::List(Box::new(item))
+            }
+            _ => LastBlock::Closed(start),
+        }
+    }
+
+    /// Returns the content from the given line.
+    fn content(&self, start: usize) -> StrippedLines<'_, 'a> {
+        StrippedLines::with_options(&self.lines[start..], self.options).with_depth(self.depth)
+    }
+
+    /// Returns the source lines and the blocks parsed from the content.
+    fn into_parts(self) -> (Vec<&'a str>, Vec<Block<'a>>) {
+        let (remaining, blocks) =
+            blocks(StrippedLines::with_options(&self.lines, self.options).with_depth(self.depth))
+                .expect("unexpected error parsing container content");
+        debug_assert!(
+            remaining.is_empty(),
+            "unparsed container content: {remaining:?}"
+        );
+        (self.segments, blocks)
+    }
+}
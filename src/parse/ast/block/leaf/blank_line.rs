@@ -5,11 +5,13 @@ use crate::{
         traits::ParseLine,
     },
 };
-use parser::{Map, ParseResult, Parser, empty, one_of, recognize};
+use parser::{Map, ParseResult, Parser, empty, one_of, recognize, tag};
 
 pub fn blank_line<'a>(input: &'a str) -> ParseResult<&'a str, BlankLine<'a>> {
     recognize(one_of((
         (space_or_tab(), line_ending),
+        // A carriage return can end the last line without a line feed.
+        (space_or_tab(), recognize((tag("\r"), empty))),
         (space_or_tab().at_least(1), empty),
     )))
     .map(BlankLine::new)
@@ -39,5 +41,7 @@ mod test {
         success_case!(should_work_with_a_single_newline, "\n");
         success_case!(should_work_with_a_single_tab, "\t");
         success_case!(should_work_with_any_whitespace, " \t\r\n");
+        success_case!(should_work_with_a_single_carriage_return, "\r");
+        success_case!(should_work_with_whitespace_and_a_carriage_return, " \t\r");
     }
 }
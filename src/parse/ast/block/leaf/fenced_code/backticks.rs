@@ -22,7 +22,6 @@ fn content_or_closing_segment<'a, I: Input<'a>>(
         if input.is_empty() {
             return Err(input);
         }
-        println!("received input: {input:?}");
         match BackticksFencedCodeClosingSegment::parse
             .validate(|segment: &BackticksFencedCodeClosingSegment| segment.closes(opening))
             .map(ContentOrClosingSegment::Closing)
@@ -2,6 +2,7 @@ use crate::{
     ast::block::Html,
     parse::{
         input::Input,
+        lines,
         parsers::{indented_by_less_than_4, line_ending_or_empty, space_or_tab},
         predicates::is_blank_line,
         traits::Parse,
@@ -89,6 +90,23 @@ impl<'a> Parse<'a> for Html<'a> {
     }
 }
 
+/// Returns whether the line is the start of an HTML block that can interrupt a paragraph.
+///
+/// All HTML blocks can interrupt a paragraph, except those of type 7.
+pub fn interrupts_paragraph(line: &str) -> bool {
+    (
+        indented_by_less_than_4,
+        one_of((case_1, case_2, case_3, case_4, case_5, case_6)),
+    )
+        .parse(lines(line))
+        .is_ok()
+}
+
+/// Returns whether the line is the start of an HTML block, when it does not continue a paragraph.
+pub fn starts_html_block(line: &str) -> bool {
+    Html::parse(lines(line)).is_ok()
+}
+
 /// This is case 1 in the spec, and covers lines with the following
 /// - Start condition: line begins with the string <pre, <script, <style, or <textarea (case-insensitive),
 ///   followed by a space, a tab, the string >, or the end of the line.
@@ -211,18 +229,25 @@ fn case_7<'a, I: Input<'a>>(input: I) -> ParseResult<I, I> {
     };
 
     recognize((
-        one_of((
-            utils::open_tag(tag_name_validator),
-            utils::closing_tag(tag_name_validator),
-        )),
-        space_or_tab(),
-        line_ending_or_empty,
+        // The tag can span multiple lines, but the start condition is about the first line only.
+        validate(
+            recognize((
+                one_of((
+                    utils::open_tag(tag_name_validator),
+                    utils::closing_tag(tag_name_validator),
+                )),
+                space_or_tab(),
+                line_ending_or_empty,
+            )),
+            |start: &I| start.lines().count() == 1,
+        ),
         take_while(|s: &str| !is_blank_line(s)),
     ))
     .parse(input)
 }
 
-mod utils {
+/// The parsers of the HTML constructs, shared with the inline [raw HTML](crate::ast::inline::RawHtml).
+pub mod utils {
     use crate::parse::{input::Input, parsers::space_or_tab_and_up_to_1_line_ending};
     use parser::{
         IsEmpty, ItemsIndices, ParseResult, Parser, SplitAt, SubsetRange, is_one_of, maybe, not,
@@ -535,6 +560,11 @@ mod test {
         mod case_7 {
             use super::*;
 
+            failure_case!(
+                should_reject_tag_spanning_multiple_lines,
+                "<a href=\"foo\"\n>\n"
+            );
+
             success_case!(
                 should_work_with_single_line,
                 "<Big-Warning>\nI'm about to POP!</Big-Warning>",
@@ -547,4 +577,42 @@ mod test {
             );
         }
     }
+
+    mod starts_html_block {
+        use super::*;
+
+        #[test]
+        fn should_be_true_for_case_7() {
+            assert!(starts_html_block("<a href=\"foo\">\n"));
+        }
+
+        #[test]
+        fn should_be_false_for_inline_html_followed_by_text() {
+            assert!(!starts_html_block("<a> foo\n"));
+        }
+    }
+
+    mod interrupts_paragraph {
+        use super::*;
+
+        #[test]
+        fn should_be_true_for_case_1() {
+            assert!(interrupts_paragraph("<script>\n"));
+        }
+
+        #[test]
+        fn should_be_true_for_case_6() {
+            assert!(interrupts_paragraph("</div>\n"));
+        }
+
+        #[test]
+        fn should_be_false_for_case_7() {
+            assert!(!interrupts_paragraph("<a href=\"foo\">\n"));
+        }
+
+        #[test]
+        fn should_be_false_with_4_spaces_of_indentation() {
+            assert!(!interrupts_paragraph("    <div>\n"));
+        }
+    }
 }
@@ -1,25 +1,28 @@
+use super::paragraph::{is_setext_heading_underline, paragraph_content};
 use crate::{
     ast::{
-        block::LinkReferenceDefinition,
+        block::{Leaf, LinkReferenceDefinition, Paragraph, SetextHeading, ThematicBreak},
         inline::link::{LinkDestination, LinkLabel, LinkTitle},
     },
     parse::{
+        ast::inline::link::link_label,
         input::Input,
         parsers::{
             indented_by_less_than_4, line_ending_or_empty, space_or_tab,
             space_or_tab_and_up_to_1_line_ending,
         },
-        traits::Parse,
+        segment::setext_heading::SetextHeadingUnderlineSegment,
+        traits::{Parse, ParseLine},
     },
 };
-use parser::{ItemsIndices, Map, ParseResult, Parser, consumed, one_of, tag, validate};
+use parser::{ItemsIndices, Map, ParseResult, Parser, Repeated, consumed, one_of, tag, validate};
 
 /// Parses the link label in the context of the link reference definition.
 ///
-/// It consumes the leading indentation (if any), the label itself by dispatching to [LinkLabel::parse],
+/// It consumes the leading indentation (if any), the label itself by dispatching to [link_label],
 /// then the following colon.
 fn label<'a, I: Input<'a>>(input: I) -> ParseResult<I, LinkLabel<'a>> {
-    (indented_by_less_than_4, LinkLabel::parse, tag(":"))
+    (indented_by_less_than_4, link_label, tag(":"))
         .map(|(_, label, _)| label)
         .parse(input)
 }
@@ -80,6 +83,66 @@ impl<'a> Parse<'a> for LinkReferenceDefinition<'a> {
     }
 }
 
+/// Parses the link reference definitions found at the beginning of paragraph content, and
+/// what follows them.
+///
+/// Link reference definitions cannot interrupt a paragraph, so they are only found at the start of one.
+/// The paragraph content is parsed first, then its leading link reference definitions are extracted.
+/// What remains of the content, if anything, is the actual paragraph, or a setext heading if an
+/// underline follows it.
+///
+/// This parser fails if the paragraph content does not start with a link reference definition.
+pub fn link_reference_definitions<'a, I: Input<'a>>(input: I) -> ParseResult<I, Vec<Leaf<'a>>> {
+    // Quick check to avoid parsing the whole paragraph content when there is no chance of success.
+    if label(input.clone()).is_err() {
+        return Err(input);
+    }
+    let Ok((remaining, content)) = paragraph_content(input.clone()) else {
+        return Err(input);
+    };
+    let Ok((rest, mut leaves)) = LinkReferenceDefinition::parse
+        .map(Leaf::LinkReferenceDefinition)
+        .repeated()
+        .at_least(1)
+        .parse(content)
+    else {
+        return Err(input);
+    };
+
+    if !rest.is_empty() {
+        return match SetextHeadingUnderlineSegment::parse(remaining) {
+            Ok((remaining, underline_segment)) => {
+                leaves.push(Leaf::SetextHeading(SetextHeading::new(
+                    rest.lines().collect(),
+                    underline_segment,
+                )));
+                Ok((remaining, leaves))
+            }
+            Err(remaining) => {
+                leaves.push(Leaf::Paragraph(Paragraph::new(rest.lines().collect())));
+                Ok((remaining, leaves))
+            }
+        };
+    }
+
+    // Without remaining content, what would have been an underline is paragraph content instead,
+    // unless it is a thematic break.
+    let is_content = remaining.lines().next().is_some_and(|line| {
+        is_setext_heading_underline(line) && ThematicBreak::parse_line(line).is_err()
+    });
+    if !is_content {
+        return Ok((remaining, leaves));
+    }
+    let (remaining, leaf) = one_of((
+        SetextHeading::parse.map(Leaf::SetextHeading),
+        Paragraph::parse.map(Leaf::Paragraph),
+    ))
+    .parse(remaining)
+    .expect("unexpected error parsing paragraph content");
+    leaves.push(leaf);
+    Ok((remaining, leaves))
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -130,4 +193,116 @@ mod test {
             Some(LinkTitle::strict_parse("'\ntitle\nline1\nline2\n'"))
         ), "toto");
     }
+
+    mod link_reference_definitions {
+        use super::*;
+        use crate::parse::{
+            Lines, segment::setext_heading::SetextHeadingUnderlineSegment, test_utils::StrictParse,
+        };
+
+        fn definition(input: &str) -> Leaf {
+            Leaf::LinkReferenceDefinition(LinkReferenceDefinition::strict_parse(input))
+        }
+
+        #[test]
+        fn should_reject_empty() {
+            assert!(link_reference_definitions(Lines::from("")).is_err());
+        }
+
+        #[test]
+        fn should_reject_paragraph_without_definition() {
+            assert!(link_reference_definitions(Lines::from("aaa\n[foo]: /url\n")).is_err());
+        }
+
+        #[test]
+        fn should_reject_indented_code() {
+            assert!(link_reference_definitions(Lines::from("    [foo]: /url\n")).is_err());
+        }
+
+        #[test]
+        fn should_work_with_single_definition() {
+            assert_eq!(
+                link_reference_definitions(Lines::from("[foo]: /url\n\nbar\n")),
+                Ok((Lines::from("\nbar\n"), vec![definition("[foo]: /url\n")]))
+            );
+        }
+
+        #[test]
+        fn should_work_with_multiple_definitions() {
+            assert_eq!(
+                link_reference_definitions(Lines::from("[foo]: /url\n[bar]: /url 'title'\n")),
+                Ok((
+                    Lines::from(""),
+                    vec![
+                        definition("[foo]: /url\n"),
+                        definition("[bar]: /url 'title'\n")
+                    ]
+                ))
+            );
+        }
+
+        #[test]
+        fn should_work_with_multi_line_title() {
+            assert_eq!(
+                link_reference_definitions(Lines::from("[foo]: /url '\ntitle\n'\n")),
+                Ok((
+                    Lines::from(""),
+                    vec![definition("[foo]: /url '\ntitle\n'\n")]
+                ))
+            );
+        }
+
+        #[test]
+        fn should_keep_remaining_content_as_paragraph() {
+            assert_eq!(
+                link_reference_definitions(Lines::from("[foo]: /url\nbar\n    baz\n")),
+                Ok((
+                    Lines::from(""),
+                    vec![
+                        definition("[foo]: /url\n"),
+                        Leaf::Paragraph(Paragraph::new(vec!["bar\n", "    baz\n"]))
+                    ]
+                ))
+            );
+        }
+
+        #[test]
+        fn should_turn_remaining_content_into_setext_heading() {
+            assert_eq!(
+                link_reference_definitions(Lines::from("[foo]: /url\nbar\n---\n")),
+                Ok((
+                    Lines::from(""),
+                    vec![
+                        definition("[foo]: /url\n"),
+                        Leaf::SetextHeading(SetextHeading::new(
+                            vec!["bar\n"],
+                            SetextHeadingUnderlineSegment::strict_parse("---\n")
+                        ))
+                    ]
+                ))
+            );
+        }
+
+        #[test]
+        fn should_treat_underline_as_content_without_remaining_content() {
+            assert_eq!(
+                link_reference_definitions(Lines::from("[foo]: /url\n===\nbar\n")),
+                Ok((
+                    Lines::from(""),
+                    vec![
+                        definition("[foo]: /url\n"),
+                        Leaf::Paragraph(Paragraph::new(vec!["===\n", "bar\n"]))
+                    ]
+                ))
+            );
+        }
+
+        #[test]
+        fn should_stop_before_thematic_break_without_remaining_content() {
+            assert_eq!(
+                link_reference_definitions(Lines::from("[foo]: /url\n---\n")),
+                Ok((Lines::from("---\n"), vec![definition("[foo]: /url\n")]))
+            );
+        }
+    }
 }
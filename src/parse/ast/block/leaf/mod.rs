@@ -4,10 +4,16 @@ pub mod fenced_code;
 pub mod html;
 pub mod indented_code;
 pub mod link_reference_definition;
+pub mod paragraph;
+pub mod setext_heading;
+pub mod table;
 pub mod thematic_break;
 
 use crate::{
-    ast::block::{AtxHeading, BlankLine, FencedCode, Html, IndentedCode, Leaf, ThematicBreak},
+    ast::block::{
+        AtxHeading, BlankLine, FencedCode, Html, IndentedCode, Leaf, Paragraph, SetextHeading,
+        Table, ThematicBreak,
+    },
     parse::{input::Input, traits::Parse},
 };
 use parser::{Map, ParseResult, Parser, one_of};
@@ -21,7 +27,12 @@ impl<'a> Parse<'a> for Leaf<'a> {
             Html::parse.map(Leaf::Html),
             IndentedCode::parse.map(Leaf::IndentedCode),
             ThematicBreak::parse.map(Leaf::ThematicBreak),
+            // Tables start with paragraph lines, and take precedence over the paragraphs.
+            Table::parse.map(Leaf::Table),
+            // Setext headings are paragraphs followed by an underline, so they need to come before.
+            SetextHeading::parse.map(Leaf::SetextHeading),
             // Paragraph should be last.
+            Paragraph::parse.map(Leaf::Paragraph),
         ))
         .parse(input)
     }
@@ -0,0 +1,182 @@
+use super::{html, table::table_alignments};
+use crate::{
+    ast::block::{AtxHeading, Paragraph, ThematicBreak},
+    parse::{
+        ast::block::container::{
+            block_quote::block_quote_marker, is_too_deep_container_start,
+            list_item::list_item_interrupts_paragraph,
+        },
+        input::Input,
+        predicates::is_blank_line,
+        segment::{
+            fenced_code::{BackticksFencedCodeOpeningSegment, TildesFencedCodeOpeningSegment},
+            setext_heading::SetextHeadingUnderlineSegment,
+        },
+        traits::{Parse, ParseLine},
+    },
+};
+use parser::{ItemsIndices, ParseResult};
+
+impl<'a> Parse<'a> for Paragraph<'a> {
+    fn parse<I: Input<'a>>(input: I) -> ParseResult<I, Self> {
+        paragraph_lines(input, is_paragraph_line)
+            .map(|(remaining, parsed)| (remaining, Paragraph::new(parsed.lines().collect())))
+    }
+}
+
+/// Parses the lines of paragraph content, stopping before a setext heading underline, if any.
+///
+/// The first line is always considered content, even if it looks like an underline.
+pub fn paragraph_content<'a, I: Input<'a>>(input: I) -> ParseResult<I, I> {
+    paragraph_lines(input, |line| {
+        // The underline is checked before anything else, which is how a `---` line
+        // ends up as an underline rather than a thematic break.
+        is_paragraph_line(line) && !is_setext_heading_underline(line)
+    })
+}
+
+/// Parses a first paragraph line, followed by the continuation lines satisfying the predicate.
+///
+/// Lazy continuation lines are always part of the paragraph, as they were already determined to be
+/// paragraph continuation text by the container holding them. The paragraph stops before the
+/// header of a table, if any.
+fn paragraph_lines<'a, I, F>(input: I, is_continuation: F) -> ParseResult<I, I>
+where
+    I: Input<'a>,
+    F: Fn(&str) -> bool,
+{
+    let mut enumerator = <I as ItemsIndices<&'a str>>::enumerate(&input);
+    // The first line cannot be the header of a table, as the paragraph would be empty.
+    let mut previous = match enumerator.next() {
+        Some((_, line)) if is_paragraph_line(line) || is_too_deep_container_start(&input, line) => {
+            None
+        }
+        _ => {
+            std::mem::drop(enumerator);
+            return Err(input);
+        }
+    };
+    let end = loop {
+        let next_index = enumerator.next_index();
+        match enumerator.next() {
+            Some((index, line)) if input.is_lazy_continuation(index) || is_continuation(line) => {
+                match previous {
+                    Some((previous_index, previous_line))
+                        if table_alignments(&input, previous_line, index, line).is_some() =>
+                    {
+                        break previous_index;
+                    }
+                    _ => previous = Some((index, line)),
+                }
+            }
+            _ => break next_index,
+        }
+    };
+    std::mem::drop(enumerator);
+    let (parsed, remaining) = input.split_at(end);
+    Ok((remaining, parsed))
+}
+
+/// Returns whether the line is a setext heading underline.
+pub fn is_setext_heading_underline(line: &str) -> bool {
+    SetextHeadingUnderlineSegment::parse_line(line).is_ok()
+}
+
+/// Returns whether the line can be part of a paragraph.
+///
+/// Blank lines end paragraphs, and so do the lines that [interrupt](interrupts_paragraph) them.
+pub fn is_paragraph_line(line: &str) -> bool {
+    !is_blank_line(line) && !interrupts_paragraph(line)
+}
+
+/// Returns whether the line starts a block that can interrupt a paragraph.
+///
+/// Indented code blocks and HTML blocks of type 7 cannot interrupt a paragraph, so their
+/// lines are treated as paragraph continuation text.
+pub fn interrupts_paragraph(line: &str) -> bool {
+    AtxHeading::parse_line(line).is_ok()
+        || ThematicBreak::parse_line(line).is_ok()
+        || BackticksFencedCodeOpeningSegment::parse_line(line).is_ok()
+        || TildesFencedCodeOpeningSegment::parse_line(line).is_ok()
+        || html::interrupts_paragraph(line)
+        || block_quote_marker(line).is_ok()
+        || list_item_interrupts_paragraph(line)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod parse {
+        use super::*;
+        use crate::parse::test_utils::test_parse_macros;
+
+        test_parse_macros!(Paragraph);
+
+        failure_case!(should_reject_empty, "");
+        failure_case!(should_reject_blank_line, " \n");
+        failure_case!(should_reject_atx_heading, "# Heading\n");
+
+        success_case!(
+            should_work_with_single_line,
+            "aaa\n",
+            parsed => Paragraph::new(vec!["aaa\n"])
+        );
+        success_case!(
+            should_work_without_trailing_newline,
+            "aaa",
+            parsed => Paragraph::new(vec!["aaa"])
+        );
+        success_case!(
+            should_work_with_multiple_lines,
+            "aaa\n  bbb\n",
+            parsed => Paragraph::new(vec!["aaa\n", "  bbb\n"])
+        );
+        success_case!(
+            should_include_indented_code_lines,
+            "aaa\n    bbb\n",
+            parsed => Paragraph::new(vec!["aaa\n", "    bbb\n"])
+        );
+        success_case!(
+            should_include_html_case_7_lines,
+            "aaa\n<a href=\"foo\">\n",
+            parsed => Paragraph::new(vec!["aaa\n", "<a href=\"foo\">\n"])
+        );
+        success_case!(
+            should_stop_at_blank_line,
+            "aaa\n\nbbb\n",
+            parsed => Paragraph::new(vec!["aaa\n"]),
+            "\nbbb\n"
+        );
+        success_case!(
+            should_stop_at_atx_heading,
+            "aaa\n# bbb\n",
+            parsed => Paragraph::new(vec!["aaa\n"]),
+            "# bbb\n"
+        );
+        success_case!(
+            should_stop_at_thematic_break,
+            "aaa\n***\n",
+            parsed => Paragraph::new(vec!["aaa\n"]),
+            "***\n"
+        );
+        success_case!(
+            should_stop_at_backticks_fenced_code,
+            "aaa\n```\n",
+            parsed => Paragraph::new(vec!["aaa\n"]),
+            "```\n"
+        );
+        success_case!(
+            should_stop_at_tildes_fenced_code,
+            "aaa\n~~~\n",
+            parsed => Paragraph::new(vec!["aaa\n"]),
+            "~~~\n"
+        );
+        success_case!(
+            should_stop_at_html_case_6,
+            "aaa\n<div>\n",
+            parsed => Paragraph::new(vec!["aaa\n"]),
+            "<div>\n"
+        );
+    }
+}
@@ -0,0 +1,73 @@
+use super::paragraph::paragraph_content;
+use crate::{
+    ast::block::SetextHeading,
+    parse::{input::Input, segment::setext_heading::SetextHeadingUnderlineSegment, traits::Parse},
+};
+use parser::{Map, ParseResult, Parser};
+
+impl<'a> Parse<'a> for SetextHeading<'a> {
+    fn parse<I: Input<'a>>(input: I) -> ParseResult<I, Self> {
+        (paragraph_content, SetextHeadingUnderlineSegment::parse)
+            .map(|(content, underline_segment): (I, _)| {
+                SetextHeading::new(content.lines().collect(), underline_segment)
+            })
+            .parse(input)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod parse {
+        use super::*;
+        use crate::parse::test_utils::{StrictParse, test_parse_macros};
+
+        test_parse_macros!(SetextHeading);
+
+        failure_case!(should_reject_empty, "");
+        failure_case!(should_reject_blank_line, "\n");
+        failure_case!(should_reject_paragraph_without_underline, "aaa\nbbb\n");
+        failure_case!(should_reject_underline_without_content, "===\n");
+        failure_case!(should_reject_underline_after_blank_line, "aaa\n\n===\n");
+        failure_case!(
+            should_reject_underline_with_4_spaces_indent,
+            "aaa\n    ===\n"
+        );
+        failure_case!(should_reject_spaced_hyphens, "aaa\n- - -\n");
+
+        success_case!(
+            should_work_with_equals_underline,
+            "aaa\n===\n",
+            parsed => SetextHeading::new(
+                vec!["aaa\n"],
+                SetextHeadingUnderlineSegment::strict_parse("===\n")
+            )
+        );
+        success_case!(
+            should_work_with_hyphens_underline,
+            "aaa\n---\n",
+            parsed => SetextHeading::new(
+                vec!["aaa\n"],
+                SetextHeadingUnderlineSegment::strict_parse("---\n")
+            )
+        );
+        success_case!(
+            should_work_with_multiline_content,
+            "aaa\n  bbb\n=\n",
+            parsed => SetextHeading::new(
+                vec!["aaa\n", "  bbb\n"],
+                SetextHeadingUnderlineSegment::strict_parse("=\n")
+            )
+        );
+        success_case!(
+            should_stop_at_first_underline,
+            "aaa\n===\nbbb\n",
+            parsed => SetextHeading::new(
+                vec!["aaa\n"],
+                SetextHeadingUnderlineSegment::strict_parse("===\n")
+            ),
+            "bbb\n"
+        );
+    }
+}
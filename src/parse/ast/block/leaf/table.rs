@@ -0,0 +1,266 @@
+use super::paragraph::{is_paragraph_line, is_setext_heading_underline};
+use crate::{
+    ast::{
+        block::{Alignment, BlockKind, Table, TableCell, TableRow},
+        inline::{Code, Inline},
+    },
+    parse::{ast::block::block_kind, input::Input, traits::Parse},
+    visit_mut::VisitorMut,
+};
+use parser::{ItemsIndices, ParseResult};
+
+impl<'a> Parse<'a> for Table<'a> {
+    /// Parses a header row followed by a delimiter row, then the body rows until a line starts
+    /// another block.
+    fn parse<I: Input<'a>>(input: I) -> ParseResult<I, Self> {
+        let mut enumerator = <I as ItemsIndices<&'a str>>::enumerate(&input);
+        let start = match (enumerator.next(), enumerator.next()) {
+            (Some((_, header)), Some((index, line))) if is_paragraph_line(header) => {
+                table_alignments(&input, header, index, line)
+                    .map(|alignments| (header, line, alignments))
+            }
+            _ => None,
+        };
+        let Some((header, delimiter_row, alignments)) = start else {
+            std::mem::drop(enumerator);
+            return Err(input);
+        };
+        let mut rows = vec![];
+        let end = loop {
+            let next_index = enumerator.next_index();
+            match enumerator.next() {
+                Some((_, line)) if block_kind(line) == BlockKind::Paragraph => rows.push(row(line)),
+                _ => break next_index,
+            }
+        };
+        std::mem::drop(enumerator);
+        let (_, remaining) = input.split_at(end);
+        Ok((
+            remaining,
+            Table::new(row(header), delimiter_row, alignments, rows),
+        ))
+    }
+}
+
+/// Returns the alignments of the columns, if the paragraph line is the header of a table followed
+/// by its delimiter row at the given index.
+///
+/// The delimiter row must be paragraph continuation text, and setext heading underlines take
+/// precedence over it.
+pub fn table_alignments<'a, I: Input<'a>>(
+    input: &I,
+    header: &str,
+    index: I::Index,
+    line: &str,
+) -> Option<Vec<Alignment>> {
+    if !input.options().tables
+        || !(input.is_lazy_continuation(index)
+            || is_paragraph_line(line) && !is_setext_heading_underline(line))
+    {
+        return None;
+    }
+    delimiter_row(header, line)
+}
+
+/// Returns the alignments of the columns, if the line is a delimiter row with as many cells as the
+/// header.
+///
+/// A pipe is required in either row, as a single column is otherwise indistinguishable from a
+/// line of text followed by hyphens.
+pub fn delimiter_row(header: &str, line: &str) -> Option<Vec<Alignment>> {
+    let cells = cells(line);
+    if !(has_pipe(header) || has_pipe(line)) || cells.len() != cells_count(header) {
+        return None;
+    }
+    cells.into_iter().map(alignment).collect()
+}
+
+fn alignment(cell: &str) -> Option<Alignment> {
+    let left = cell.starts_with(':');
+    let hyphens = cell.strip_prefix(':').unwrap_or(cell);
+    let right = hyphens.ends_with(':');
+    let hyphens = hyphens.strip_suffix(':').unwrap_or(hyphens);
+    if hyphens.is_empty() || !hyphens.chars().all(|char| char == '-') {
+        return None;
+    }
+    Some(match (left, right) {
+        (false, false) => Alignment::None,
+        (true, false) => Alignment::Left,
+        (true, true) => Alignment::Center,
+        (false, true) => Alignment::Right,
+    })
+}
+
+fn row(line: &str) -> TableRow {
+    TableRow::new(line, cells(line).into_iter().map(TableCell::new).collect())
+}
+
+fn cells_count(line: &str) -> usize {
+    cells(line).len()
+}
+
+fn has_pipe(line: &str) -> bool {
+    cells_count(line) > 1 || line.trim().starts_with('|') || trailing_pipe(line.trim())
+}
+
+/// Whether the line ends with a pipe that is not escaped.
+fn trailing_pipe(line: &str) -> bool {
+    line.strip_suffix('|')
+        .is_some_and(|rest| rest.chars().rev().take_while(|char| *char == '\\').count() % 2 == 0)
+}
+
+/// Splits the row into its cells, trimmed of their whitespace.
+///
+/// The cells are separated by the pipes that are not escaped with a backslash, and the leading and
+/// trailing pipes are optional.
+fn cells(line: &str) -> Vec<&str> {
+    let line = line.trim_matches([' ', '\t', '\r', '\n']);
+    let line = line.strip_prefix('|').unwrap_or(line);
+    let mut cells = vec![];
+    let mut start = 0;
+    let mut chars = line.char_indices();
+    while let Some((index, char)) = chars.next() {
+        match char {
+            '\\' => {
+                chars.next();
+            }
+            '|' => {
+                cells.push(&line[start..index]);
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+    if start < line.len() || cells.is_empty() {
+        cells.push(&line[start..]);
+    }
+    cells
+        .into_iter()
+        .map(|cell| cell.trim_matches([' ', '\t']))
+        .collect()
+}
+
+/// Removes the backslashes escaping pipes in the code spans of a cell.
+///
+/// Pipes are escaped so as not to end the cell, even in code spans, where backslashes are
+/// otherwise kept as they are. Elsewhere, the escapes are decoded like any other.
+pub fn unescape_pipes(inlines: &mut [Inline]) {
+    for inline in inlines {
+        PipesUnescaper.visit_inline_mut(inline);
+    }
+}
+
+struct PipesUnescaper;
+
+impl<'a> VisitorMut<'a> for PipesUnescaper {
+    fn visit_code_mut(&mut self, code: &mut Code<'a>) {
+        code.unescape_pipes();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Segment;
+
+    mod cells {
+        use super::*;
+
+        #[test]
+        fn should_make_the_outer_pipes_optional() {
+            assert_eq!(cells("| foo | bar |\n"), vec!["foo", "bar"]);
+            assert_eq!(cells("foo | bar\n"), vec!["foo", "bar"]);
+        }
+
+        #[test]
+        fn should_keep_empty_cells() {
+            assert_eq!(cells("| | foo ||\n"), vec!["", "foo", ""]);
+            assert_eq!(cells("|\n"), vec![""]);
+        }
+
+        #[test]
+        fn should_not_split_on_escaped_pipes() {
+            assert_eq!(
+                cells("| f\\|oo | b\\\\|ar\n"),
+                vec!["f\\|oo", "b\\\\", "ar"]
+            );
+        }
+    }
+
+    mod delimiter_row {
+        use super::*;
+
+        #[test]
+        fn should_work_with_alignments() {
+            assert_eq!(
+                delimiter_row("a|b|c|d", "--- | :-- | :-: | --:"),
+                Some(vec![
+                    Alignment::None,
+                    Alignment::Left,
+                    Alignment::Center,
+                    Alignment::Right
+                ])
+            );
+        }
+
+        #[test]
+        fn should_reject_a_different_cells_count() {
+            assert_eq!(delimiter_row("| a | b |", "| --- |"), None);
+        }
+
+        #[test]
+        fn should_reject_invalid_cells() {
+            assert_eq!(delimiter_row("| a |", "| - - |"), None);
+            assert_eq!(delimiter_row("| a |", "| : |"), None);
+            assert_eq!(delimiter_row("| a |", "| -:- |"), None);
+        }
+
+        #[test]
+        fn should_require_a_pipe() {
+            assert_eq!(delimiter_row("a", ":--"), None);
+            assert_eq!(delimiter_row("a |", ":--"), Some(vec![Alignment::Left]));
+            assert_eq!(delimiter_row("a", "--- |"), Some(vec![Alignment::None]));
+        }
+    }
+
+    mod parse {
+        use super::*;
+        use crate::{ParseOptions, parse::Lines};
+        use parser::IsEmpty;
+
+        fn parse(source: &str) -> ParseResult<Lines, Table> {
+            Table::parse(Lines::with_options(source, ParseOptions::gfm()))
+        }
+
+        #[test]
+        fn should_require_the_extension() {
+            assert!(Table::parse(Lines::from("| a |\n| - |\n")).is_err());
+        }
+
+        #[test]
+        fn should_reject_a_header_without_delimiter_row() {
+            assert!(parse("| a |\n| b |\n").is_err());
+        }
+
+        #[test]
+        fn should_leave_setext_heading_underlines() {
+            assert!(parse("a |\n---\n").is_err());
+        }
+
+        #[test]
+        fn should_work_without_body_rows() {
+            let (remaining, table) = parse("| a |\n| - |\n").unwrap();
+            assert!(remaining.is_empty());
+            assert_eq!(table.header().segment(), "| a |\n");
+            assert!(table.rows().is_empty());
+        }
+
+        #[test]
+        fn should_stop_at_the_first_line_starting_another_block() {
+            let (remaining, table) = parse("| a |\n| - |\n| b |\nc\n# d\n").unwrap();
+            assert_eq!(remaining, Lines::with_options("# d\n", ParseOptions::gfm()));
+            assert_eq!(table.rows().len(), 2);
+            assert_eq!(table.rows()[1].cells()[0].segment(), "c");
+        }
+    }
+}
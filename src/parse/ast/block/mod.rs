@@ -2,13 +2,130 @@ pub mod container;
 pub mod leaf;
 
 use crate::{
-    ast::block::{Block, Leaf},
-    parse::{input::Input, traits::Parse},
+    ast::block::{
+        AtxHeading, BlankLine, Block, BlockKind, Container, FencedCode, Html, IndentedCode, Leaf,
+        Table, ThematicBreak,
+    },
+    parse::{
+        Lines,
+        input::Input,
+        traits::{Parse, ParseLine},
+    },
 };
-use parser::{Map, ParseResult, Parser};
+use container::{block_quote::block_quote_marker, list_item::list_item_start};
+use leaf::link_reference_definition::link_reference_definitions;
+use parser::{Map, ParseResult, Parser, Repeated, one_of};
 
 impl<'a> Parse<'a> for Block<'a> {
     fn parse<I: Input<'a>>(input: I) -> ParseResult<I, Self> {
-        Leaf::parse.map(Block::Leaf).parse(input)
+        // Containers are tried first, as their markers would otherwise be parsed as paragraph text.
+        one_of((
+            Container::parse.map(Block::Container),
+            Leaf::parse.map(Block::Leaf),
+        ))
+        .parse(input)
+    }
+}
+
+/// Parses blocks until the input is exhausted or no more blocks can be parsed.
+pub fn blocks<'a, I: Input<'a>>(input: I) -> ParseResult<I, Vec<Block<'a>>> {
+    next_blocks
+        .repeated()
+        .map(|blocks: Vec<Vec<Block<'a>>>| blocks.into_iter().flatten().collect())
+        .parse(input)
+}
+
+/// Parses the next block.
+///
+/// Unlike [Block::parse], this parser extracts the link reference definitions found at the
+/// beginning of paragraphs, which is why a single step can result in multiple blocks. For the same
+/// reason, it also parses the table whose header ends the paragraph, if any.
+pub fn next_blocks<'a, I: Input<'a>>(input: I) -> ParseResult<I, Vec<Block<'a>>> {
+    let (remaining, mut blocks) = one_of((
+        link_reference_definitions
+            .map(|leaves: Vec<Leaf<'a>>| Iterator::map(leaves.into_iter(), Block::Leaf).collect()),
+        Block::parse.map(|block| vec![block]),
+    ))
+    .parse(input)?;
+    // Paragraphs stop before the header of a table, which could otherwise be taken for the start
+    // of another block, like indented code.
+    if !matches!(
+        blocks.last(),
+        Some(Block::Leaf(
+            Leaf::Paragraph(_) | Leaf::LinkReferenceDefinition(_)
+        ))
+    ) {
+        return Ok((remaining, blocks));
+    }
+    match Table::parse(remaining.clone()) {
+        Ok((remaining, table)) => {
+            blocks.push(Block::Leaf(Leaf::Table(table)));
+            Ok((remaining, blocks))
+        }
+        Err(remaining) => Ok((remaining, blocks)),
+    }
+}
+
+/// Returns the kind of block started by the line, checking the start conditions in the same order
+/// as [Block::parse].
+///
+/// Only the line is looked at, so the blocks that are told apart from paragraphs by their later
+/// lines, tables and setext headings, are paragraphs here. Tables rely on it to end at the first
+/// line that starts any other kind of block.
+pub fn block_kind(line: &str) -> BlockKind {
+    if block_quote_marker(line).is_ok() {
+        BlockKind::BlockQuote
+    } else if list_item_start(line).is_some() {
+        BlockKind::List
+    } else if AtxHeading::parse_line(line).is_ok() {
+        BlockKind::AtxHeading
+    } else if BlankLine::parse_line(line).is_ok() {
+        BlockKind::BlankLine
+    } else if FencedCode::parse(Lines::from(line)).is_ok() {
+        BlockKind::FencedCode
+    } else if Html::parse(Lines::from(line)).is_ok() {
+        BlockKind::Html
+    } else if IndentedCode::parse(Lines::from(line)).is_ok() {
+        BlockKind::IndentedCode
+    } else if ThematicBreak::parse_line(line).is_ok() {
+        BlockKind::ThematicBreak
+    } else {
+        BlockKind::Paragraph
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod block_kind {
+        use super::*;
+
+        #[test]
+        fn should_work_with_containers() {
+            assert_eq!(block_kind("> foo\n"), BlockKind::BlockQuote);
+            assert_eq!(block_kind("1. foo\n"), BlockKind::List);
+        }
+
+        #[test]
+        fn should_work_with_leaves() {
+            assert_eq!(block_kind("# foo\n"), BlockKind::AtxHeading);
+            assert_eq!(block_kind(" \n"), BlockKind::BlankLine);
+            assert_eq!(block_kind("```rust\n"), BlockKind::FencedCode);
+            assert_eq!(block_kind("<div>\n"), BlockKind::Html);
+            assert_eq!(block_kind("    foo\n"), BlockKind::IndentedCode);
+            assert_eq!(block_kind("***\n"), BlockKind::ThematicBreak);
+            assert_eq!(block_kind("foo\n"), BlockKind::Paragraph);
+        }
+
+        #[test]
+        fn should_take_table_headers_for_paragraphs() {
+            assert_eq!(block_kind("| foo | bar |\n"), BlockKind::Paragraph);
+        }
+
+        #[test]
+        fn should_prefer_thematic_breaks_over_list_items() {
+            assert_eq!(block_kind("* * *\n"), BlockKind::ThematicBreak);
+        }
     }
 }
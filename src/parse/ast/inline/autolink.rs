@@ -0,0 +1,455 @@
+use crate::{
+    ast::inline::{Autolink, AutolinkKind},
+    parse::{predicates::unmatched_closing_parentheses, traits::ParseLine},
+};
+use parser::{
+    Map, ParseResult, Parser, Repeated, one_of, recognize, tag, take, take_while, validate,
+};
+use std::iter;
+
+impl<'a> ParseLine<'a> for Autolink<'a> {
+    fn parse_line(input: &'a str) -> ParseResult<&'a str, Self> {
+        one_of((
+            recognize((tag("<"), absolute_uri, tag(">")))
+                .map(|segment| Autolink::new(segment, AutolinkKind::Uri)),
+            recognize((tag("<"), email_address, tag(">")))
+                .map(|segment| Autolink::new(segment, AutolinkKind::Email)),
+        ))
+        .parse(input)
+    }
+}
+
+/// An absolute URI consists of a scheme followed by a colon (:) followed by zero or more characters
+/// other than ASCII control characters, space, <, and >.
+fn absolute_uri(input: &str) -> ParseResult<&str, &str> {
+    recognize((
+        scheme,
+        tag(":"),
+        take_while(|c: char| !c.is_ascii_control() && c != ' ' && c != '<' && c != '>'),
+    ))
+    .parse(input)
+}
+
+/// A scheme is any sequence of 2–32 characters beginning with an ASCII letter and followed by any
+/// combination of ASCII letters, digits, or the symbols plus (+), period (.), or hyphen (-).
+fn scheme(input: &str) -> ParseResult<&str, &str> {
+    recognize((
+        take(1).that(|c: char| c.is_ascii_alphabetic()),
+        take_while(|c: char| c.is_ascii_alphanumeric() || c == '+' || c == '.' || c == '-')
+            .at_least(1)
+            .at_most(31),
+    ))
+    .parse(input)
+}
+
+/// An email address is anything that matches the non-normative regex from the HTML5 spec:
+/// `[a-zA-Z0-9.!#$%&'*+/=?^_`{|}~-]+@[a-zA-Z0-9](?:[a-zA-Z0-9-]{0,61}[a-zA-Z0-9])?(?:\.[a-zA-Z0-9](?:[a-zA-Z0-9-]{0,61}[a-zA-Z0-9])?)*`
+fn email_address(input: &str) -> ParseResult<&str, &str> {
+    recognize((
+        take_while(|c: char| c.is_ascii_alphanumeric() || ".!#$%&'*+/=?^_`{|}~-".contains(c))
+            .at_least(1),
+        tag("@"),
+        domain_label,
+        recognize((tag("."), domain_label)).repeated(),
+    ))
+    .parse(input)
+}
+
+/// A label of the domain of an email address, made of up to 63 ASCII letters, digits or hyphens,
+/// that neither starts nor ends with a hyphen.
+fn domain_label(input: &str) -> ParseResult<&str, &str> {
+    validate(
+        take_while(|c: char| c.is_ascii_alphanumeric() || c == '-')
+            .at_least(1)
+            .at_most(63),
+        |label: &&str| !label.starts_with('-') && !label.ends_with('-'),
+    )
+    .parse(input)
+}
+
+/// Looks for an [extended autolink](https://github.github.com/gfm/#autolinks-extension-) at the
+/// index of the line content. Extended autolinks start at the beginning of the line, after
+/// whitespace, or after one of `*`, `_`, `~` and `(`, except for email addresses, which only need to
+/// follow a character that is not alphanumeric.
+///
+/// Returns the segment of the autolink and its kind, if there is one.
+pub fn extended_autolink_at(content: &str, index: usize) -> Option<(&str, AutolinkKind)> {
+    let before = content[..index].chars().next_back();
+    let text = &content[index..];
+    if before.is_none_or(|c| c.is_ascii_whitespace() || matches!(c, '*' | '_' | '~' | '(')) {
+        extended_autolink(text)
+    } else if before.is_some_and(|c| !c.is_ascii_alphanumeric()) {
+        extended_email(text).map(|email| (email, AutolinkKind::Email))
+    } else {
+        None
+    }
+}
+
+/// Returns the extended autolink starting the text, if any, regardless of what precedes it.
+///
+/// Backslash escapes are seen through, as they are decoded in the text and the destination of the
+/// autolink, like its character references.
+pub fn extended_autolink(text: &str) -> Option<(&str, AutolinkKind)> {
+    if text.starts_with("www.") {
+        return extended_url(text, 0).map(|url| (url, AutolinkKind::Uri));
+    }
+    for scheme in ["http://", "https://"] {
+        if text.starts_with(scheme) {
+            return extended_url(text, scheme.len()).map(|url| (url, AutolinkKind::Uri));
+        }
+    }
+    extended_email(text).map(|email| (email, AutolinkKind::Email))
+}
+
+/// Returns the URL starting the text, made of a valid domain following the prefix of the given
+/// length, and of the path that comes after.
+///
+/// The path ends with whitespace or with the characters starting other inlines, escaped or not, so
+/// that the URL never takes any part of them.
+fn extended_url(text: &str, prefix_length: usize) -> Option<&str> {
+    if !is_valid_domain(&text[prefix_length..]) {
+        return None;
+    }
+    let mut chars = text.char_indices();
+    let mut end = text.len();
+    while let Some((index, c)) = chars.next() {
+        match c {
+            // A backslash ending the line is a hard break.
+            '\\' => match chars.clone().next() {
+                Some((_, '<' | '[' | ']' | '`')) | None => {
+                    end = index;
+                    break;
+                }
+                Some((_, escaped)) if escaped.is_ascii_punctuation() => {
+                    chars.next();
+                }
+                _ => {}
+            },
+            c if c.is_ascii_whitespace() || matches!(c, '<' | '[' | ']' | '`') => {
+                end = index;
+                break;
+            }
+            _ => {}
+        }
+    }
+    let url = trim_url_end(&text[..end]);
+    (url.len() > prefix_length).then_some(url)
+}
+
+/// Returns whether a valid domain starts the text: segments of alphanumeric characters, underscores
+/// and hyphens separated by periods, with at least one period and no underscores in the last two
+/// segments.
+fn is_valid_domain(text: &str) -> bool {
+    let domain: String = unescaped_chars(text)
+        .map_while(|(_, c)| (c.is_alphanumeric() || matches!(c, '-' | '_' | '.')).then_some(c))
+        .collect();
+    let domain = domain.trim_end_matches('.');
+    domain.contains('.')
+        && domain
+            .rsplit('.')
+            .take(2)
+            .all(|segment| !segment.contains('_'))
+}
+
+/// Trims what ends the URL without being part of it: trailing punctuation, closing parentheses
+/// that were never opened, and what looks like a character reference.
+fn trim_url_end(url: &str) -> &str {
+    let mut url = url;
+    // The parentheses are only counted once, then as they are trimmed.
+    let mut unmatched = None;
+    loop {
+        if url.ends_with(['?', '!', '.', ',', ':', '*', '_', '~']) {
+            url = without_last_character(url);
+        } else if let Some(trimmed) = url.strip_suffix(';') {
+            let name_start = trimmed.trim_end_matches(|c: char| c.is_ascii_alphanumeric());
+            // Numeric character references are trimmed as well.
+            let reference = name_start.strip_suffix('#').unwrap_or(name_start);
+            url = match reference.strip_suffix('&') {
+                Some(_) if name_start.len() < trimmed.len() => without_last_character(reference),
+                _ => without_last_character(url),
+            };
+        } else if url.ends_with(')')
+            && *unmatched.get_or_insert_with(|| unmatched_closing_parentheses(url)) > 0
+        {
+            let trimmed = without_last_character(url);
+            if trimmed.len() + 1 == url.len() {
+                unmatched = unmatched.map(|count| count - 1);
+            }
+            url = trimmed;
+        } else {
+            return url;
+        }
+    }
+}
+
+/// Returns the URL without its last character, which is ASCII, nor the backslash escaping it.
+fn without_last_character(url: &str) -> &str {
+    let trimmed = &url[..url.len() - 1];
+    match is_escaped(trimmed) {
+        true => &trimmed[..trimmed.len() - 1],
+        false => trimmed,
+    }
+}
+
+/// Returns whether the character following the text is escaped, by an odd number of backslashes.
+fn is_escaped(text: &str) -> bool {
+    (text.len() - text.trim_end_matches('\\').len()) % 2 == 1
+}
+
+/// Returns the email address starting the text: a local part of alphanumeric characters, `.`, `-`,
+/// `_` and `+`, then `@` and a domain with at least one period, that does not end with `-` or `_`.
+///
+/// The local part starts with an alphanumeric character, so that it does not depend on whether the
+/// punctuation preceding it is escaped.
+fn extended_email(text: &str) -> Option<&str> {
+    if !text.starts_with(|c: char| c.is_ascii_alphanumeric()) {
+        return None;
+    }
+    let mut chars = unescaped_chars(text);
+    loop {
+        match chars.next()? {
+            (_, '@') => break,
+            (_, c) if c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_' | '+') => {}
+            _ => return None,
+        }
+    }
+    let (mut end, mut periods, mut last) = (None, 0, None);
+    let mut lookahead = chars.clone();
+    while let Some((after, c)) = lookahead.next() {
+        match c {
+            c if c.is_ascii_alphanumeric() || c == '-' || c == '_' => {}
+            '.' if lookahead
+                .clone()
+                .next()
+                .is_some_and(|(_, next)| next.is_ascii_alphanumeric()) =>
+            {
+                periods += 1
+            }
+            _ => break,
+        }
+        (end, last) = (Some(after), Some(c));
+    }
+    let end = end?;
+    (periods > 0 && !matches!(last, Some('-' | '_'))).then(|| &text[..end])
+}
+
+/// Iterates over the characters of the text, with the index following each. Backslash escapes
+/// stand for the characters they escape.
+fn unescaped_chars(text: &str) -> impl Iterator<Item = (usize, char)> + Clone + '_ {
+    let mut chars = text.char_indices();
+    iter::from_fn(move || {
+        let (index, c) = chars.next()?;
+        match chars.clone().next() {
+            Some((escaped_index, escaped)) if c == '\\' && escaped.is_ascii_punctuation() => {
+                chars.next();
+                Some((escaped_index + 1, escaped))
+            }
+            _ => Some((index + c.len_utf8(), c)),
+        }
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod parse {
+        use super::*;
+        use crate::parse::test_utils::test_parse_macros;
+
+        test_parse_macros!(Autolink);
+
+        failure_case!(should_reject_empty, "");
+        failure_case!(should_reject_missing_brackets, "https://example.com");
+        failure_case!(
+            should_reject_missing_closing_bracket,
+            "<https://example.com"
+        );
+        failure_case!(should_reject_single_character_scheme, "<m:abc>");
+        failure_case!(should_reject_spaces, "<https://foo.bar/baz bim>");
+        failure_case!(should_reject_relative_uri, "<foo.bar.baz>");
+        failure_case!(should_reject_hyphen_ending_domain_label, "<foo@bar-.com>");
+        failure_case!(should_reject_backslash_in_email, r"<foo\+@bar.example.com>");
+
+        success_case!(
+            should_work_with_uri,
+            "<https://example.com>",
+            parsed => Autolink::new("<https://example.com>", AutolinkKind::Uri)
+        );
+        success_case!(
+            should_work_with_empty_uri_after_scheme,
+            "<a+b+c:d>",
+            parsed => Autolink::new("<a+b+c:d>", AutolinkKind::Uri)
+        );
+        success_case!(
+            should_work_with_backslash_in_uri,
+            r"<https://example.com/\[\>",
+            parsed => Autolink::new(r"<https://example.com/\[\>", AutolinkKind::Uri)
+        );
+        success_case!(
+            should_work_with_email,
+            "<foo+special@Bar.baz-bar0.com>",
+            parsed => Autolink::new("<foo+special@Bar.baz-bar0.com>", AutolinkKind::Email)
+        );
+        success_case!(
+            should_stop_at_closing_bracket,
+            "<irc://foo.bar:2233/baz> text",
+            parsed => Autolink::new("<irc://foo.bar:2233/baz>", AutolinkKind::Uri),
+            " text"
+        );
+    }
+
+    mod extended_autolink {
+        use super::*;
+
+        #[test]
+        fn should_reject_text_without_a_prefix() {
+            assert_eq!(extended_autolink("foo.bar.com"), None);
+        }
+
+        #[test]
+        fn should_require_a_period_in_the_domain() {
+            assert_eq!(extended_autolink("http://localhost"), None);
+        }
+
+        #[test]
+        fn should_work_with_www() {
+            assert_eq!(
+                extended_autolink("www.foo.com/bar baz"),
+                Some(("www.foo.com/bar", AutolinkKind::Uri))
+            );
+        }
+
+        #[test]
+        fn should_work_with_https() {
+            assert_eq!(
+                extended_autolink("https://foo.com<bar"),
+                Some(("https://foo.com", AutolinkKind::Uri))
+            );
+        }
+
+        #[test]
+        fn should_trim_trailing_punctuation() {
+            assert_eq!(
+                extended_autolink("www.foo.com/bar.?!*"),
+                Some(("www.foo.com/bar", AutolinkKind::Uri))
+            );
+        }
+
+        #[test]
+        fn should_trim_trailing_entity_references() {
+            assert_eq!(
+                extended_autolink("www.foo.com/bar&amp;&quot;"),
+                Some(("www.foo.com/bar", AutolinkKind::Uri))
+            );
+        }
+
+        #[test]
+        fn should_trim_trailing_numeric_character_references() {
+            assert_eq!(
+                extended_autolink("www.foo.com/bar&#169;&#x1F;"),
+                Some(("www.foo.com/bar", AutolinkKind::Uri))
+            );
+        }
+
+        #[test]
+        fn should_trim_escaped_character_references() {
+            assert_eq!(
+                extended_autolink(r"www.foo.com/bar\&amp;"),
+                Some(("www.foo.com/bar", AutolinkKind::Uri))
+            );
+        }
+
+        #[test]
+        fn should_trim_unmatched_closing_parentheses() {
+            assert_eq!(
+                extended_autolink("www.foo.com/(bar))),"),
+                Some(("www.foo.com/(bar)", AutolinkKind::Uri))
+            );
+        }
+
+        #[test]
+        fn should_see_through_escaped_underscores_in_the_domain() {
+            assert_eq!(extended_autolink(r"www.foo.bar\_baz"), None);
+        }
+
+        #[test]
+        fn should_stop_before_escaped_less_than_signs() {
+            assert_eq!(
+                extended_autolink(r"www.foo.com/bar\<baz"),
+                Some(("www.foo.com/bar", AutolinkKind::Uri))
+            );
+        }
+
+        #[test]
+        fn should_work_with_emails() {
+            assert_eq!(
+                extended_autolink("foo+bar@baz.com."),
+                Some(("foo+bar@baz.com", AutolinkKind::Email))
+            );
+        }
+
+        #[test]
+        fn should_see_through_escapes_in_emails() {
+            let (segment, kind) = extended_autolink(r"foo\.bar\@baz\.com").unwrap();
+            let autolink = Autolink::extended(segment, kind);
+            assert_eq!(autolink.text(), "foo.bar@baz.com");
+            assert_eq!(autolink.destination(), "mailto:foo.bar@baz.com");
+        }
+
+        #[test]
+        fn should_reject_emails_ending_with_an_escaped_underscore() {
+            assert_eq!(extended_autolink(r"foo@bar.baz\_"), None);
+        }
+    }
+
+    mod extended_autolink_at {
+        use super::*;
+
+        #[test]
+        fn should_work_at_the_start() {
+            assert_eq!(
+                extended_autolink_at("www.foo.com", 0),
+                Some(("www.foo.com", AutolinkKind::Uri))
+            );
+        }
+
+        #[test]
+        fn should_work_after_an_opening_parenthesis() {
+            assert_eq!(
+                extended_autolink_at("(www.foo.com)", 1),
+                Some(("www.foo.com", AutolinkKind::Uri))
+            );
+        }
+
+        #[test]
+        fn should_reject_links_following_other_characters() {
+            assert_eq!(extended_autolink_at("/www.foo.com", 1), None);
+        }
+
+        #[test]
+        fn should_work_with_emails_following_punctuation() {
+            assert_eq!(
+                extended_autolink_at("-foo@bar.com", 1),
+                Some(("foo@bar.com", AutolinkKind::Email))
+            );
+        }
+
+        #[test]
+        fn should_reject_emails_following_alphanumeric_characters() {
+            assert_eq!(extended_autolink_at("ab@c.d", 1), None);
+        }
+    }
+
+    mod url {
+        use super::*;
+
+        #[test]
+        fn should_strip_the_angle_brackets() {
+            assert_eq!(
+                Autolink::new("<foo@bar.com>", AutolinkKind::Email).url(),
+                "foo@bar.com"
+            );
+        }
+    }
+}
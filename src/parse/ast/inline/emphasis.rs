@@ -0,0 +1,513 @@
+use super::Item;
+use crate::{
+    ParseOptions,
+    ast::inline::{Autolink, Emphasis, Inline, Strikethrough, Strong, Text},
+};
+use parser::is_one_of;
+use std::collections::{HashMap, VecDeque};
+
+/// A run of `*` or `_` characters, as described in the [spec](https://spec.commonmark.org/0.31.2/#delimiter-run).
+///
+/// Delimiter runs are what emphasis is made of. They are resolved once all the inline content is known.
+/// With the strikethrough extension, runs of `~` are resolved along with them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DelimiterRun<'a> {
+    /// The delimiters of the run that have not been used by emphasis yet.
+    segment: &'a str,
+    character: char,
+    original_length: usize,
+    can_open: bool,
+    can_close: bool,
+}
+
+/// Returns whether the character starts a delimiter run.
+pub fn is_delimiter(character: char, options: &ParseOptions) -> bool {
+    is_one_of(&['*', '_'])(character) || (character == '~' && options.strikethrough)
+}
+
+impl<'a> DelimiterRun<'a> {
+    /// Parses the run of delimiters starting at the given index of the line.
+    ///
+    /// The beginning and the end of the line count as whitespace when determining whether the run
+    /// can open or close emphasis.
+    pub fn parse(line: &'a str, start: usize) -> Self {
+        let character = line[start..]
+            .chars()
+            .next()
+            .expect("unexpected empty delimiter run");
+        let end = line[start..]
+            .find(|c| c != character)
+            .map_or(line.len(), |length| start + length);
+        let before = line[..start].chars().next_back();
+        let after = line[end..].chars().next();
+        let left_flanking = is_left_flanking(before, after);
+        let right_flanking = is_left_flanking(after, before);
+        let (can_open, can_close) = match character {
+            // Underscores cannot be used for intraword emphasis.
+            '_' => (
+                left_flanking && (!right_flanking || before.is_some_and(is_punctuation)),
+                right_flanking && (!left_flanking || after.is_some_and(is_punctuation)),
+            ),
+            // Strikethrough is delimited by one or two tildes, longer runs are just text.
+            '~' if end - start > 2 => (false, false),
+            _ => (left_flanking, right_flanking),
+        };
+        Self {
+            segment: &line[start..end],
+            character,
+            original_length: end - start,
+            can_open,
+            can_close,
+        }
+    }
+
+    pub fn segment(&self) -> &'a str {
+        self.segment
+    }
+
+    /// Returns whether this run can close emphasis opened by the other run.
+    fn closes(&self, opener: &Self) -> bool {
+        if !opener.can_open || opener.character != self.character {
+            return false;
+        }
+        // Both sides of strikethrough have as many tildes.
+        if self.character == '~' {
+            return opener.original_length == self.original_length;
+        }
+        // The rule of 3.
+        let both_ways = opener.can_close || self.can_open;
+        let sum = opener.original_length + self.original_length;
+        !both_ways
+            || sum % 3 != 0
+            || (opener.original_length % 3 == 0 && self.original_length % 3 == 0)
+    }
+}
+
+/// Returns whether the delimiter run surrounded by those characters is left-flanking.
+///
+/// Swapping the characters tells whether the run is right-flanking.
+fn is_left_flanking(before: Option<char>, after: Option<char>) -> bool {
+    let Some(after) = after.filter(|c| !c.is_whitespace()) else {
+        return false;
+    };
+    !is_punctuation(after) || before.is_none_or(|c| c.is_whitespace() || is_punctuation(c))
+}
+
+/// Returns whether the character is a [Unicode punctuation character](https://spec.commonmark.org/0.31.2/#unicode-punctuation-character).
+// TODO: use the Unicode general categories for non ASCII characters.
+fn is_punctuation(character: char) -> bool {
+    character.is_ascii_punctuation()
+        || (!character.is_ascii() && !character.is_alphanumeric() && !character.is_whitespace())
+}
+
+/// What a pair of delimiters makes of the inlines between them.
+#[derive(Debug, Clone, Copy)]
+enum Delimited {
+    Emphasis,
+    Strong,
+    Strikethrough,
+}
+
+impl Delimited {
+    fn inline<'a>(
+        self,
+        opening: &'a str,
+        children: Vec<Inline<'a>>,
+        closing: &'a str,
+    ) -> Inline<'a> {
+        match self {
+            Self::Emphasis => Inline::Emphasis(Emphasis::new(opening, children, closing)),
+            Self::Strong => Inline::Strong(Strong::new(opening, children, closing)),
+            Self::Strikethrough => {
+                Inline::Strikethrough(Strikethrough::new(opening, children, closing))
+            }
+        }
+    }
+}
+
+/// Resolves the delimiter runs into emphasis and strikethrough, following the [spec](https://spec.commonmark.org/0.31.2/#process-emphasis).
+///
+/// The delimiters left unused become text, and so do the ones of emphasis that would nest the inlines
+/// deeper than the given depth. Returns the inlines along with how deep they are nested.
+pub fn process_emphasis(items: Vec<Item>, max_depth: usize) -> (Vec<Inline>, usize) {
+    let mut runs: Vec<DelimiterRun> = items
+        .iter()
+        .filter_map(|item| match item {
+            Item::Delimiter(run) => Some(run.clone()),
+            _ => None,
+        })
+        .collect();
+    // The delimiter stack is a linked list of the runs, where each run points to the previous one
+    // still on the stack. The runs after the current closer are all still on it, and they only
+    // ever need to skip the closer when it is removed.
+    let mut previous: Vec<Option<usize>> = (0..runs.len()).map(|run| run.checked_sub(1)).collect();
+    // The delimiters used by each run, in order, to close emphasis and to open it.
+    let mut closing: Vec<Vec<(&str, Delimited)>> = vec![Vec::new(); runs.len()];
+    let mut opening: Vec<Vec<&str>> = vec![Vec::new(); runs.len()];
+    // The lowest run an opener can be, for each kind of closer.
+    let mut openers_bottom = HashMap::new();
+    for current in 0..runs.len() {
+        while runs[current].can_close {
+            let closer = &runs[current];
+            let key = (
+                closer.character,
+                closer.can_open,
+                closer.original_length % 3,
+            );
+            let bottom = openers_bottom.get(&key).copied().unwrap_or(0);
+            let mut candidate = previous[current].filter(|&run| run >= bottom);
+            while let Some(run) = candidate.filter(|&run| !closer.closes(&runs[run])) {
+                candidate = previous[run].filter(|&run| run >= bottom);
+            }
+            let Some(opener_index) = candidate else {
+                openers_bottom.insert(key, current);
+                if !closer.can_open {
+                    remove(&mut previous, current);
+                }
+                break;
+            };
+
+            let opener = &runs[opener_index];
+            let (used, delimited) = if opener.character == '~' {
+                (opener.segment.len(), Delimited::Strikethrough)
+            } else if opener.segment.len() >= 2 && closer.segment.len() >= 2 {
+                (2, Delimited::Strong)
+            } else {
+                (1, Delimited::Emphasis)
+            };
+            let (opener_rest, opening_delimiter) =
+                opener.segment.split_at(opener.segment.len() - used);
+            let (closing_delimiter, closer_rest) = closer.segment.split_at(used);
+            opening[opener_index].push(opening_delimiter);
+            closing[current].push((closing_delimiter, delimited));
+            runs[opener_index].segment = opener_rest;
+            runs[current].segment = closer_rest;
+            // The runs between the opener and the closer are removed from the stack, and so is
+            // the opener once used up.
+            previous[current] = if opener_rest.is_empty() {
+                previous[opener_index]
+            } else {
+                Some(opener_index)
+            };
+            if closer_rest.is_empty() {
+                remove(&mut previous, current);
+                break;
+            }
+        }
+    }
+
+    // The emphasis being built, innermost last, with its opening delimiter and how deep the
+    // inlines are nested in it so far.
+    let mut open: Vec<(&str, VecDeque<Inline>, usize)> = vec![("", VecDeque::new(), 0)];
+    let mut runs = runs.into_iter().zip(closing.into_iter().zip(opening));
+    for item in items {
+        let Item::Delimiter(_) = item else {
+            let (_, inlines, depth) = open.last_mut().expect("unexpected closed emphasis");
+            if let Item::Link(_, link_depth) = &item {
+                *depth = (*depth).max(*link_depth);
+            }
+            inlines.push_back(into_inline(item));
+            continue;
+        };
+        let (run, (closing, opening)) = runs.next().expect("unexpected missing delimiter run");
+        // A run closes emphasis with its first delimiters, and opens it with its last ones, the
+        // ones used first being the innermost.
+        for (closing_delimiter, delimited) in closing {
+            let (opening_delimiter, children, children_depth) =
+                open.pop().expect("unexpected closed emphasis");
+            let (_, inlines, depth) = open.last_mut().expect("unexpected closed emphasis");
+            if children_depth < max_depth {
+                let children = children.into();
+                inlines.push_back(delimited.inline(opening_delimiter, children, closing_delimiter));
+                *depth = (*depth).max(children_depth + 1);
+            } else {
+                // The delimiters are text around the children, which join the inlines before
+                // them. The shorter of the two is moved, so that deeply nested text is not moved
+                // again at every level.
+                let mut children = children;
+                children.push_front(Inline::Text(Text::new(opening_delimiter)));
+                children.push_back(Inline::Text(Text::new(closing_delimiter)));
+                if inlines.len() < children.len() {
+                    while let Some(inline) = inlines.pop_back() {
+                        children.push_front(inline);
+                    }
+                    *inlines = children;
+                } else {
+                    inlines.extend(children);
+                }
+                *depth = (*depth).max(children_depth);
+            }
+        }
+        if !run.segment.is_empty() {
+            let (_, inlines, _) = open.last_mut().expect("unexpected closed emphasis");
+            inlines.push_back(Inline::Text(Text::new(run.segment)));
+        }
+        open.extend(
+            opening
+                .into_iter()
+                .rev()
+                .map(|delimiter| (delimiter, VecDeque::new(), 0)),
+        );
+    }
+    let (_, inlines, depth) = open.pop().expect("unexpected closed emphasis");
+    debug_assert!(open.is_empty(), "unexpected unclosed emphasis");
+    (inlines.into(), depth)
+}
+
+/// Removes the current closer from the delimiter stack.
+fn remove(previous: &mut [Option<usize>], closer: usize) {
+    if closer + 1 < previous.len() {
+        previous[closer + 1] = previous[closer];
+    }
+}
+
+/// Turns the item into an inline, a remaining delimiter run becoming text.
+fn into_inline(item: Item) -> Inline {
+    match item {
+        Item::Inline(inline) | Item::Link(inline, _) => inline,
+        Item::Delimiter(run) => Inline::Text(Text::new(run.segment)),
+        Item::Bracket(bracket) => Inline::Text(Text::new(bracket.segment())),
+        Item::ExtendedAutolink(segment, kind) => {
+            Inline::Autolink(Autolink::extended(segment, kind))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod delimiter_run {
+        use super::*;
+
+        fn flags(line: &str, start: usize) -> (bool, bool) {
+            let run = DelimiterRun::parse(line, start);
+            (run.can_open, run.can_close)
+        }
+
+        #[test]
+        fn should_take_the_whole_run() {
+            assert_eq!(DelimiterRun::parse("a***b", 1).segment(), "***");
+        }
+
+        #[test]
+        fn should_not_mix_characters() {
+            assert_eq!(DelimiterRun::parse("*_a", 0).segment(), "*");
+        }
+
+        #[test]
+        fn should_open_before_word() {
+            assert_eq!(flags("*foo", 0), (true, false));
+        }
+
+        #[test]
+        fn should_close_after_word() {
+            assert_eq!(flags("foo*", 3), (false, true));
+        }
+
+        #[test]
+        fn should_neither_open_nor_close_surrounded_by_whitespace() {
+            assert_eq!(flags("a * b", 2), (false, false));
+        }
+
+        #[test]
+        fn should_open_and_close_within_word_with_asterisks() {
+            assert_eq!(flags("foo*bar", 3), (true, true));
+        }
+
+        #[test]
+        fn should_neither_open_nor_close_within_word_with_underscores() {
+            assert_eq!(flags("foo_bar", 3), (false, false));
+        }
+
+        #[test]
+        fn should_not_open_before_punctuation_preceded_by_alphanumeric() {
+            assert_eq!(flags("a*\"foo\"", 1), (false, true));
+        }
+
+        #[test]
+        fn should_open_and_close_between_punctuation() {
+            assert_eq!(flags("(_(foo", 1), (true, true));
+        }
+
+        #[test]
+        fn should_neither_open_nor_close_with_more_than_two_tildes() {
+            assert_eq!(flags("~~~foo", 0), (false, false));
+        }
+    }
+
+    mod process_emphasis {
+        use super::*;
+        use crate::{
+            Segment,
+            ast::inline::SoftBreak,
+            parse::ast::inline::{MAX_DEPTH, inlines, link::Definitions},
+        };
+
+        #[test]
+        fn should_produce_emphasis() {
+            assert_eq!(
+                inlines(["*foo*"], &Definitions::default(), &ParseOptions::default()),
+                vec![Inline::Emphasis(Emphasis::new(
+                    "*",
+                    vec![Inline::Text(Text::new("foo"))],
+                    "*"
+                ))]
+            );
+        }
+
+        #[test]
+        fn should_produce_strong_emphasis() {
+            assert_eq!(
+                inlines(
+                    ["__foo__"],
+                    &Definitions::default(),
+                    &ParseOptions::default()
+                ),
+                vec![Inline::Strong(Strong::new(
+                    "__",
+                    vec![Inline::Text(Text::new("foo"))],
+                    "__"
+                ))]
+            );
+        }
+
+        #[test]
+        fn should_nest_emphasis_within_strong_emphasis() {
+            assert_eq!(
+                inlines(
+                    ["***foo***"],
+                    &Definitions::default(),
+                    &ParseOptions::default()
+                ),
+                vec![Inline::Emphasis(Emphasis::new(
+                    "*",
+                    vec![Inline::Strong(Strong::new(
+                        "**",
+                        vec![Inline::Text(Text::new("foo"))],
+                        "**"
+                    ))],
+                    "*"
+                ))]
+            );
+        }
+
+        #[test]
+        fn should_leave_unmatched_delimiters_as_text() {
+            assert_eq!(
+                inlines(
+                    ["**foo*"],
+                    &Definitions::default(),
+                    &ParseOptions::default()
+                ),
+                vec![
+                    Inline::Text(Text::new("*")),
+                    Inline::Emphasis(Emphasis::new(
+                        "*",
+                        vec![Inline::Text(Text::new("foo"))],
+                        "*"
+                    )),
+                ]
+            );
+        }
+
+        #[test]
+        fn should_not_match_different_characters() {
+            assert_eq!(
+                inlines(["*foo_"], &Definitions::default(), &ParseOptions::default()),
+                vec![
+                    Inline::Text(Text::new("*")),
+                    Inline::Text(Text::new("foo")),
+                    Inline::Text(Text::new("_")),
+                ]
+            );
+        }
+
+        #[test]
+        fn should_apply_the_rule_of_3() {
+            // The inner `**` can both open and close, so it cannot close the first `*`.
+            assert_eq!(
+                inlines(
+                    ["*foo**bar*"],
+                    &Definitions::default(),
+                    &ParseOptions::default()
+                ),
+                vec![Inline::Emphasis(Emphasis::new(
+                    "*",
+                    vec![
+                        Inline::Text(Text::new("foo")),
+                        Inline::Text(Text::new("**")),
+                        Inline::Text(Text::new("bar")),
+                    ],
+                    "*"
+                ))]
+            );
+        }
+
+        #[test]
+        fn should_span_multiple_lines() {
+            assert_eq!(
+                inlines(
+                    "*foo\nbar*".split_inclusive('\n'),
+                    &Definitions::default(),
+                    &ParseOptions::default()
+                ),
+                vec![Inline::Emphasis(Emphasis::new(
+                    "*",
+                    vec![
+                        Inline::Text(Text::new("foo")),
+                        Inline::SoftBreak(SoftBreak::new("\n")),
+                        Inline::Text(Text::new("bar")),
+                    ],
+                    "*"
+                ))]
+            );
+        }
+
+        #[test]
+        fn should_leave_emphasis_beyond_the_maximum_depth_as_text() {
+            let source = "*a ".repeat(MAX_DEPTH + 1) + &" a*".repeat(MAX_DEPTH + 1);
+            let inlines = inlines(
+                [source.as_str()],
+                &Definitions::default(),
+                &ParseOptions::default(),
+            );
+            let [Inline::Text(opening), children @ .., Inline::Text(closing)] = inlines.as_slice()
+            else {
+                panic!("expected text around the emphasis: {inlines:?}");
+            };
+            assert_eq!((opening.segment(), closing.segment()), ("*", "*"));
+            let mut children = children;
+            for _ in 0..MAX_DEPTH {
+                let Some(Inline::Emphasis(emphasis)) = children
+                    .iter()
+                    .find(|inline| matches!(inline, Inline::Emphasis(_)))
+                else {
+                    panic!("expected emphasis: {children:?}");
+                };
+                children = emphasis.children();
+            }
+            assert!(
+                children
+                    .iter()
+                    .all(|inline| matches!(inline, Inline::Text(_)))
+            );
+        }
+
+        #[test]
+        fn should_produce_strikethrough_with_the_extension() {
+            let options = ParseOptions {
+                strikethrough: true,
+                ..Default::default()
+            };
+            assert_eq!(
+                inlines(["~~foo~~"], &Definitions::default(), &options),
+                vec![Inline::Strikethrough(Strikethrough::new(
+                    "~~",
+                    vec![Inline::Text(Text::new("foo"))],
+                    "~~"
+                ))]
+            );
+        }
+    }
+}
@@ -17,10 +17,78 @@ impl<'a> ParseLine<'a> for LinkDestination<'a> {
     }
 }
 
+/// Parses the whole string as a link destination, as it would be written in the source.
+///
+/// This is how a destination is built for [set_destination](crate::ast::inline::Link::set_destination).
+/// The string is given back as the error when it is not a valid link destination.
+impl<'a> TryFrom<&'a str> for LinkDestination<'a> {
+    type Error = &'a str;
+
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        match Self::parse_line(value) {
+            Ok(("", destination)) => Ok(destination),
+            _ => Err(value),
+        }
+    }
+}
+
+/// Parses the whole string as a link destination, like the implementation for `&str`, but
+/// without borrowing it, as for a destination made with [format!].
+impl TryFrom<String> for LinkDestination<'static> {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match LinkDestination::try_from(value.as_str()) {
+            Ok(destination) => Ok(destination.into_owned()),
+            Err(_) => Err(value),
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
 
+    mod try_from {
+        use super::*;
+
+        #[test]
+        fn should_work_with_a_valid_destination() {
+            assert_eq!(
+                LinkDestination::try_from("<foo bar>").unwrap().url(),
+                "foo bar"
+            );
+        }
+
+        #[test]
+        fn should_reject_trailing_content() {
+            assert_eq!(LinkDestination::try_from("foo bar"), Err("foo bar"));
+        }
+
+        #[test]
+        fn should_reject_an_invalid_destination() {
+            assert_eq!(LinkDestination::try_from("<foo"), Err("<foo"));
+        }
+
+        #[test]
+        fn should_work_with_an_owned_string() {
+            assert_eq!(
+                LinkDestination::try_from(String::from("foo"))
+                    .unwrap()
+                    .url(),
+                "foo"
+            );
+        }
+
+        #[test]
+        fn should_give_back_an_invalid_owned_string() {
+            assert_eq!(
+                LinkDestination::try_from(String::from("<foo")),
+                Err(String::from("<foo"))
+            );
+        }
+    }
+
     mod parse {
         use super::*;
         use crate::parse::test_utils::{StrictParse, test_parse_macros};
@@ -1,8 +1,6 @@
+use crate::ast::inline::link::UnbracketedLinkDestination;
 use crate::parse::traits::ParseLine;
-use crate::{
-    ast::inline::link::UnbracketedLinkDestination, parse::predicates::parentheseses_balance,
-};
-use parser::{Map, ParseResult, Parser, recognize, take, take_while, validate};
+use parser::ParseResult;
 
 /*
 From the spec, a "unbracketed" link destination is:
@@ -12,15 +10,14 @@ and includes parentheses only if (a) they are backslash-escaped or (b) they are
 */
 impl<'a> ParseLine<'a> for UnbracketedLinkDestination<'a> {
     fn parse_line(input: &'a str) -> ParseResult<&'a str, Self> {
-        validate(
-            recognize((
-                take(1).that(utils::is_opening_char),
-                take_while(utils::is_continuation_char),
-            )),
-            |segment: &&str| parentheseses_balance(segment),
-        )
-        .map(Self::new)
-        .parse(input)
+        if !input.starts_with(utils::is_opening_char) {
+            return Err(input);
+        }
+        // An unbalanced closing parenthesis ends the destination, as in `[link](/uri)`.
+        match utils::destination_end(input) {
+            Some(end) if end > 0 => Ok((&input[end..], Self::new(&input[..end]))),
+            _ => Err(input),
+        }
     }
 }
 
@@ -35,11 +32,19 @@ mod test {
         test_parse_macros!(UnbracketedLinkDestination);
 
         failure_case!(should_reject_empty_string, "");
+        failure_case!(should_reject_unmatched_closing_parenthesis, ")");
         failure_case!(should_reject_blank_line, "\n");
         failure_case!(should_reject_space, " ");
         failure_case!(should_reject_leading_whitespace, " a");
         failure_case!(should_reject_ascii_control_character, "\x00");
         failure_case!(should_reject_unbalanced_parentheses, "(foo(and(bar))");
+        failure_case!(
+            should_reject_parentheses_nested_too_deep,
+            concat!(
+                "(((((((((((((((((((((((((((((((((",
+                ")))))))))))))))))))))))))))))))))"
+            )
+        );
 
         success_case!(should_work_with_character, "a");
         success_case!(should_work_with_several_characters, "abc");
@@ -56,6 +61,25 @@ mod test {
         );
         success_case!(should_stop_at_first_space, "foo bar", "foo", " bar");
         success_case!(should_stop_at_first_newline, "foo\nbar", "foo", "\nbar");
+        success_case!(
+            should_work_with_parentheses_nested_32_levels_deep,
+            concat!(
+                "((((((((((((((((((((((((((((((((",
+                "))))))))))))))))))))))))))))))))"
+            )
+        );
+        success_case!(
+            should_stop_at_space_after_backslash,
+            "foo\\ bar",
+            "foo\\",
+            " bar"
+        );
+        success_case!(
+            should_stop_at_unbalanced_closing_parenthesis,
+            "(foo))",
+            "(foo)",
+            ")"
+        );
     }
 }
 
@@ -70,6 +94,35 @@ mod utils {
         character != ' ' && !character.is_ascii_control() && character != '\n'
     }
 
+    /// How deep parentheses can be nested in a destination, as cmark does.
+    const MAX_PARENTHESES_DEPTH: usize = 32;
+
+    /// Returns the end of the destination the segment starts with: its first character that
+    /// cannot be part of a destination, or its first unescaped closing parenthesis that has no
+    /// opening match.
+    ///
+    /// There is no destination when its parentheses are unbalanced or nested too deep.
+    pub fn destination_end(segment: &str) -> Option<usize> {
+        let mut depth = 0;
+        let mut chars = segment.char_indices().peekable();
+        while let Some((index, character)) = chars.next() {
+            match character {
+                '\\' => {
+                    chars.next_if(|&(_, next)| is_continuation_char(next));
+                }
+                '(' if depth == MAX_PARENTHESES_DEPTH => return None,
+                '(' => depth += 1,
+                ')' if depth == 0 => return Some(index),
+                ')' => depth -= 1,
+                character if !is_continuation_char(character) => {
+                    return (depth == 0).then_some(index);
+                }
+                _ => {}
+            }
+        }
+        (depth == 0).then_some(segment.len())
+    }
+
     #[cfg(test)]
     mod test {
         use super::*;
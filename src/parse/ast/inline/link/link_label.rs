@@ -1,10 +1,14 @@
 use crate::{
     ast::inline::link::LinkLabel,
-    parse::{parsers::escaped_sequence, traits::ParseLine},
+    parse::{
+        input::Input,
+        parsers::escaped_sequence,
+        traits::{Parse, ParseLine},
+    },
 };
 use parser::{
-    Map, ParseResult, Parser, Repeated, is_one_of, not, one_of, recognize, tag, take_while,
-    validate,
+    ItemsIndices, Map, ParseResult, Parser, Repeated, is_one_of, not, one_of, recognize, tag,
+    take_while, validate,
 };
 
 /*
@@ -36,6 +40,58 @@ impl<'a> ParseLine<'a> for LinkLabel<'a> {
     }
 }
 
+/// Parses a link label that can span several lines, the line endings within it being whitespace
+/// like spaces and tabs.
+///
+/// A label spanning several lines is made of the concatenation of those, as the container markers
+/// between them are not part of it.
+pub fn link_label<'a, I: Input<'a>>(input: I) -> ParseResult<I, LinkLabel<'a>> {
+    let input = match LinkLabel::parse(input) {
+        Ok(parsed) => return Ok(parsed),
+        Err(input) => input,
+    };
+    let mut enumerator = <I as ItemsIndices<&'a str>>::enumerate(&input);
+    let mut label = String::new();
+    let mut rest = None;
+    for (_, line) in enumerator.by_ref() {
+        if label.is_empty() && !line.starts_with('[') {
+            break;
+        }
+        let start = if label.is_empty() { 1 } else { 0 };
+        match utils::closing_bracket(&line[start..]) {
+            Ok(Some(end)) => {
+                label.push_str(&line[..start + end]);
+                rest = Some(&line[start + end..]);
+                break;
+            }
+            Ok(None) => label.push_str(line),
+            Err(()) => break,
+        }
+        // The 999 characters of a label are at most 4 bytes each.
+        if label.len() > 4 * 999 + 2 {
+            break;
+        }
+    }
+    let index = match rest {
+        // A label on the first line only would have been parsed already, so it is invalid.
+        Some(rest)
+            if label.contains('\n') && utils::is_valid_content(&label[1..label.len() - 1]) =>
+        {
+            match rest.is_empty() {
+                true => enumerator.next_index(),
+                false => input.subset_range(rest).0,
+            }
+        }
+        _ => {
+            std::mem::drop(enumerator);
+            return Err(input);
+        }
+    };
+    std::mem::drop(enumerator);
+    let (_, remaining) = input.split_at(index);
+    Ok((remaining, LinkLabel::joined(label)))
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -97,6 +153,57 @@ mod test {
         );
         success_case!(should_stop_after_closing_bracket, "[a] ", "[a]", " ");
     }
+
+    mod link_label {
+        use super::*;
+        use crate::parse::{Lines, StrippedLine, StrippedLines};
+
+        #[test]
+        fn should_work_on_a_single_line() {
+            assert_eq!(
+                link_label(Lines::from("[foo]: /url\n")),
+                Ok((Lines::from(": /url\n"), LinkLabel::new("[foo]")))
+            );
+        }
+
+        #[test]
+        fn should_work_across_lines() {
+            assert_eq!(
+                link_label(Lines::from("[\nfoo\n]: /url\n")),
+                Ok((
+                    Lines::from(": /url\n"),
+                    LinkLabel::joined("[\nfoo\n]".to_string())
+                ))
+            );
+        }
+
+        #[test]
+        fn should_join_lines_without_what_was_stripped_between_them() {
+            let source = "> [foo\n> bar]\n";
+            let lines = [
+                StrippedLine::explicit(&source[2..7]),
+                StrippedLine::explicit(&source[9..]),
+            ];
+            let (remaining, label) = link_label(StrippedLines::new(&lines)).unwrap();
+            assert_eq!(label, LinkLabel::joined("[foo\nbar]".to_string()));
+            assert_eq!(remaining.lines().collect::<String>(), "\n");
+        }
+
+        #[test]
+        fn should_reject_opening_brackets_on_later_lines() {
+            assert!(link_label(Lines::from("[foo\n[bar]\n")).is_err());
+        }
+
+        #[test]
+        fn should_reject_blank_labels() {
+            assert!(link_label(Lines::from("[\n]\n")).is_err());
+        }
+
+        #[test]
+        fn should_reject_unclosed_labels() {
+            assert!(link_label(Lines::from("[foo\nbar\n")).is_err());
+        }
+    }
 }
 
 mod utils {
@@ -105,6 +212,23 @@ mod utils {
         !segment.trim().is_empty() && valid_character_count(segment)
     }
 
+    /// Returns the end of the label in the line, right after its closing bracket, if it is found
+    /// there, or an error if an unescaped opening bracket is found first.
+    pub fn closing_bracket(line: &str) -> Result<Option<usize>, ()> {
+        let mut characters = line.char_indices();
+        while let Some((index, character)) = characters.next() {
+            match character {
+                '\\' => {
+                    characters.next();
+                }
+                '[' => return Err(()),
+                ']' => return Ok(Some(index + 1)),
+                _ => {}
+            }
+        }
+        Ok(None)
+    }
+
     /// A link label can have at most 999 characters inside the square brackets.
     pub fn valid_character_count(segment: &str) -> bool {
         segment.chars().count() <= 999
@@ -1,3 +1,140 @@
 mod link_destination;
 mod link_label;
 mod link_title;
+
+pub use link_label::link_label;
+
+use crate::{
+    ast::{
+        block::LinkReferenceDefinition,
+        inline::link::{LinkDestination, LinkLabel, LinkTitle},
+    },
+    parse::{
+        input::Input,
+        parsers::space_or_tab_and_up_to_1_line_ending,
+        traits::{Parse, ParseLine},
+    },
+};
+use parser::{Map, ParseResult, Parser, maybe, tag, validate};
+use std::collections::HashMap;
+
+/// The link reference definitions that references can match, by normalized label.
+#[derive(Debug, Clone, Default)]
+pub struct Definitions<'a>(HashMap<String, LinkReferenceDefinition<'a>>);
+
+impl<'a> Definitions<'a> {
+    /// Returns the definition matching the normalized label, if any.
+    pub fn get(&self, label: &str) -> Option<&LinkReferenceDefinition<'a>> {
+        self.0.get(label)
+    }
+}
+
+impl<'a> FromIterator<LinkReferenceDefinition<'a>> for Definitions<'a> {
+    fn from_iter<T: IntoIterator<Item = LinkReferenceDefinition<'a>>>(definitions: T) -> Self {
+        let mut by_label = HashMap::new();
+        for definition in definitions {
+            // When multiple definitions share the same label, the first one wins.
+            by_label
+                .entry(definition.label().normalized())
+                .or_insert(definition);
+        }
+        Self(by_label)
+    }
+}
+
+/// An opening bracket, `[` for links or `![` for images, waiting for its closing bracket.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bracket<'a> {
+    segment: &'a str,
+    /// The index of the link text in the inline content.
+    text_start: usize,
+    /// Links cannot contain other links, so the brackets before a link are deactivated once it is formed.
+    active: bool,
+    /// How deep the inlines are nested in the items following the bracket, up to the next open one.
+    depth: usize,
+}
+
+impl<'a> Bracket<'a> {
+    pub fn new(segment: &'a str, text_start: usize) -> Self {
+        Self {
+            segment,
+            text_start,
+            active: true,
+            depth: 0,
+        }
+    }
+
+    pub fn segment(&self) -> &'a str {
+        self.segment
+    }
+
+    pub fn text_start(&self) -> usize {
+        self.text_start
+    }
+
+    pub fn is_image(&self) -> bool {
+        self.segment.starts_with('!')
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    pub fn deactivate(&mut self) {
+        self.active = false;
+    }
+
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    pub fn nest(&mut self, depth: usize) {
+        self.depth = self.depth.max(depth);
+    }
+}
+
+/// Parses the destination and title of an inline link, between the parentheses following the link text.
+///
+/// Both are optional, and they can be surrounded by spaces, tabs and up to one line ending.
+#[allow(clippy::type_complexity)]
+pub fn inline_link<'a, I: Input<'a>>(
+    input: I,
+) -> ParseResult<I, (Option<LinkDestination<'a>>, Option<LinkTitle<'a>>)> {
+    (
+        tag("("),
+        space_or_tab_and_up_to_1_line_ending,
+        maybe((
+            LinkDestination::parse,
+            maybe(
+                (
+                    // The title must be separated from the destination.
+                    validate(space_or_tab_and_up_to_1_line_ending, |parsed: &I| {
+                        !parsed.is_empty()
+                    }),
+                    LinkTitle::parse,
+                )
+                    .map(|(_, title)| title),
+            ),
+        )),
+        space_or_tab_and_up_to_1_line_ending,
+        tag(")"),
+    )
+        .map(
+            |(_, _, destination_and_title, _, _)| match destination_and_title {
+                Some((destination, title)) => (Some(destination), title),
+                None => (None, None),
+            },
+        )
+        .parse(input)
+}
+
+/// Returns the normalized label made of the link text, if the text is a valid link label.
+///
+/// This is how collapsed and shortcut references are matched with their definitions.
+pub fn normalized_text_label(text: &str) -> Option<String> {
+    let label = format!("[{text}]");
+    match LinkLabel::parse_line(&label) {
+        Ok(("", label)) => Some(label.normalized()),
+        _ => None,
+    }
+}
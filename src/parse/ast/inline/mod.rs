@@ -1 +1,931 @@
+pub mod autolink;
+pub mod emphasis;
 pub mod link;
+pub mod raw_html;
+
+use crate::{
+    ParseOptions, Segment, Segments,
+    ast::inline::{
+        Autolink, AutolinkKind, Code, HardBreak, Image, Inline, Link, LinkKind, RawHtml, SoftBreak,
+        Text,
+        link::{LinkDestination, LinkTitle},
+    },
+    parse::{StrippedLine, StrippedLines, input::Input, traits::ParseLine},
+};
+use autolink::extended_autolink_at;
+use emphasis::{DelimiterRun, is_delimiter, process_emphasis};
+use link::{Bracket, Definitions, inline_link, link_label, normalized_text_label};
+use parser::{Parser, SplitAt, SubsetRange, tag};
+use raw_html::{MissingTerminators, raw_html};
+use std::{iter, ops::Range};
+
+/// How many inlines can be nested in one another.
+///
+/// The inlines are rendered recursively, so deeper inlines could overflow the stack. Beyond this
+/// depth, what would open emphasis, a link or an image is text instead.
+pub const MAX_DEPTH: usize = 64;
+
+/// An element of inline content, as parsed before the emphasis is resolved.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Item<'a> {
+    Inline(Inline<'a>),
+    /// A link or an image, with how deep the inlines are nested in it, itself included.
+    Link(Inline<'a>, usize),
+    Delimiter(DelimiterRun<'a>),
+    Bracket(Bracket<'a>),
+    /// An extended autolink, kept apart as it is only text within the text of a link.
+    ExtendedAutolink(&'a str, AutolinkKind),
+}
+
+/// Parses the inline content made of the given lines, resolving the reference links with the definitions.
+/// The constructs of the extensions are only parsed when enabled in the options.
+///
+/// The lines are expected to be slices of the same source, in order. The leading spaces and tabs of
+/// every line are not part of the content, and neither are the trailing whitespaces of the last line.
+pub fn inlines<'a>(
+    segments: impl IntoIterator<Item = &'a str>,
+    definitions: &Definitions<'a>,
+    options: &ParseOptions,
+) -> Vec<Inline<'a>> {
+    let mut lines: Vec<&'a str> = segments
+        .into_iter()
+        .map(|segment| segment.trim_start_matches([' ', '\t']))
+        .collect();
+    // The last line has no line ending, nor trailing whitespace, which can leave nothing of it.
+    while let Some(last) = lines.last_mut() {
+        *last = without_line_ending(last).trim_end_matches([' ', '\t']);
+        if !last.is_empty() {
+            break;
+        }
+        lines.pop();
+    }
+    lines.retain(|line| !line.is_empty());
+    let stripped_lines: Vec<StrippedLine> =
+        lines.iter().copied().map(StrippedLine::explicit).collect();
+    let parse = |options: &ParseOptions, links: &[Range<usize>]| {
+        InlineParser::new(&lines, &stripped_lines, definitions, options, links).parse()
+    };
+    // Without closing brackets, there are no links that extended autolinks could be found in.
+    if !options.autolinks || !lines.iter().any(|line| line.contains(']')) {
+        return parse(options, &[]);
+    }
+    // Extended autolinks cannot take brackets, so the links are the same without them.
+    let without_autolinks = ParseOptions {
+        autolinks: false,
+        ..*options
+    };
+    let mut links = Vec::new();
+    push_link_ranges(&parse(&without_autolinks, &[]), &mut links);
+    parse(options, &links)
+}
+
+/// Pushes the ranges of the addresses that the links and images among the inlines span.
+fn push_link_ranges(inlines: &[Inline], ranges: &mut Vec<Range<usize>>) {
+    for inline in inlines {
+        match inline {
+            Inline::Link(_) | Inline::Image(_) => {
+                let addresses = |segment: &str| {
+                    let start = segment.as_ptr() as usize;
+                    start..start + segment.len()
+                };
+                let range = inline.segments().map(addresses).reduce(|range, segment| {
+                    range.start.min(segment.start)..range.end.max(segment.end)
+                });
+                ranges.extend(range);
+            }
+            Inline::Emphasis(emphasis) => push_link_ranges(emphasis.children(), ranges),
+            Inline::Strikethrough(strikethrough) => {
+                push_link_ranges(strikethrough.children(), ranges)
+            }
+            Inline::Strong(strong) => push_link_ranges(strong.children(), ranges),
+            _ => {}
+        }
+    }
+}
+
+/// A position in the content, as the index of a line and a byte offset within that line.
+type Position = (usize, usize);
+
+/// Accumulates the items parsed from the content, line by line.
+struct InlineParser<'s, 'a> {
+    lines: &'s [&'a str],
+    /// The same lines, as the input of the constructs that can span multiple lines.
+    input: StrippedLines<'s, 'a>,
+    definitions: &'s Definitions<'a>,
+    /// The addresses spanned by the links and images, as found without extended autolinks, in
+    /// order. The ones the parsing is past are left out as it goes.
+    links: &'s [Range<usize>],
+    /// The address of the next character that an extended autolink cannot take within the text of
+    /// a link, or of the end of its line, as last found.
+    markup: usize,
+    items: Vec<Item<'a>>,
+    /// The indices of the bracket items that have not been closed yet, the innermost last.
+    brackets: Vec<usize>,
+    /// How many of the outermost brackets are already deactivated, when they are not for images.
+    deactivated_brackets: usize,
+    missing_terminators: MissingTerminators<usize>,
+}
+
+impl<'s, 'a> InlineParser<'s, 'a> {
+    fn new(
+        lines: &'s [&'a str],
+        stripped_lines: &'s [StrippedLine<'a>],
+        definitions: &'s Definitions<'a>,
+        options: &ParseOptions,
+        links: &'s [Range<usize>],
+    ) -> Self {
+        Self {
+            lines,
+            input: StrippedLines::with_options(stripped_lines, *options),
+            definitions,
+            links,
+            markup: 0,
+            items: Vec::new(),
+            brackets: Vec::new(),
+            deactivated_brackets: 0,
+            missing_terminators: MissingTerminators::default(),
+        }
+    }
+
+    fn parse(mut self) -> Vec<Inline<'a>> {
+        let mut position = (!self.lines.is_empty()).then_some((0, 0));
+        while let Some(current) = position {
+            position = self.parse_line(current);
+        }
+        // The brackets that were never closed are just text.
+        for index in std::mem::take(&mut self.brackets) {
+            self.bracket_to_text(index);
+        }
+        let (inlines, _) = process_emphasis(self.items, MAX_DEPTH);
+        inlines
+    }
+
+    /// Parses the line from the given position, up to its end or up to a construct that can span
+    /// multiple lines: a code span, raw HTML or a link.
+    ///
+    /// Returns the position to continue from, if there is any content left.
+    fn parse_line(&mut self, (line_index, start): Position) -> Option<Position> {
+        let line = self.lines[line_index];
+        // The last line has already been stripped of its line ending and trailing whitespace.
+        let content = if line_index + 1 == self.lines.len() {
+            line
+        } else {
+            without_line_ending(line).trim_end_matches([' ', '\t'])
+        };
+        let mut text_start = start;
+        let mut index = start;
+        let mut trailing_backslash = false;
+        while let Some(character) = content[index..].chars().next() {
+            match character {
+                '\\' => {
+                    // Escaped characters are kept in the text, they never start anything.
+                    let escaped = content[index + 1..]
+                        .chars()
+                        .next()
+                        .is_some_and(|character| character.is_ascii_punctuation());
+                    trailing_backslash = index + 1 == content.len();
+                    index += if escaped { 2 } else { 1 };
+                }
+                character if is_delimiter(character, self.input.options()) => {
+                    self.push_text(&content[text_start..index]);
+                    let run = DelimiterRun::parse(content, index);
+                    index += run.segment().len();
+                    text_start = index;
+                    self.items.push(Item::Delimiter(run));
+                }
+                '`' => {
+                    let length = content[index..]
+                        .find(|c| c != '`')
+                        .unwrap_or(content.len() - index);
+                    // Without a matching closing backtick string, the backticks are just text.
+                    let Some((code, position)) = self.code_span((line_index, index), length) else {
+                        index += length;
+                        continue;
+                    };
+                    self.push_text(&content[text_start..index]);
+                    self.push_inline(Inline::Code(code));
+                    return Some(position);
+                }
+                '[' => {
+                    self.push_text(&content[text_start..index]);
+                    self.push_bracket(&content[index..index + 1]);
+                    index += 1;
+                    text_start = index;
+                }
+                '!' if content[index + 1..].starts_with('[') => {
+                    self.push_text(&content[text_start..index]);
+                    self.push_bracket(&content[index..index + 2]);
+                    index += 2;
+                    text_start = index;
+                }
+                '<' => {
+                    if let Ok((_, autolink)) = Autolink::parse_line(&content[index..]) {
+                        self.push_text(&content[text_start..index]);
+                        index += autolink.segment().len();
+                        text_start = index;
+                        self.push_inline(Inline::Autolink(autolink));
+                        continue;
+                    }
+                    let (start, _) = self.input.subset_range(&content[index..]);
+                    let Ok((remaining, parsed)) =
+                        raw_html(self.input.split_at(start).1, &self.missing_terminators)
+                    else {
+                        index += 1;
+                        continue;
+                    };
+                    self.push_text(&content[text_start..index]);
+                    self.push_inline(Inline::RawHtml(RawHtml::new(parsed.lines().collect())));
+                    return Some(self.position_of(remaining));
+                }
+                ']' if !self.brackets.is_empty() => {
+                    self.push_text(&content[text_start..index]);
+                    text_start = index;
+                    if let Some(position) = self.close_bracket(&content[index..index + 1]) {
+                        return Some(position);
+                    }
+                    index += 1;
+                }
+                _ if self.input.options().autolinks => {
+                    // Within the text of a link, an extended autolink is only text, and it cannot
+                    // take what could be written differently then.
+                    let address = content[index..].as_ptr() as usize;
+                    let passed = self.links.partition_point(|link| link.end <= address);
+                    self.links = &self.links[passed..];
+                    let end = match self.links.first() {
+                        Some(link) if link.start <= address => self.markup_offset(content, index),
+                        _ => content.len(),
+                    };
+                    match extended_autolink_at(&content[..end], index) {
+                        Some((segment, kind)) => {
+                            self.push_text(&content[text_start..index]);
+                            self.items.push(Item::ExtendedAutolink(segment, kind));
+                            index += segment.len();
+                            text_start = index;
+                        }
+                        None => index += character.len_utf8(),
+                    }
+                }
+                _ => index += character.len_utf8(),
+            }
+        }
+        // Only the last line has no line ending.
+        let line_end = &line[content.len()..];
+        if line_end.is_empty() {
+            self.push_text(&content[text_start..]);
+            return None;
+        }
+        let line_ending = line_end.trim_start_matches([' ', '\t']);
+        let trailing_whitespace = &line_end[..line_end.len() - line_ending.len()];
+        if trailing_whitespace.ends_with("  ") {
+            self.push_text(&content[text_start..]);
+            self.push_inline(Inline::HardBreak(HardBreak::new(line_end)));
+        } else if trailing_whitespace.is_empty() && trailing_backslash {
+            let backslash = content.len() - 1;
+            self.push_text(&content[text_start..backslash]);
+            self.push_inline(Inline::HardBreak(HardBreak::new(&line[backslash..])));
+        } else {
+            self.push_text(&content[text_start..]);
+            self.push_inline(Inline::SoftBreak(SoftBreak::new(line_end)));
+        }
+        Some((line_index + 1, 0))
+    }
+
+    /// Looks for the closing backtick string of the code span opened at the given position, which
+    /// can be on a later line.
+    ///
+    /// Returns the code span and the position following it, if there is one.
+    fn code_span(
+        &self,
+        (line_index, start): Position,
+        length: usize,
+    ) -> Option<(Code<'a>, Position)> {
+        let opening = &self.lines[line_index][start..start + length];
+        let mut content = Vec::new();
+        for (index, &line) in self.lines.iter().enumerate().skip(line_index) {
+            let from = if index == line_index {
+                start + length
+            } else {
+                0
+            };
+            match find_backtick_string(&line[from..], length) {
+                Some(closing_start) => {
+                    let closing_start = from + closing_start;
+                    content.push(&line[from..closing_start]);
+                    content.retain(|segment: &&str| !segment.is_empty());
+                    let closing = &line[closing_start..closing_start + length];
+                    let code = Code::new(opening, content, closing);
+                    return Some((code, (index, closing_start + length)));
+                }
+                None => content.push(&line[from..]),
+            }
+        }
+        None
+    }
+
+    fn push_bracket(&mut self, segment: &'a str) {
+        let (_, text_start) = self.input.subset_range(segment);
+        self.items
+            .push(Item::Bracket(Bracket::new(segment, text_start)));
+        self.brackets.push(self.items.len() - 1);
+    }
+
+    /// Looks for a link, or an image, ending with the closing bracket, following the
+    /// [spec](https://spec.commonmark.org/0.31.2/#look-for-link-or-image).
+    ///
+    /// Returns the position following the link, if one was formed.
+    fn close_bracket(&mut self, closing_bracket: &'a str) -> Option<Position> {
+        let opener_index = self.brackets.pop()?;
+        self.deactivated_brackets = self.deactivated_brackets.min(self.brackets.len());
+        let Item::Bracket(opener) = self.items[opener_index].clone() else {
+            unreachable!("expected a bracket at index {opener_index}");
+        };
+        if !opener.is_active() || opener.depth() >= MAX_DEPTH {
+            self.bracket_to_text(opener_index);
+            self.nest_in_last_bracket(opener.depth());
+            return None;
+        }
+        let (text_end, after) = self.input.subset_range(closing_bracket);
+        let (_, input) = self.input.split_at(after);
+        let text = opener.text_start()..text_end;
+        let Some((remaining, kind, destination, title)) = self.link_end(input, text) else {
+            self.bracket_to_text(opener_index);
+            self.nest_in_last_bracket(opener.depth());
+            return None;
+        };
+        let (end, _) = self.input.subset_range(remaining);
+        let closing = iter::once(closing_bracket)
+            .chain(input.split_at(end).0.lines())
+            .collect();
+        // Neither links nor images can contain extended autolinks.
+        let children =
+            Iterator::map(
+                self.items.split_off(opener_index + 1).into_iter(),
+                |item| match item {
+                    Item::ExtendedAutolink(segment, _) => {
+                        Item::Inline(Inline::Text(Text::new(segment)))
+                    }
+                    item => item,
+                },
+            );
+        let (children, depth) = process_emphasis(children.collect(), MAX_DEPTH - 1);
+        self.items.pop();
+        let inline = if opener.is_image() {
+            Inline::Image(Image::new(
+                opener.segment(),
+                children,
+                closing,
+                kind,
+                destination,
+                title,
+            ))
+        } else {
+            // Links cannot contain other links.
+            for &index in &self.brackets[self.deactivated_brackets..] {
+                if let Item::Bracket(bracket) = &mut self.items[index] {
+                    if !bracket.is_image() {
+                        bracket.deactivate();
+                    }
+                }
+            }
+            self.deactivated_brackets = self.brackets.len();
+            Inline::Link(Link::new(
+                opener.segment(),
+                children,
+                closing,
+                kind,
+                destination,
+                title,
+            ))
+        };
+        self.items.push(Item::Link(inline, depth + 1));
+        self.nest_in_last_bracket(depth + 1);
+        Some(self.position_of(remaining))
+    }
+
+    /// Parses what follows the link text, found between the given offsets: either the destination
+    /// and title of an inline link, or the reference to a definition.
+    ///
+    /// Returns the remaining input and the target of the link, if any.
+    #[allow(clippy::type_complexity)]
+    fn link_end(
+        &self,
+        input: StrippedLines<'s, 'a>,
+        text: Range<usize>,
+    ) -> Option<(
+        StrippedLines<'s, 'a>,
+        LinkKind,
+        Option<LinkDestination<'a>>,
+        Option<LinkTitle<'a>>,
+    )> {
+        if let Ok((remaining, (destination, title))) = inline_link(input) {
+            return Some((remaining, LinkKind::Inline, destination, title));
+        }
+        let (remaining, kind, label) = match link_label(input) {
+            Ok((remaining, label)) => (remaining, LinkKind::Full, Some(label.normalized())),
+            Err(input) => match tag("[]").parse(input) {
+                Ok((remaining, _)) => (remaining, LinkKind::Collapsed, self.text_label(text)),
+                Err(input) => (input, LinkKind::Shortcut, self.text_label(text)),
+            },
+        };
+        let label = label?;
+        let definition = self.definitions.get(&label)?;
+        Some((
+            remaining,
+            kind,
+            Some(definition.destination().clone()),
+            definition.title().cloned(),
+        ))
+    }
+
+    /// Returns the normalized label made of the link text found between the given offsets, if the
+    /// text is a valid link label.
+    fn text_label(&self, Range { start, end }: Range<usize>) -> Option<String> {
+        let mut text = String::new();
+        for line in self.input.split_at(end).0.split_at(start).1.lines() {
+            // A label has at most 999 characters, so there is no need to collect more of the text.
+            if text.len() + line.len() > 4 * 999 {
+                return None;
+            }
+            text.push_str(line);
+        }
+        normalized_text_label(&text)
+    }
+
+    /// Returns the position of the start of the remaining input.
+    fn position_of(&self, remaining: StrippedLines<'s, 'a>) -> Position {
+        let last_line = self.lines.len() - 1;
+        let Some(rest) = remaining.lines().next() else {
+            return (last_line, self.lines[last_line].len());
+        };
+        // The rest of a line ends where the line does.
+        let end = |segment: &str| segment.as_ptr() as usize + segment.len();
+        let line_index = self.lines.partition_point(|line| end(line) < end(rest));
+        assert!(
+            self.lines
+                .get(line_index)
+                .is_some_and(|line| end(line) == end(rest)),
+            "remaining input should be part of the lines"
+        );
+        (line_index, self.lines[line_index].len() - rest.len())
+    }
+
+    fn bracket_to_text(&mut self, index: usize) {
+        if let Item::Bracket(bracket) = &self.items[index] {
+            self.items[index] = Item::Inline(Inline::Text(Text::new(bracket.segment())));
+        }
+    }
+
+    /// Records how deep the inlines are nested in an item following the innermost open bracket.
+    fn nest_in_last_bracket(&mut self, depth: usize) {
+        if let Some(&index) = self.brackets.last() {
+            if let Item::Bracket(bracket) = &mut self.items[index] {
+                bracket.nest(depth);
+            }
+        }
+    }
+
+    fn push_inline(&mut self, inline: Inline<'a>) {
+        self.items.push(Item::Inline(inline));
+    }
+
+    /// Returns the offset of the next character of the line content, from the given one, that
+    /// could be written differently, and that an extended autolink cannot take within the text of
+    /// a link. The offset is kept for the following characters, so that the line is scanned once.
+    fn markup_offset(&mut self, content: &str, index: usize) -> usize {
+        let start = content.as_ptr() as usize;
+        if self.markup < start + index {
+            let offset = content[index..]
+                .find(['\\', '*', '_', '~', '&', '!'])
+                .map_or(content.len(), |offset| index + offset);
+            self.markup = start + offset;
+        }
+        self.markup - start
+    }
+
+    fn push_text(&mut self, text: &'a str) {
+        if !text.is_empty() {
+            self.push_inline(Inline::Text(Text::new(text)));
+        }
+    }
+}
+
+/// Strips the line ending of the line: a line feed, optionally preceded by a carriage return, or the
+/// carriage return ending the source. The other carriage returns are part of the content.
+fn without_line_ending(line: &str) -> &str {
+    let line = line.strip_suffix('\n').unwrap_or(line);
+    line.strip_suffix('\r').unwrap_or(line)
+}
+
+/// Returns the index of the first backtick string of exactly the given length in the text.
+fn find_backtick_string(text: &str, length: usize) -> Option<usize> {
+    let mut index = 0;
+    while let Some(start) = text[index..].find('`').map(|offset| index + offset) {
+        let end = text[start..]
+            .find(|c| c != '`')
+            .map_or(text.len(), |offset| start + offset);
+        if end - start == length {
+            return Some(start);
+        }
+        index = end;
+    }
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        Segment, Segments,
+        ast::{
+            block::LinkReferenceDefinition,
+            inline::{AutolinkKind, Emphasis},
+        },
+        parse::{Lines, traits::Parse},
+    };
+
+    mod inlines {
+        use super::*;
+
+        #[test]
+        fn should_return_nothing_for_empty_content() {
+            assert_eq!(
+                inlines([], &Definitions::default(), &ParseOptions::default()),
+                vec![]
+            );
+            assert_eq!(
+                inlines([""], &Definitions::default(), &ParseOptions::default()),
+                vec![]
+            );
+        }
+
+        #[test]
+        fn should_work_with_single_line() {
+            assert_eq!(
+                inlines(["foo\n"], &Definitions::default(), &ParseOptions::default()),
+                vec![Inline::Text(Text::new("foo"))]
+            );
+        }
+
+        #[test]
+        fn should_keep_carriage_returns_before_the_line_ending() {
+            assert_eq!(
+                inlines(
+                    ["\r\r\t"],
+                    &Definitions::default(),
+                    &ParseOptions::default()
+                ),
+                vec![Inline::Text(Text::new("\r\r"))]
+            );
+            assert_eq!(
+                inlines(
+                    ["foo\r\r\n"],
+                    &Definitions::default(),
+                    &ParseOptions::default()
+                ),
+                vec![Inline::Text(Text::new("foo\r"))]
+            );
+        }
+
+        #[test]
+        fn should_ignore_trailing_lines_without_content() {
+            assert_eq!(
+                inlines(
+                    ["foo\n", "\r"],
+                    &Definitions::default(),
+                    &ParseOptions::default()
+                ),
+                vec![Inline::Text(Text::new("foo"))]
+            );
+        }
+
+        #[test]
+        fn should_strip_leading_whitespace() {
+            assert_eq!(
+                inlines(
+                    "  foo\n\tbar".split_inclusive('\n'),
+                    &Definitions::default(),
+                    &ParseOptions::default()
+                ),
+                vec![
+                    Inline::Text(Text::new("foo")),
+                    Inline::SoftBreak(SoftBreak::new("\n")),
+                    Inline::Text(Text::new("bar")),
+                ]
+            );
+        }
+
+        #[test]
+        fn should_include_trailing_spaces_in_soft_break() {
+            assert_eq!(
+                inlines(
+                    "foo \r\nbar  \n".split_inclusive('\n'),
+                    &Definitions::default(),
+                    &ParseOptions::default()
+                ),
+                vec![
+                    Inline::Text(Text::new("foo")),
+                    Inline::SoftBreak(SoftBreak::new(" \r\n")),
+                    Inline::Text(Text::new("bar")),
+                ]
+            );
+        }
+
+        #[test]
+        fn should_parse_hard_breaks() {
+            assert_eq!(
+                inlines(
+                    "foo  \nbar\\\r\nbaz".split_inclusive('\n'),
+                    &Definitions::default(),
+                    &ParseOptions::default()
+                ),
+                vec![
+                    Inline::Text(Text::new("foo")),
+                    Inline::HardBreak(HardBreak::new("  \n")),
+                    Inline::Text(Text::new("bar")),
+                    Inline::HardBreak(HardBreak::new("\\\r\n")),
+                    Inline::Text(Text::new("baz")),
+                ]
+            );
+        }
+
+        #[test]
+        fn should_not_parse_escaped_backslash_as_hard_break() {
+            assert_eq!(
+                inlines(
+                    "foo\\\\\nbar".split_inclusive('\n'),
+                    &Definitions::default(),
+                    &ParseOptions::default()
+                ),
+                vec![
+                    Inline::Text(Text::new("foo\\\\")),
+                    Inline::SoftBreak(SoftBreak::new("\n")),
+                    Inline::Text(Text::new("bar")),
+                ]
+            );
+        }
+
+        #[test]
+        fn should_not_parse_hard_breaks_at_the_end() {
+            assert_eq!(
+                inlines(["foo\\"], &Definitions::default(), &ParseOptions::default()),
+                vec![Inline::Text(Text::new("foo\\"))]
+            );
+        }
+
+        #[test]
+        fn should_give_back_the_original_lines() {
+            let source = "foo  \nbar\\\n*baz* \nqux";
+            let inlines = inlines(
+                source.split_inclusive('\n'),
+                &Definitions::default(),
+                &ParseOptions::default(),
+            );
+            let segments: String = inlines.iter().flat_map(Inline::segments).collect();
+            assert_eq!(segments, source);
+        }
+
+        #[test]
+        fn should_parse_inline_links() {
+            let inlines = inlines(
+                ["[foo](/url \"title\")"],
+                &Definitions::default(),
+                &ParseOptions::default(),
+            );
+            let [Inline::Link(link)] = inlines.as_slice() else {
+                panic!("expected a link: {inlines:?}");
+            };
+            assert_eq!(link.kind(), LinkKind::Inline);
+            assert_eq!(link.children(), [Inline::Text(Text::new("foo"))]);
+            assert_eq!(link.destination().map(Segment::segment), Some("/url"));
+            assert!(link.title().is_some());
+            assert_eq!(link.segments().collect::<String>(), "[foo](/url \"title\")");
+        }
+
+        #[test]
+        fn should_parse_links_spanning_multiple_lines() {
+            let inlines = inlines(
+                "[foo\nbar](\n/url\n) baz".split_inclusive('\n'),
+                &Definitions::default(),
+                &ParseOptions::default(),
+            );
+            let [Inline::Link(link), Inline::Text(text)] = inlines.as_slice() else {
+                panic!("expected a link followed by text: {inlines:?}");
+            };
+            assert_eq!(link.children().len(), 3);
+            assert_eq!(link.destination().map(Segment::segment), Some("/url"));
+            assert_eq!(text.segment(), " baz");
+        }
+
+        #[test]
+        fn should_resolve_reference_links() {
+            let (_, definition) =
+                LinkReferenceDefinition::parse(Lines::from("[Foo]: /url\n")).unwrap();
+            let definitions = Definitions::from_iter([definition]);
+            for (source, kind) in [
+                ("[bar][FOO]", LinkKind::Full),
+                ("[foo][]", LinkKind::Collapsed),
+                ("[FOO]", LinkKind::Shortcut),
+            ] {
+                let inlines = inlines([source], &definitions, &ParseOptions::default());
+                let [Inline::Link(link)] = inlines.as_slice() else {
+                    panic!("expected a link for {source}: {inlines:?}");
+                };
+                assert_eq!(link.kind(), kind);
+                assert_eq!(link.destination().map(Segment::segment), Some("/url"));
+            }
+        }
+
+        #[test]
+        fn should_leave_undefined_references_as_text() {
+            assert!(
+                inlines(["[foo]"], &Definitions::default(), &ParseOptions::default())
+                    .iter()
+                    .all(|inline| matches!(inline, Inline::Text(_)))
+            );
+        }
+
+        #[test]
+        fn should_not_nest_links() {
+            let inlines = inlines(
+                ["[a [b](/b)](/a)"],
+                &Definitions::default(),
+                &ParseOptions::default(),
+            );
+            assert!(matches!(
+                inlines.as_slice(),
+                [
+                    Inline::Text(_),
+                    Inline::Text(_),
+                    Inline::Link(_),
+                    Inline::Text(_)
+                ]
+            ));
+        }
+
+        #[test]
+        fn should_parse_images() {
+            let inlines = inlines(
+                ["![foo *bar*](/url)"],
+                &Definitions::default(),
+                &ParseOptions::default(),
+            );
+            let [Inline::Image(image)] = inlines.as_slice() else {
+                panic!("expected an image: {inlines:?}");
+            };
+            assert!(matches!(
+                image.children(),
+                [Inline::Text(_), Inline::Emphasis(_)]
+            ));
+        }
+
+        #[test]
+        fn should_leave_images_beyond_the_maximum_depth_as_text() {
+            let source = "![a ".repeat(MAX_DEPTH + 1) + &" a](b)".repeat(MAX_DEPTH + 1);
+            let mut inlines = inlines(
+                [source.as_str()],
+                &Definitions::default(),
+                &ParseOptions::default(),
+            );
+            for _ in 0..MAX_DEPTH {
+                let Some(Inline::Image(image)) = inlines
+                    .iter()
+                    .find(|inline| matches!(inline, Inline::Image(_)))
+                else {
+                    panic!("expected an image: {inlines:?}");
+                };
+                inlines = image.children().to_vec();
+            }
+            assert!(
+                inlines
+                    .iter()
+                    .all(|inline| matches!(inline, Inline::Text(_)))
+            );
+        }
+
+        #[test]
+        fn should_parse_code_spans() {
+            assert_eq!(
+                inlines(
+                    ["foo `` ` `` bar"],
+                    &Definitions::default(),
+                    &ParseOptions::default()
+                ),
+                vec![
+                    Inline::Text(Text::new("foo ")),
+                    Inline::Code(Code::new("``", vec![" ` "], "``")),
+                    Inline::Text(Text::new(" bar")),
+                ]
+            );
+        }
+
+        #[test]
+        fn should_parse_code_spans_spanning_multiple_lines() {
+            assert_eq!(
+                inlines(
+                    "`foo\nbar`".split_inclusive('\n'),
+                    &Definitions::default(),
+                    &ParseOptions::default()
+                ),
+                vec![Inline::Code(Code::new("`", vec!["foo\n", "bar"], "`"))]
+            );
+        }
+
+        #[test]
+        fn should_leave_unmatched_backticks_as_text() {
+            assert_eq!(
+                inlines(
+                    ["```foo``"],
+                    &Definitions::default(),
+                    &ParseOptions::default()
+                ),
+                vec![Inline::Text(Text::new("```foo``"))]
+            );
+        }
+
+        #[test]
+        fn should_give_code_spans_precedence_over_emphasis_and_links() {
+            assert_eq!(
+                inlines(
+                    ["*foo`*`"],
+                    &Definitions::default(),
+                    &ParseOptions::default()
+                ),
+                vec![
+                    Inline::Text(Text::new("*")),
+                    Inline::Text(Text::new("foo")),
+                    Inline::Code(Code::new("`", vec!["*"], "`")),
+                ]
+            );
+            assert!(
+                !inlines(
+                    ["[foo`](/uri)`"],
+                    &Definitions::default(),
+                    &ParseOptions::default()
+                )
+                .iter()
+                .any(|inline| matches!(inline, Inline::Link(_)))
+            );
+        }
+
+        #[test]
+        fn should_parse_autolinks() {
+            assert_eq!(
+                inlines(
+                    ["see <https://example.com>, <foo@bar.com>"],
+                    &Definitions::default(),
+                    &ParseOptions::default()
+                ),
+                vec![
+                    Inline::Text(Text::new("see ")),
+                    Inline::Autolink(Autolink::new("<https://example.com>", AutolinkKind::Uri)),
+                    Inline::Text(Text::new(", ")),
+                    Inline::Autolink(Autolink::new("<foo@bar.com>", AutolinkKind::Email)),
+                ]
+            );
+        }
+
+        #[test]
+        fn should_parse_raw_html_spanning_multiple_lines() {
+            assert_eq!(
+                inlines(
+                    "foo <a\nhref=\"bar\">*baz*".split_inclusive('\n'),
+                    &Definitions::default(),
+                    &ParseOptions::default()
+                ),
+                vec![
+                    Inline::Text(Text::new("foo ")),
+                    Inline::RawHtml(RawHtml::new(vec!["<a\n", "href=\"bar\">"])),
+                    Inline::Emphasis(Emphasis::new(
+                        "*",
+                        vec![Inline::Text(Text::new("baz"))],
+                        "*"
+                    )),
+                ]
+            );
+        }
+
+        #[test]
+        fn should_leave_invalid_tags_as_text() {
+            assert_eq!(
+                inlines(
+                    ["<33> <a"],
+                    &Definitions::default(),
+                    &ParseOptions::default()
+                ),
+                vec![Inline::Text(Text::new("<33> <a"))]
+            );
+        }
+
+        #[test]
+        fn should_keep_escaped_characters_in_text() {
+            assert_eq!(
+                inlines(
+                    [r"\*foo\* \[bar\] \`baz\`"],
+                    &Definitions::default(),
+                    &ParseOptions::default()
+                ),
+                vec![Inline::Text(Text::new(r"\*foo\* \[bar\] \`baz\`"))]
+            );
+        }
+    }
+}
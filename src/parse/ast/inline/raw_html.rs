@@ -0,0 +1,195 @@
+use crate::parse::{
+    ast::block::leaf::html::utils::{closing_tag, open_tag},
+    input::Input,
+};
+use parser::{ItemsIndices, ParseResult, Parser, one_of, recognize, tag, take};
+use std::cell::Cell;
+
+/// Where the terminators of the raw HTML constructs were found missing, from there to the end of
+/// the input.
+///
+/// The constructs starting past that fail right away, instead of looking for their terminator
+/// again, which would take quadratic time with many of them left unterminated.
+#[derive(Debug, Default)]
+pub struct MissingTerminators<Index: Copy> {
+    comment: Cell<Option<Index>>,
+    processing_instruction: Cell<Option<Index>>,
+    declaration: Cell<Option<Index>>,
+    cdata_section: Cell<Option<Index>>,
+}
+
+/// Parses [raw HTML](https://spec.commonmark.org/0.31.2/#raw-html), which can span multiple lines.
+///
+/// Unlike the HTML blocks, any tag name is accepted.
+pub fn raw_html<'a, I>(input: I, missing: &MissingTerminators<I::Index>) -> ParseResult<I, I>
+where
+    I: Input<'a>,
+    I::Index: PartialOrd,
+{
+    one_of((
+        open_tag(|_: &I| true),
+        closing_tag(|_: &I| true),
+        |input| comment(input, missing),
+        |input| processing_instruction(input, missing),
+        |input| declaration(input, missing),
+        |input| cdata_section(input, missing),
+    ))
+    .parse(input)
+}
+
+/// An HTML comment consists of `<!-->`, `<!--->`, or `<!--`, a string of characters not including
+/// the string `-->`, and `-->`.
+fn comment<'a, I>(input: I, missing: &MissingTerminators<I::Index>) -> ParseResult<I, I>
+where
+    I: Input<'a>,
+    I::Index: PartialOrd,
+{
+    one_of((
+        tag("<!-->"),
+        tag("<!--->"),
+        recognize((tag("<!--"), through("-->", &missing.comment))),
+    ))
+    .parse(input)
+}
+
+/// A processing instruction consists of the string `<?`, a string of characters not including the
+/// string `?>`, and the string `?>`.
+fn processing_instruction<'a, I>(
+    input: I,
+    missing: &MissingTerminators<I::Index>,
+) -> ParseResult<I, I>
+where
+    I: Input<'a>,
+    I::Index: PartialOrd,
+{
+    recognize((tag("<?"), through("?>", &missing.processing_instruction))).parse(input)
+}
+
+/// A declaration consists of the string `<!`, an ASCII letter, zero or more characters not including
+/// the character `>`, and the character `>`.
+fn declaration<'a, I>(input: I, missing: &MissingTerminators<I::Index>) -> ParseResult<I, I>
+where
+    I: Input<'a>,
+    I::Index: PartialOrd,
+{
+    recognize((
+        tag("<!"),
+        take(1).that(|c: char| c.is_ascii_alphabetic()),
+        through(">", &missing.declaration),
+    ))
+    .parse(input)
+}
+
+/// A CDATA section consists of the string `<![CDATA[`, a string of characters not including the
+/// string `]]>`, and the string `]]>`.
+fn cdata_section<'a, I>(input: I, missing: &MissingTerminators<I::Index>) -> ParseResult<I, I>
+where
+    I: Input<'a>,
+    I::Index: PartialOrd,
+{
+    recognize((tag("<![CDATA["), through("]]>", &missing.cdata_section))).parse(input)
+}
+
+/// Parses everything up to, and including, the first occurrence of the terminator, which can be on
+/// a later line.
+///
+/// Records where the terminator was found missing, to fail right away from there on.
+fn through<'a, 'm, I>(
+    terminator: &'static str,
+    missing: &'m Cell<Option<I::Index>>,
+) -> impl Fn(I) -> ParseResult<I, I> + 'm
+where
+    I: Input<'a>,
+    I::Index: PartialOrd,
+{
+    move |input: I| {
+        let (start, _) = input.subset_range(input.clone());
+        if missing.get().is_some_and(|index| start >= index) {
+            return Err(input);
+        }
+        for (index, _) in <I as ItemsIndices<char>>::items_indices(&input) {
+            let (_, rest) = input.split_at(index);
+            if let Ok((remaining, _)) = tag(terminator).parse(rest) {
+                let (parsed, _) = input.split_at(input.subset_range(remaining.clone()).0);
+                return Ok((remaining, parsed));
+            }
+        }
+        missing.set(Some(start));
+        Err(input)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::parse::Lines;
+
+    fn parse(source: &str) -> Result<(Vec<&str>, Vec<&str>), Vec<&str>> {
+        raw_html(Lines::from(source), &MissingTerminators::default())
+            .map(|(remaining, parsed)| (parsed.lines().collect(), remaining.lines().collect()))
+            .map_err(|input| input.lines().collect())
+    }
+
+    #[test]
+    fn should_parse_open_tags_with_any_name() {
+        assert_eq!(
+            parse("<a foo=\"bar\" bam = 'baz <em>\"</em>'\n_boolean zoop:33=zoop:33 /> x"),
+            Ok((
+                vec![
+                    "<a foo=\"bar\" bam = 'baz <em>\"</em>'\n",
+                    "_boolean zoop:33=zoop:33 />"
+                ],
+                vec![" x"]
+            ))
+        );
+        assert_eq!(parse("<pre>"), Ok((vec!["<pre>"], vec![])));
+    }
+
+    #[test]
+    fn should_parse_closing_tags() {
+        assert_eq!(parse("</a  >"), Ok((vec!["</a  >"], vec![])));
+    }
+
+    #[test]
+    fn should_reject_invalid_tags() {
+        assert!(parse("<33>").is_err());
+        assert!(parse("<a h*#ref=\"hi\">").is_err());
+        assert!(parse("</a href=\"foo\">").is_err());
+    }
+
+    #[test]
+    fn should_parse_comments() {
+        assert_eq!(
+            parse("<!-- foo\n-- bar -->x"),
+            Ok((vec!["<!-- foo\n", "-- bar -->"], vec!["x"]))
+        );
+        assert_eq!(parse("<!-->"), Ok((vec!["<!-->"], vec![])));
+        assert_eq!(parse("<!--->"), Ok((vec!["<!--->"], vec![])));
+        assert!(parse("<!-- foo").is_err());
+    }
+
+    #[test]
+    fn should_parse_processing_instructions() {
+        assert_eq!(
+            parse("<?php echo $a; ?>"),
+            Ok((vec!["<?php echo $a; ?>"], vec![]))
+        );
+    }
+
+    #[test]
+    fn should_parse_declarations() {
+        assert_eq!(
+            parse("<!ELEMENT br EMPTY>"),
+            Ok((vec!["<!ELEMENT br EMPTY>"], vec![]))
+        );
+        assert!(parse("<!1>").is_err());
+    }
+
+    #[test]
+    fn should_parse_cdata_sections() {
+        assert_eq!(
+            parse("<![CDATA[>&<]]>"),
+            Ok((vec!["<![CDATA[>&<]]>"], vec![]))
+        );
+    }
+}
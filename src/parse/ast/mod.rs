@@ -2,11 +2,174 @@ pub mod block;
 pub mod inline;
 
 use super::{input::Input, traits::Parse};
-use crate::ast::{Document, block::Block};
-use parser::{Map, ParseResult, Parser, Repeated};
+use crate::{
+    ParseOptions,
+    ast::{
+        Document,
+        block::{Block, Container, Leaf},
+    },
+};
+use block::{blocks, leaf::table::unescape_pipes};
+use inline::{inlines, link::Definitions};
+use parser::{Map, ParseResult, Parser};
 
 impl<'a> Parse<'a> for Document<'a> {
     fn parse<I: Input<'a>>(input: I) -> ParseResult<I, Self> {
-        Block::parse.repeated().map(Document::from).parse(input)
+        let options = *input.options();
+        blocks
+            .map(|blocks: Vec<Block<'a>>| {
+                let mut document = Document::from(blocks);
+                let definitions = document
+                    .link_reference_definitions
+                    .iter()
+                    .cloned()
+                    .collect();
+                parse_inlines(&mut document.blocks, &definitions, &options);
+                document
+            })
+            .parse(input)
+    }
+}
+
+/// Parses the inline content of the leaves, recursively through the containers.
+///
+/// This happens once all the blocks are known, as inlines depend on the whole document: links can
+/// reference definitions found anywhere in it.
+///
+/// The leaves detached from their source are left as they are, with the inlines they were detached
+/// with.
+pub fn parse_inlines<'a>(
+    blocks: &mut [Block<'a>],
+    definitions: &Definitions<'a>,
+    options: &ParseOptions,
+) {
+    for block in blocks {
+        match block {
+            Block::Container(Container::BlockQuote(block_quote)) => {
+                parse_inlines(block_quote.blocks_mut(), definitions, options)
+            }
+            Block::Container(Container::List(list)) => {
+                for item in list.items_mut() {
+                    parse_inlines(item.blocks_mut(), definitions, options);
+                }
+            }
+            Block::Leaf(Leaf::AtxHeading(atx_heading)) => {
+                if let Some(line) = atx_heading.content_line() {
+                    atx_heading.set_inlines(inlines([line], definitions, options));
+                }
+            }
+            Block::Leaf(Leaf::Paragraph(paragraph)) => {
+                if let Some(lines) = paragraph.content_lines() {
+                    paragraph.set_inlines(inlines(lines, definitions, options));
+                }
+            }
+            Block::Leaf(Leaf::SetextHeading(setext_heading)) => {
+                if let Some(lines) = setext_heading.content_lines() {
+                    setext_heading.set_inlines(inlines(lines, definitions, options));
+                }
+            }
+            Block::Leaf(Leaf::Table(table)) => {
+                for row in table.all_rows_mut() {
+                    for cell in row.cells_mut() {
+                        if let Some(line) = cell.content_line() {
+                            cell.set_inlines(inlines([line], definitions, options));
+                            unescape_pipes(cell.inlines_mut());
+                        }
+                    }
+                }
+            }
+            Block::Leaf(_) => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod parse {
+        use super::*;
+        use crate::{Segment, ToHtml, ast::inline::Inline, parse::Lines};
+
+        #[test]
+        fn should_collect_link_reference_definitions() {
+            let (_, document) = Document::parse(Lines::from("[foo]: /url\n\nparagraph\n")).unwrap();
+            assert_eq!(document.link_reference_definitions.len(), 1);
+            assert_eq!(
+                document.link_reference_definitions[0].label().segment(),
+                "[foo]"
+            );
+        }
+
+        #[test]
+        fn should_collect_link_reference_definitions_in_block_quotes() {
+            let (_, document) = Document::parse(Lines::from("> [foo]: /url\n")).unwrap();
+            assert_eq!(document.link_reference_definitions.len(), 1);
+            assert_eq!(
+                document.link_reference_definitions[0].label().segment(),
+                "[foo]"
+            );
+        }
+
+        #[test]
+        fn should_parse_inlines_of_nested_paragraphs() {
+            let (_, document) = Document::parse(Lines::from("> - foo\n")).unwrap();
+            let Block::Container(Container::BlockQuote(block_quote)) = &document.blocks[0] else {
+                panic!("expected a block quote: {:?}", document.blocks[0]);
+            };
+            let Block::Container(Container::List(list)) = &block_quote.blocks()[0] else {
+                panic!("expected a list: {:?}", block_quote.blocks()[0]);
+            };
+            let Block::Leaf(Leaf::Paragraph(paragraph)) = &list.items()[0].blocks()[0] else {
+                panic!("expected a paragraph: {:?}", list.items()[0].blocks()[0]);
+            };
+            assert!(matches!(paragraph.inlines(), [Inline::Text(text)] if text.segment() == "foo"));
+        }
+
+        #[test]
+        fn should_keep_the_first_definition_of_a_label() {
+            let (_, document) =
+                Document::parse(Lines::from("[foo]: /first\n[FOO]: /second\n")).unwrap();
+            assert_eq!(document.link_reference_definitions.len(), 1);
+            assert_eq!(
+                document.link_reference_definitions[0]
+                    .destination()
+                    .segment(),
+                "/first"
+            );
+        }
+
+        #[test]
+        fn should_take_a_lone_carriage_return_for_a_blank_line() {
+            for source in ["\r", " \t\r", "foo\n\r"] {
+                let (_, document) = Document::parse(Lines::from(source)).unwrap();
+                assert!(
+                    matches!(
+                        document.blocks.last(),
+                        Some(Block::Leaf(Leaf::BlankLine(_)))
+                    ),
+                    "{source:?}: {:?}",
+                    document.blocks
+                );
+            }
+            assert_eq!(crate::parse("\r").to_html(), "");
+            assert_eq!(crate::parse("foo\n\r").to_html(), "<p>foo</p>");
+        }
+    }
+
+    mod parse_inlines {
+        use super::*;
+        use crate::parse::Lines;
+
+        #[test]
+        fn should_leave_detached_leaves_as_they_are() {
+            let source = "# *foo*\n\nbar\n===\n\n| `a\\|b` |\n| - |\n\n> [baz]\n\n[baz]: /url\n";
+            let options = ParseOptions::gfm();
+            let (_, document) = Document::parse(Lines::with_options(source, options)).unwrap();
+            let document = document.into_owned();
+            let mut blocks = document.blocks.clone();
+            parse_inlines(&mut blocks, &Definitions::default(), &options);
+            assert_eq!(blocks, document.blocks);
+        }
     }
 }
@@ -1,3 +1,4 @@
+use crate::ParseOptions;
 use parser::{IsEmpty, ItemsIndices, SplitAt, SubsetRange};
 use std::{fmt::Debug, iter::Map};
 
@@ -22,4 +23,22 @@ where
     > {
         <Self as ItemsIndices<&'a str>>::items(self)
     }
+
+    /// The options of the parsing, which enable the extensions.
+    fn options(&self) -> &ParseOptions;
+
+    /// Returns whether the line starting at the given index is a
+    /// [lazy continuation line](https://spec.commonmark.org/0.31.2/#lazy-continuation-line).
+    ///
+    /// Only the content of containers can have such lines, hence the default.
+    fn is_lazy_continuation(&self, _index: Self::Index) -> bool {
+        false
+    }
+
+    /// Returns how many containers the input is nested in.
+    ///
+    /// Only the content of containers is nested, hence the default.
+    fn depth(&self) -> usize {
+        0
+    }
 }
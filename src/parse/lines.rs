@@ -1,4 +1,5 @@
 use super::input::Input;
+use crate::ParseOptions;
 use parser::{Indexable, IsEmpty, ItemsIndices, SplitAt, SubsetRange};
 use std::str::{CharIndices, SplitInclusive};
 
@@ -9,11 +10,16 @@ pub fn lines<'a, T: Into<Lines<'a>>>(source: T) -> Lines<'a> {
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Lines<'a> {
     source: &'a str,
+    options: ParseOptions,
 }
 
 impl<'a> Lines<'a> {
     pub fn new(source: &'a str) -> Self {
-        Self { source }
+        Self::with_options(source, ParseOptions::default())
+    }
+
+    pub fn with_options(source: &'a str, options: ParseOptions) -> Self {
+        Self { source, options }
     }
 
     pub fn len(&self) -> usize {
@@ -67,7 +73,10 @@ impl<'a> SubsetRange<&'a str> for Lines<'a> {
 impl SplitAt for Lines<'_> {
     fn split_at(&self, index: Self::Index) -> (Self, Self) {
         let (left, right) = self.source.split_at(index);
-        (left.into(), right.into())
+        (
+            Self::with_options(left, self.options),
+            Self::with_options(right, self.options),
+        )
     }
 }
 
@@ -77,7 +86,11 @@ impl IsEmpty for Lines<'_> {
     }
 }
 
-impl<'a> Input<'a> for Lines<'a> {}
+impl<'a> Input<'a> for Lines<'a> {
+    fn options(&self) -> &ParseOptions {
+        &self.options
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct LinesIndices<I> {
@@ -1,11 +1,16 @@
 pub mod ast;
+mod entities;
 pub mod input;
 mod lines;
 pub mod parsers;
 pub mod predicates;
 pub mod segment;
+mod stripped_lines;
 #[cfg(test)]
 pub mod test_utils;
 pub mod traits;
+mod unescape;
 
 pub use lines::*;
+pub use stripped_lines::*;
+pub use unescape::*;
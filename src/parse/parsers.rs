@@ -1,6 +1,6 @@
 use super::predicates::is_space_or_tab;
 use parser::{
-    IsEmpty, ItemsIndices, ParseResult, Parser, SplitAt, SubsetRange, TakeWhileParser, empty,
+    IsEmpty, ItemsIndices, Map, ParseResult, Parser, SplitAt, SubsetRange, TakeWhileParser, empty,
     maybe, one_of, recognize, tag, take, take_while, validate,
 };
 
@@ -54,12 +54,17 @@ where
     one_of((tag("\n"), tag("\r\n"))).parse(input)
 }
 
-/// Consumes either a line ending or the end of the input.
+/// Consumes either a line ending or the end of the input, which a carriage return can precede.
 pub fn line_ending_or_empty<I>(input: I) -> ParseResult<I, I>
 where
     I: IsEmpty + ItemsIndices<char> + SplitAt + Clone,
 {
-    one_of((line_ending, empty)).parse(input)
+    one_of((
+        line_ending,
+        (tag("\r"), empty).map(|(carriage_return, _)| carriage_return),
+        empty,
+    ))
+    .parse(input)
 }
 
 /// Consumes any amount of spaces or tabs.
@@ -263,6 +268,16 @@ mod test {
             assert_eq!(Ok(("", "\n")), line_ending_or_empty("\n"));
         }
 
+        #[test]
+        fn should_work_with_a_carriage_return_ending_the_input() {
+            assert_eq!(Ok(("", "\r")), line_ending_or_empty("\r"));
+        }
+
+        #[test]
+        fn should_fail_with_a_carriage_return_within_the_input() {
+            assert!(line_ending_or_empty("\ra").is_err());
+        }
+
         #[test]
         fn should_work_with_lines() {
             let result = line_ending_or_empty(lines(""));
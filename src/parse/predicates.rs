@@ -14,15 +14,22 @@ pub fn is_blank_line(input: &str) -> bool {
     }
 }
 
-/// Returns whether the parentheses in the segment are balanced.
+/// Returns how many more closing parentheses than opening ones the segment has, which is negative
+/// when it has more opening ones.
 ///
 /// Escaped parentheses are ignored.
-pub fn parentheseses_balance(segment: &str) -> bool {
-    // Ignore escaped parentheseses by removing them.
-    let sanitized = segment.replace(r"\(", "").replace(r"\)", "");
-    // Ensure the count of opening and closing parentheseses is equal.
-    sanitized.chars().filter(|&c| c == '(').count()
-        == sanitized.chars().filter(|&c| c == ')').count()
+pub fn unmatched_closing_parentheses(segment: &str) -> isize {
+    let mut count = 0;
+    let mut escaped = false;
+    for c in segment.chars() {
+        match c {
+            '(' if !escaped => count -= 1,
+            ')' if !escaped => count += 1,
+            _ => {}
+        }
+        escaped = c == '\\' && !escaped;
+    }
+    count
 }
 
 #[cfg(test)]
@@ -94,47 +101,32 @@ mod test {
         }
     }
 
-    mod parentheseses_balance {
+    mod unmatched_closing_parentheses {
         use super::*;
 
         #[test]
-        fn should_reject_single_opening_parenthesis() {
-            assert!(!parentheseses_balance("("));
-        }
-
-        #[test]
-        fn should_reject_single_closing_parenthesis() {
-            assert!(!parentheseses_balance(")"));
-        }
-
-        #[test]
-        fn should_reject_unbalanced_parentheses() {
-            assert!(!parentheseses_balance("(foo(and(bar))"));
-        }
-
-        #[test]
-        fn should_accept_an_empty_string() {
-            assert!(parentheseses_balance(""));
+        fn should_return_zero_for_balanced_parentheses() {
+            assert_eq!(unmatched_closing_parentheses("(foo(bar))"), 0);
         }
 
         #[test]
-        fn should_accept_string_without_parentheses() {
-            assert!(parentheseses_balance("foo"));
+        fn should_count_the_extra_closing_parentheses() {
+            assert_eq!(unmatched_closing_parentheses("(foo))bar)"), 2);
         }
 
         #[test]
-        fn should_accept_unbalanced_escaped_parentheses() {
-            assert!(parentheseses_balance(r"\(\(foo\)and\(bar\)"));
+        fn should_be_negative_with_extra_opening_parentheses() {
+            assert_eq!(unmatched_closing_parentheses("((foo)"), -1);
         }
 
         #[test]
-        fn should_accept_balanced_parentheses() {
-            assert!(parentheseses_balance("(foo(and(bar)))"));
+        fn should_ignore_escaped_parentheses() {
+            assert_eq!(unmatched_closing_parentheses(r"foo\))"), 1);
         }
 
         #[test]
-        fn should_accept_balanced_parentheses_and_ignore_escaped_ones() {
-            assert!(parentheseses_balance(r"(foo\(blip(and(bar)))"));
+        fn should_count_parentheses_following_escaped_backslashes() {
+            assert_eq!(unmatched_closing_parentheses(r"foo\\))"), 2);
         }
     }
 }
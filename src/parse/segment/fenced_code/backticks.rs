@@ -1,7 +1,8 @@
-use crate::Segment;
 use crate::parse::parsers::{indented_by_less_than_4, line_ending_or_empty, space_or_tab};
 use crate::parse::traits::ParseLine;
+use crate::{Segment, owned};
 use parser::{Map, ParseResult, Parser, consumed};
+use std::borrow::Cow;
 
 pub fn backticks_fenced_code_opening_segment<'a>(
     input: &'a str,
@@ -20,23 +21,38 @@ pub fn backticks_fenced_code_opening_segment<'a>(
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BackticksFencedCodeOpeningSegment<'a> {
-    segment: &'a str,
+    segment: Cow<'a, str>,
     indent: usize,
     // The amount of backticks used, minimally 3.
     fence_length: usize,
-    info_string: &'a str,
+    info_string: Cow<'a, str>,
 }
 
 impl<'a> BackticksFencedCodeOpeningSegment<'a> {
     fn new(segment: &'a str, indent: usize, fence_length: usize, info_string: &'a str) -> Self {
         Self {
-            segment,
+            segment: Cow::Borrowed(segment),
             indent,
             fence_length,
-            info_string,
+            info_string: Cow::Borrowed(info_string),
         }
     }
+
+    pub fn into_owned(self) -> BackticksFencedCodeOpeningSegment<'static> {
+        BackticksFencedCodeOpeningSegment {
+            segment: owned(self.segment),
+            indent: self.indent,
+            fence_length: self.fence_length,
+            info_string: owned(self.info_string),
+        }
+    }
+
+    /// The info string, without its surrounding whitespace.
+    pub fn info_string(&self) -> &str {
+        &self.info_string
+    }
 }
 
 impl<'a> ParseLine<'a> for BackticksFencedCodeOpeningSegment<'a> {
@@ -45,9 +61,9 @@ impl<'a> ParseLine<'a> for BackticksFencedCodeOpeningSegment<'a> {
     }
 }
 
-impl<'a> Segment<'a> for BackticksFencedCodeOpeningSegment<'a> {
-    fn segment(&self) -> &'a str {
-        self.segment
+impl Segment for BackticksFencedCodeOpeningSegment<'_> {
+    fn segment(&self) -> &str {
+        &self.segment
     }
 }
 
@@ -70,8 +86,9 @@ pub fn backticks_fenced_code_closing_segment<'a>(
 
 // Closing segments don't have info strings.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BackticksFencedCodeClosingSegment<'a> {
-    segment: &'a str,
+    segment: Cow<'a, str>,
     indent: usize,
     fence_length: usize,
 }
@@ -86,11 +103,19 @@ impl<'a> BackticksFencedCodeClosingSegment<'a> {
 
     fn new(segment: &'a str, indent: usize, fence_length: usize) -> Self {
         Self {
-            segment,
+            segment: Cow::Borrowed(segment),
             indent,
             fence_length,
         }
     }
+
+    pub fn into_owned(self) -> BackticksFencedCodeClosingSegment<'static> {
+        BackticksFencedCodeClosingSegment {
+            segment: owned(self.segment),
+            indent: self.indent,
+            fence_length: self.fence_length,
+        }
+    }
 }
 
 impl<'a> ParseLine<'a> for BackticksFencedCodeClosingSegment<'a> {
@@ -99,9 +124,9 @@ impl<'a> ParseLine<'a> for BackticksFencedCodeClosingSegment<'a> {
     }
 }
 
-impl<'a> Segment<'a> for BackticksFencedCodeClosingSegment<'a> {
-    fn segment(&self) -> &'a str {
-        self.segment
+impl Segment for BackticksFencedCodeClosingSegment<'_> {
+    fn segment(&self) -> &str {
+        &self.segment
     }
 }
 
@@ -1,11 +1,12 @@
 use crate::{
-    Segment,
+    Segment, owned,
     parse::{
         parsers::{indented_by_less_than_4, line_ending_or_empty, space_or_tab},
         traits::ParseLine,
     },
 };
 use parser::{Map, ParseResult, Parser, consumed};
+use std::borrow::Cow;
 
 pub fn tildes_fenced_code_opening_segment<'a>(
     input: &'a str,
@@ -24,23 +25,38 @@ pub fn tildes_fenced_code_opening_segment<'a>(
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TildesFencedCodeOpeningSegment<'a> {
-    segment: &'a str,
+    segment: Cow<'a, str>,
     indent: usize,
     // The amount of tildes used, minimally 3.
     fence_length: usize,
-    info_string: &'a str,
+    info_string: Cow<'a, str>,
 }
 
 impl<'a> TildesFencedCodeOpeningSegment<'a> {
     fn new(segment: &'a str, indent: usize, fence_length: usize, info_string: &'a str) -> Self {
         Self {
-            segment,
+            segment: Cow::Borrowed(segment),
             indent,
             fence_length,
-            info_string,
+            info_string: Cow::Borrowed(info_string),
         }
     }
+
+    pub fn into_owned(self) -> TildesFencedCodeOpeningSegment<'static> {
+        TildesFencedCodeOpeningSegment {
+            segment: owned(self.segment),
+            indent: self.indent,
+            fence_length: self.fence_length,
+            info_string: owned(self.info_string),
+        }
+    }
+
+    /// The info string, without its surrounding whitespace.
+    pub fn info_string(&self) -> &str {
+        &self.info_string
+    }
 }
 
 impl<'a> ParseLine<'a> for TildesFencedCodeOpeningSegment<'a> {
@@ -49,9 +65,9 @@ impl<'a> ParseLine<'a> for TildesFencedCodeOpeningSegment<'a> {
     }
 }
 
-impl<'a> Segment<'a> for TildesFencedCodeOpeningSegment<'a> {
-    fn segment(&self) -> &'a str {
-        self.segment
+impl Segment for TildesFencedCodeOpeningSegment<'_> {
+    fn segment(&self) -> &str {
+        &self.segment
     }
 }
 
@@ -74,8 +90,9 @@ pub fn tildes_fenced_code_closing_segment<'a>(
 
 // Closing segments don't have info strings.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TildesFencedCodeClosingSegment<'a> {
-    segment: &'a str,
+    segment: Cow<'a, str>,
     indent: usize,
     fence_length: usize,
 }
@@ -90,11 +107,19 @@ impl<'a> TildesFencedCodeClosingSegment<'a> {
 
     fn new(segment: &'a str, indent: usize, fence_length: usize) -> Self {
         Self {
-            segment,
+            segment: Cow::Borrowed(segment),
             indent,
             fence_length,
         }
     }
+
+    pub fn into_owned(self) -> TildesFencedCodeClosingSegment<'static> {
+        TildesFencedCodeClosingSegment {
+            segment: owned(self.segment),
+            indent: self.indent,
+            fence_length: self.fence_length,
+        }
+    }
 }
 
 impl<'a> ParseLine<'a> for TildesFencedCodeClosingSegment<'a> {
@@ -103,9 +128,9 @@ impl<'a> ParseLine<'a> for TildesFencedCodeClosingSegment<'a> {
     }
 }
 
-impl<'a> Segment<'a> for TildesFencedCodeClosingSegment<'a> {
-    fn segment(&self) -> &'a str {
-        self.segment
+impl Segment for TildesFencedCodeClosingSegment<'_> {
+    fn segment(&self) -> &str {
+        &self.segment
     }
 }
 
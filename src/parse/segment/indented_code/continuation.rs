@@ -6,6 +6,7 @@ use crate::{
 use parser::{And, ParseResult, Parser, Repeated};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ContinuationSegments<'a> {
     pub segments: Vec<IndentedCodeOrBlankLineSegment<'a>>,
     pub closing_segment: IndentedCodeSegment<'a>,
@@ -21,6 +22,17 @@ impl<'a> ContinuationSegments<'a> {
             closing_segment,
         }
     }
+
+    pub fn into_owned(self) -> ContinuationSegments<'static> {
+        ContinuationSegments {
+            segments: self
+                .segments
+                .into_iter()
+                .map(IndentedCodeOrBlankLineSegment::into_owned)
+                .collect(),
+            closing_segment: self.closing_segment.into_owned(),
+        }
+    }
 }
 
 impl<'a> Parse<'a> for ContinuationSegments<'a> {
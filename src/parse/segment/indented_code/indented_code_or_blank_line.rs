@@ -9,13 +9,29 @@ use parser::{Map, ParseResult, Parser, one_of};
 ///
 /// # Note
 /// Only non trailing blank lines should be kept in the block.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(tag = "kind", content = "value", rename_all = "snake_case")
+)]
 pub enum IndentedCodeOrBlankLineSegment<'a> {
     IndentedCode(IndentedCodeSegment<'a>),
     BlankLine(BlankLine<'a>),
 }
 
 impl<'a> IndentedCodeOrBlankLineSegment<'a> {
+    pub fn into_owned(self) -> IndentedCodeOrBlankLineSegment<'static> {
+        match self {
+            Self::IndentedCode(segment) => {
+                IndentedCodeOrBlankLineSegment::IndentedCode(segment.into_owned())
+            }
+            Self::BlankLine(blank_line) => {
+                IndentedCodeOrBlankLineSegment::BlankLine(blank_line.into_owned())
+            }
+        }
+    }
+
     #[allow(dead_code)]
     pub fn is_blank_line(&self) -> bool {
         matches!(self, Self::BlankLine(_))
@@ -66,8 +82,8 @@ impl<'a> ParseLine<'a> for IndentedCodeOrBlankLineSegment<'a> {
     }
 }
 
-impl<'a> Segment<'a> for IndentedCodeOrBlankLineSegment<'a> {
-    fn segment(&self) -> &'a str {
+impl Segment for IndentedCodeOrBlankLineSegment<'_> {
+    fn segment(&self) -> &str {
         match self {
             Self::IndentedCode(segment) => segment.segment(),
             Self::BlankLine(blank_line) => blank_line.segment(),
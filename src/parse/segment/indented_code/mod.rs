@@ -5,10 +5,11 @@ pub use continuation::*;
 pub use indented_code_or_blank_line::*;
 
 use crate::{
-    Segment,
+    Segment, owned,
     parse::{parsers::indented_by_at_least_4, predicates::is_blank_line, traits::ParseLine},
 };
 use parser::{Map, ParseResult, Parser, recognize, rest};
+use std::borrow::Cow;
 
 pub fn indented_code<'a>(input: &'a str) -> ParseResult<&'a str, IndentedCodeSegment<'a>> {
     if is_blank_line(input) {
@@ -24,12 +25,17 @@ pub fn indented_code<'a>(input: &'a str) -> ParseResult<&'a str, IndentedCodeSeg
 ///
 /// An indented code segment is one that starts with 4 spaces or a tab and
 /// isn't a blank line segment.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct IndentedCodeSegment<'a>(&'a str);
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IndentedCodeSegment<'a>(Cow<'a, str>);
 
 impl<'a> IndentedCodeSegment<'a> {
     fn new(segment: &'a str) -> Self {
-        Self(segment)
+        Self(Cow::Borrowed(segment))
+    }
+
+    pub fn into_owned(self) -> IndentedCodeSegment<'static> {
+        IndentedCodeSegment(owned(self.0))
     }
 }
 
@@ -39,9 +45,9 @@ impl<'a> ParseLine<'a> for IndentedCodeSegment<'a> {
     }
 }
 
-impl<'a> Segment<'a> for IndentedCodeSegment<'a> {
-    fn segment(&self) -> &'a str {
-        self.0
+impl Segment for IndentedCodeSegment<'_> {
+    fn segment(&self) -> &str {
+        &self.0
     }
 }
 
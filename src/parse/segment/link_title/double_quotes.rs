@@ -1,5 +1,5 @@
 use crate::{
-    Segment, Segments,
+    Segment, Segments, owned,
     parse::{
         input::Input,
         parsers::line_ending,
@@ -8,14 +8,19 @@ use crate::{
     },
 };
 use parser::{Map, ParseResult, Parser, Repeated, recognize, tag, validate};
-use std::{iter::FusedIterator, slice};
+use std::{borrow::Cow, iter::FusedIterator, slice};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct DoubleQuotesLinkTitleSingleSegment<'a>(&'a str);
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DoubleQuotesLinkTitleSingleSegment<'a>(Cow<'a, str>);
 
 impl<'a> DoubleQuotesLinkTitleSingleSegment<'a> {
     fn new(segment: &'a str) -> Self {
-        Self(segment)
+        Self(Cow::Borrowed(segment))
+    }
+
+    pub fn into_owned(self) -> DoubleQuotesLinkTitleSingleSegment<'static> {
+        DoubleQuotesLinkTitleSingleSegment(owned(self.0))
     }
 }
 
@@ -27,13 +32,14 @@ impl<'a> ParseLine<'a> for DoubleQuotesLinkTitleSingleSegment<'a> {
     }
 }
 
-impl<'a> Segment<'a> for DoubleQuotesLinkTitleSingleSegment<'a> {
-    fn segment(&self) -> &'a str {
-        self.0
+impl Segment for DoubleQuotesLinkTitleSingleSegment<'_> {
+    fn segment(&self) -> &str {
+        &self.0
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DoubleQuotesLinkTitleMultiSegments<'a> {
     opening: DoubleQuotesLinkTitleOpeningSegment<'a>,
     continuations: Vec<DoubleQuotesLinkTitleContinuationSegment<'a>>,
@@ -52,6 +58,18 @@ impl<'a> DoubleQuotesLinkTitleMultiSegments<'a> {
             closing,
         }
     }
+
+    pub fn into_owned(self) -> DoubleQuotesLinkTitleMultiSegments<'static> {
+        DoubleQuotesLinkTitleMultiSegments {
+            opening: self.opening.into_owned(),
+            continuations: Iterator::map(
+                self.continuations.into_iter(),
+                DoubleQuotesLinkTitleContinuationSegment::into_owned,
+            )
+            .collect(),
+            closing: self.closing.into_owned(),
+        }
+    }
 }
 
 impl<'a> Parse<'a> for DoubleQuotesLinkTitleMultiSegments<'a> {
@@ -84,9 +102,9 @@ impl<'a> From<&'a DoubleQuotesLinkTitleMultiSegments<'a>>
 {
     fn from(title: &'a DoubleQuotesLinkTitleMultiSegments<'a>) -> Self {
         Self {
-            opening: Some(title.opening.0),
+            opening: Some(title.opening.0.as_ref()),
             continuations: title.continuations.iter(),
-            closing: Some(title.closing.0),
+            closing: Some(title.closing.0.as_ref()),
         }
     }
 }
@@ -102,19 +120,24 @@ impl<'a> Iterator for DoubleQuotesLinkTitleMultiSegmentsIter<'a> {
         }
 
         if let Some(continuation) = self.continuations.next() {
-            return Some(continuation.0);
+            return Some(continuation.0.as_ref());
         }
 
         self.closing.take()
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct DoubleQuotesLinkTitleOpeningSegment<'a>(&'a str);
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DoubleQuotesLinkTitleOpeningSegment<'a>(Cow<'a, str>);
 
 impl<'a> DoubleQuotesLinkTitleOpeningSegment<'a> {
     fn new(segment: &'a str) -> Self {
-        Self(segment)
+        Self(Cow::Borrowed(segment))
+    }
+
+    pub fn into_owned(self) -> DoubleQuotesLinkTitleOpeningSegment<'static> {
+        DoubleQuotesLinkTitleOpeningSegment(owned(self.0))
     }
 }
 
@@ -126,12 +149,17 @@ impl<'a> ParseLine<'a> for DoubleQuotesLinkTitleOpeningSegment<'a> {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct DoubleQuotesLinkTitleContinuationSegment<'a>(&'a str);
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DoubleQuotesLinkTitleContinuationSegment<'a>(Cow<'a, str>);
 
 impl<'a> DoubleQuotesLinkTitleContinuationSegment<'a> {
     fn new(segment: &'a str) -> Self {
-        Self(segment)
+        Self(Cow::Borrowed(segment))
+    }
+
+    pub fn into_owned(self) -> DoubleQuotesLinkTitleContinuationSegment<'static> {
+        DoubleQuotesLinkTitleContinuationSegment(owned(self.0))
     }
 }
 
@@ -146,12 +174,17 @@ impl<'a> ParseLine<'a> for DoubleQuotesLinkTitleContinuationSegment<'a> {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct DoubleQuotesLinkTitleClosingSegment<'a>(&'a str);
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DoubleQuotesLinkTitleClosingSegment<'a>(Cow<'a, str>);
 
 impl<'a> DoubleQuotesLinkTitleClosingSegment<'a> {
     fn new(segment: &'a str) -> Self {
-        Self(segment)
+        Self(Cow::Borrowed(segment))
+    }
+
+    pub fn into_owned(self) -> DoubleQuotesLinkTitleClosingSegment<'static> {
+        DoubleQuotesLinkTitleClosingSegment(owned(self.0))
     }
 }
 
@@ -1,5 +1,5 @@
 use crate::{
-    Segment, Segments,
+    Segment, Segments, owned,
     parse::{
         input::Input,
         parsers::line_ending,
@@ -8,14 +8,19 @@ use crate::{
     },
 };
 use parser::{Map, ParseResult, Parser, Repeated, recognize, tag, validate};
-use std::{iter::FusedIterator, slice};
+use std::{borrow::Cow, iter::FusedIterator, slice};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct ParenthesesLinkTitleSingleSegment<'a>(&'a str);
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ParenthesesLinkTitleSingleSegment<'a>(Cow<'a, str>);
 
 impl<'a> ParenthesesLinkTitleSingleSegment<'a> {
     fn new(segment: &'a str) -> Self {
-        Self(segment)
+        Self(Cow::Borrowed(segment))
+    }
+
+    pub fn into_owned(self) -> ParenthesesLinkTitleSingleSegment<'static> {
+        ParenthesesLinkTitleSingleSegment(owned(self.0))
     }
 }
 
@@ -27,13 +32,14 @@ impl<'a> ParseLine<'a> for ParenthesesLinkTitleSingleSegment<'a> {
     }
 }
 
-impl<'a> Segment<'a> for ParenthesesLinkTitleSingleSegment<'a> {
-    fn segment(&self) -> &'a str {
-        self.0
+impl Segment for ParenthesesLinkTitleSingleSegment<'_> {
+    fn segment(&self) -> &str {
+        &self.0
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ParenthesesLinkTitleMultiSegments<'a> {
     opening: ParenthesesLinkTitleOpeningSegment<'a>,
     continuations: Vec<ParenthesesLinkTitleContinuationSegment<'a>>,
@@ -52,6 +58,18 @@ impl<'a> ParenthesesLinkTitleMultiSegments<'a> {
             closing,
         }
     }
+
+    pub fn into_owned(self) -> ParenthesesLinkTitleMultiSegments<'static> {
+        ParenthesesLinkTitleMultiSegments {
+            opening: self.opening.into_owned(),
+            continuations: Iterator::map(
+                self.continuations.into_iter(),
+                ParenthesesLinkTitleContinuationSegment::into_owned,
+            )
+            .collect(),
+            closing: self.closing.into_owned(),
+        }
+    }
 }
 
 impl<'a> Parse<'a> for ParenthesesLinkTitleMultiSegments<'a> {
@@ -84,9 +102,9 @@ impl<'a> From<&'a ParenthesesLinkTitleMultiSegments<'a>>
 {
     fn from(title: &'a ParenthesesLinkTitleMultiSegments<'a>) -> Self {
         Self {
-            opening: Some(title.opening.0),
+            opening: Some(title.opening.0.as_ref()),
             continuations: title.continuations.iter(),
-            closing: Some(title.closing.0),
+            closing: Some(title.closing.0.as_ref()),
         }
     }
 }
@@ -102,19 +120,24 @@ impl<'a> Iterator for ParenthesesLinkTitleMultiSegmentsIter<'a> {
         }
 
         if let Some(continuation) = self.continuations.next() {
-            return Some(continuation.0);
+            return Some(continuation.0.as_ref());
         }
 
         self.closing.take()
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct ParenthesesLinkTitleOpeningSegment<'a>(&'a str);
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ParenthesesLinkTitleOpeningSegment<'a>(Cow<'a, str>);
 
 impl<'a> ParenthesesLinkTitleOpeningSegment<'a> {
     fn new(segment: &'a str) -> Self {
-        Self(segment)
+        Self(Cow::Borrowed(segment))
+    }
+
+    pub fn into_owned(self) -> ParenthesesLinkTitleOpeningSegment<'static> {
+        ParenthesesLinkTitleOpeningSegment(owned(self.0))
     }
 }
 
@@ -126,12 +149,17 @@ impl<'a> ParseLine<'a> for ParenthesesLinkTitleOpeningSegment<'a> {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct ParenthesesLinkTitleContinuationSegment<'a>(&'a str);
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ParenthesesLinkTitleContinuationSegment<'a>(Cow<'a, str>);
 
 impl<'a> ParenthesesLinkTitleContinuationSegment<'a> {
     fn new(segment: &'a str) -> Self {
-        Self(segment)
+        Self(Cow::Borrowed(segment))
+    }
+
+    pub fn into_owned(self) -> ParenthesesLinkTitleContinuationSegment<'static> {
+        ParenthesesLinkTitleContinuationSegment(owned(self.0))
     }
 }
 
@@ -146,12 +174,17 @@ impl<'a> ParseLine<'a> for ParenthesesLinkTitleContinuationSegment<'a> {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct ParenthesesLinkTitleClosingSegment<'a>(&'a str);
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ParenthesesLinkTitleClosingSegment<'a>(Cow<'a, str>);
 
 impl<'a> ParenthesesLinkTitleClosingSegment<'a> {
     fn new(segment: &'a str) -> Self {
-        Self(segment)
+        Self(Cow::Borrowed(segment))
+    }
+
+    pub fn into_owned(self) -> ParenthesesLinkTitleClosingSegment<'static> {
+        ParenthesesLinkTitleClosingSegment(owned(self.0))
     }
 }
 
@@ -1,5 +1,5 @@
 use crate::{
-    Segment, Segments,
+    Segment, Segments, owned,
     parse::{
         input::Input,
         parsers::line_ending,
@@ -8,14 +8,19 @@ use crate::{
     },
 };
 use parser::{Map, ParseResult, Parser, Repeated, recognize, tag, validate};
-use std::{iter::FusedIterator, slice};
+use std::{borrow::Cow, iter::FusedIterator, slice};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct SingleQuotesLinkTitleSingleSegment<'a>(&'a str);
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SingleQuotesLinkTitleSingleSegment<'a>(Cow<'a, str>);
 
 impl<'a> SingleQuotesLinkTitleSingleSegment<'a> {
     fn new(segment: &'a str) -> Self {
-        Self(segment)
+        Self(Cow::Borrowed(segment))
+    }
+
+    pub fn into_owned(self) -> SingleQuotesLinkTitleSingleSegment<'static> {
+        SingleQuotesLinkTitleSingleSegment(owned(self.0))
     }
 }
 
@@ -27,13 +32,14 @@ impl<'a> ParseLine<'a> for SingleQuotesLinkTitleSingleSegment<'a> {
     }
 }
 
-impl<'a> Segment<'a> for SingleQuotesLinkTitleSingleSegment<'a> {
-    fn segment(&self) -> &'a str {
-        self.0
+impl Segment for SingleQuotesLinkTitleSingleSegment<'_> {
+    fn segment(&self) -> &str {
+        &self.0
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SingleQuotesLinkTitleMultiSegments<'a> {
     opening: SingleQuotesLinkTitleOpeningSegment<'a>,
     continuations: Vec<SingleQuotesLinkTitleContinuationSegment<'a>>,
@@ -52,6 +58,18 @@ impl<'a> SingleQuotesLinkTitleMultiSegments<'a> {
             closing,
         }
     }
+
+    pub fn into_owned(self) -> SingleQuotesLinkTitleMultiSegments<'static> {
+        SingleQuotesLinkTitleMultiSegments {
+            opening: self.opening.into_owned(),
+            continuations: Iterator::map(
+                self.continuations.into_iter(),
+                SingleQuotesLinkTitleContinuationSegment::into_owned,
+            )
+            .collect(),
+            closing: self.closing.into_owned(),
+        }
+    }
 }
 
 impl<'a> Parse<'a> for SingleQuotesLinkTitleMultiSegments<'a> {
@@ -84,9 +102,9 @@ impl<'a> From<&'a SingleQuotesLinkTitleMultiSegments<'a>>
 {
     fn from(title: &'a SingleQuotesLinkTitleMultiSegments<'a>) -> Self {
         Self {
-            opening: Some(title.opening.0),
+            opening: Some(title.opening.0.as_ref()),
             continuations: title.continuations.iter(),
-            closing: Some(title.closing.0),
+            closing: Some(title.closing.0.as_ref()),
         }
     }
 }
@@ -102,19 +120,24 @@ impl<'a> Iterator for SingleQuotesLinkTitleMultiSegmentsIter<'a> {
         }
 
         if let Some(continuation) = self.continuations.next() {
-            return Some(continuation.0);
+            return Some(continuation.0.as_ref());
         }
 
         self.closing.take()
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct SingleQuotesLinkTitleOpeningSegment<'a>(&'a str);
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SingleQuotesLinkTitleOpeningSegment<'a>(Cow<'a, str>);
 
 impl<'a> SingleQuotesLinkTitleOpeningSegment<'a> {
     fn new(segment: &'a str) -> Self {
-        Self(segment)
+        Self(Cow::Borrowed(segment))
+    }
+
+    pub fn into_owned(self) -> SingleQuotesLinkTitleOpeningSegment<'static> {
+        SingleQuotesLinkTitleOpeningSegment(owned(self.0))
     }
 }
 
@@ -126,12 +149,17 @@ impl<'a> ParseLine<'a> for SingleQuotesLinkTitleOpeningSegment<'a> {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct SingleQuotesLinkTitleContinuationSegment<'a>(&'a str);
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SingleQuotesLinkTitleContinuationSegment<'a>(Cow<'a, str>);
 
 impl<'a> SingleQuotesLinkTitleContinuationSegment<'a> {
     fn new(segment: &'a str) -> Self {
-        Self(segment)
+        Self(Cow::Borrowed(segment))
+    }
+
+    pub fn into_owned(self) -> SingleQuotesLinkTitleContinuationSegment<'static> {
+        SingleQuotesLinkTitleContinuationSegment(owned(self.0))
     }
 }
 
@@ -146,12 +174,17 @@ impl<'a> ParseLine<'a> for SingleQuotesLinkTitleContinuationSegment<'a> {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct SingleQuotesLinkTitleClosingSegment<'a>(&'a str);
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SingleQuotesLinkTitleClosingSegment<'a>(Cow<'a, str>);
 
 impl<'a> SingleQuotesLinkTitleClosingSegment<'a> {
     fn new(segment: &'a str) -> Self {
-        Self(segment)
+        Self(Cow::Borrowed(segment))
+    }
+
+    pub fn into_owned(self) -> SingleQuotesLinkTitleClosingSegment<'static> {
+        SingleQuotesLinkTitleClosingSegment(owned(self.0))
     }
 }
 
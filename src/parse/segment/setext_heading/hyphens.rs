@@ -1,18 +1,24 @@
 use crate::{
-    Segment,
+    Segment, owned,
     parse::{
         parsers::{indented_by_less_than_4, line_ending_or_empty, space_or_tab},
         traits::ParseLine,
     },
 };
 use parser::{Map, Parser, equals, recognize, take_while};
+use std::borrow::Cow;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct SetextHeadingHyphensUnderlineSegment<'a>(&'a str);
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetextHeadingHyphensUnderlineSegment<'a>(Cow<'a, str>);
 
 impl<'a> SetextHeadingHyphensUnderlineSegment<'a> {
     fn new(segment: &'a str) -> Self {
-        Self(segment)
+        Self(Cow::Borrowed(segment))
+    }
+
+    pub fn into_owned(self) -> SetextHeadingHyphensUnderlineSegment<'static> {
+        SetextHeadingHyphensUnderlineSegment(owned(self.0))
     }
 
     pub fn level(&self) -> u8 {
@@ -33,9 +39,9 @@ impl<'a> ParseLine<'a> for SetextHeadingHyphensUnderlineSegment<'a> {
     }
 }
 
-impl<'a> Segment<'a> for SetextHeadingHyphensUnderlineSegment<'a> {
-    fn segment(&self) -> &'a str {
-        self.0
+impl Segment for SetextHeadingHyphensUnderlineSegment<'_> {
+    fn segment(&self) -> &str {
+        &self.0
     }
 }
 
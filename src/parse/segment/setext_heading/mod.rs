@@ -7,12 +7,24 @@ pub use hyphens::*;
 use parser::{Map, Parser, one_of};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(tag = "kind", content = "value", rename_all = "snake_case")
+)]
 pub enum SetextHeadingUnderlineSegment<'a> {
     Equals(SetextHeadingEqualsUnderlineSegment<'a>),
     Hyphens(SetextHeadingHyphensUnderlineSegment<'a>),
 }
 
 impl SetextHeadingUnderlineSegment<'_> {
+    pub fn into_owned(self) -> SetextHeadingUnderlineSegment<'static> {
+        match self {
+            Self::Equals(segment) => SetextHeadingUnderlineSegment::Equals(segment.into_owned()),
+            Self::Hyphens(segment) => SetextHeadingUnderlineSegment::Hyphens(segment.into_owned()),
+        }
+    }
+
     pub fn level(&self) -> u8 {
         match self {
             Self::Equals(segment) => segment.level(),
@@ -43,8 +55,8 @@ impl<'a> ParseLine<'a> for SetextHeadingUnderlineSegment<'a> {
     }
 }
 
-impl<'a> Segment<'a> for SetextHeadingUnderlineSegment<'a> {
-    fn segment(&self) -> &'a str {
+impl Segment for SetextHeadingUnderlineSegment<'_> {
+    fn segment(&self) -> &str {
         match self {
             Self::Equals(segment) => segment.segment(),
             Self::Hyphens(segment) => segment.segment(),
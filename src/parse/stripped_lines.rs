@@ -0,0 +1,311 @@
+use super::input::Input;
+use crate::ParseOptions;
+use parser::{Indexable, IsEmpty, ItemsIndices, SplitAt, SubsetRange};
+use std::{iter::FusedIterator, slice, str::CharIndices};
+
+/// A line of a container's content, stripped of the container markers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StrippedLine<'a> {
+    segment: &'a str,
+    /// Whether the line is a [lazy continuation line](https://spec.commonmark.org/0.31.2/#lazy-continuation-line).
+    lazy: bool,
+}
+
+impl<'a> StrippedLine<'a> {
+    /// A line that was explicitly marked as part of the container.
+    pub fn explicit(segment: &'a str) -> Self {
+        Self {
+            segment,
+            lazy: false,
+        }
+    }
+
+    /// A paragraph continuation line that is missing the container markers.
+    pub fn lazy(segment: &'a str) -> Self {
+        Self {
+            segment,
+            lazy: true,
+        }
+    }
+}
+
+/// An input made of lines that are not contiguous in the source.
+///
+/// This is the input used to parse the content of containers, where the container markers have been
+/// stripped from the lines. Because every line is still a slice of the same source, and they come in order,
+/// the indices are the byte offsets from the start of the first line. Note that the characters between the
+/// lines, the container markers, are never part of the input, even though they are covered by the
+/// indices.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StrippedLines<'s, 'a> {
+    lines: &'s [StrippedLine<'a>],
+    start: usize,
+    end: usize,
+    options: ParseOptions,
+    depth: usize,
+}
+
+impl<'s, 'a> StrippedLines<'s, 'a> {
+    /// Empty lines are not allowed.
+    pub fn new(lines: &'s [StrippedLine<'a>]) -> Self {
+        Self::with_options(lines, ParseOptions::default())
+    }
+
+    pub fn with_options(lines: &'s [StrippedLine<'a>], options: ParseOptions) -> Self {
+        debug_assert!(lines.iter().all(|line| !line.segment.is_empty()));
+        let mut stripped_lines = Self {
+            lines,
+            start: 0,
+            end: 0,
+            options,
+            depth: 0,
+        };
+        stripped_lines.end = match lines.last() {
+            Some(line) => stripped_lines.offset_of(line.segment) + line.segment.len(),
+            None => 0,
+        };
+        stripped_lines
+    }
+
+    /// Sets how many containers the lines are nested in.
+    pub fn with_depth(self, depth: usize) -> Self {
+        Self { depth, ..self }
+    }
+
+    /// Returns the offset of the given slice, which must be part of one of the lines.
+    fn offset_of(&self, segment: &str) -> usize {
+        let base = self.lines[0].segment.as_ptr() as usize;
+        segment.as_ptr() as usize - base
+    }
+
+    /// Returns the index of the first line that ends after the offset.
+    fn line_at(&self, offset: usize) -> usize {
+        self.lines
+            .partition_point(|line| self.offset_of(line.segment) + line.segment.len() <= offset)
+    }
+}
+
+impl<'s, 'a> From<&'s [StrippedLine<'a>]> for StrippedLines<'s, 'a> {
+    fn from(lines: &'s [StrippedLine<'a>]) -> Self {
+        Self::new(lines)
+    }
+}
+
+impl Indexable for StrippedLines<'_, '_> {
+    type Index = usize;
+
+    fn last_index(&self) -> Self::Index {
+        self.end
+    }
+}
+
+impl<'s, 'a> ItemsIndices<&'a str> for StrippedLines<'s, 'a> {
+    type ItemsIndices = StrippedLinesIndices<'s, 'a>;
+
+    fn items_indices(&self) -> Self::ItemsIndices {
+        StrippedLinesIndices::from(*self)
+    }
+}
+
+impl<'s, 'a> ItemsIndices<char> for StrippedLines<'s, 'a> {
+    type ItemsIndices = StrippedLinesCharIndices<'s, 'a>;
+
+    fn items_indices(&self) -> Self::ItemsIndices {
+        StrippedLinesCharIndices::from(*self)
+    }
+}
+
+impl SubsetRange<Self> for StrippedLines<'_, '_> {
+    fn subset_range(&self, item: Self) -> (Self::Index, Self::Index) {
+        (item.start, item.end)
+    }
+}
+
+impl<'a> SubsetRange<&'a str> for StrippedLines<'_, 'a> {
+    fn subset_range(&self, item: &'a str) -> (Self::Index, Self::Index) {
+        let start = self.offset_of(item);
+        (start, start + item.len())
+    }
+}
+
+impl SplitAt for StrippedLines<'_, '_> {
+    fn split_at(&self, index: Self::Index) -> (Self, Self) {
+        let left = Self {
+            end: index,
+            ..*self
+        };
+        let right = Self {
+            start: index,
+            ..*self
+        };
+        (left, right)
+    }
+}
+
+impl IsEmpty for StrippedLines<'_, '_> {
+    fn is_empty(&self) -> bool {
+        // The range could cover only what is between two lines.
+        <Self as ItemsIndices<&str>>::items_indices(self)
+            .next()
+            .is_none()
+    }
+}
+
+impl<'a> Input<'a> for StrippedLines<'_, 'a> {
+    fn options(&self) -> &ParseOptions {
+        &self.options
+    }
+
+    fn is_lazy_continuation(&self, index: Self::Index) -> bool {
+        match self.lines.get(self.line_at(index)) {
+            Some(line) => line.lazy && self.offset_of(line.segment) == index,
+            None => false,
+        }
+    }
+
+    fn depth(&self) -> usize {
+        self.depth
+    }
+}
+
+/// Iterates over the parts of the lines that fall within the range of the input.
+#[derive(Debug, Clone)]
+pub struct StrippedLinesIndices<'s, 'a> {
+    input: StrippedLines<'s, 'a>,
+    lines: slice::Iter<'s, StrippedLine<'a>>,
+}
+
+impl<'s, 'a> From<StrippedLines<'s, 'a>> for StrippedLinesIndices<'s, 'a> {
+    fn from(input: StrippedLines<'s, 'a>) -> Self {
+        let first_line = input.line_at(input.start);
+        let lines = input.lines[first_line..].iter();
+        Self { input, lines }
+    }
+}
+
+impl<'a> Iterator for StrippedLinesIndices<'_, 'a> {
+    type Item = (usize, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let line = self.lines.next()?;
+        let line_start = self.input.offset_of(line.segment);
+        let start = self.input.start.max(line_start);
+        let end = self.input.end.min(line_start + line.segment.len());
+        // An empty range within a line has no items.
+        if start >= end {
+            return None;
+        }
+        Some((start, &line.segment[start - line_start..end - line_start]))
+    }
+}
+
+impl FusedIterator for StrippedLinesIndices<'_, '_> {}
+
+/// Iterates over the characters of the lines that fall within the range of the input.
+#[derive(Debug, Clone)]
+pub struct StrippedLinesCharIndices<'s, 'a> {
+    lines: StrippedLinesIndices<'s, 'a>,
+    current: Option<(usize, CharIndices<'a>)>,
+}
+
+impl<'s, 'a> From<StrippedLines<'s, 'a>> for StrippedLinesCharIndices<'s, 'a> {
+    fn from(input: StrippedLines<'s, 'a>) -> Self {
+        Self {
+            lines: StrippedLinesIndices::from(input),
+            current: None,
+        }
+    }
+}
+
+impl Iterator for StrippedLinesCharIndices<'_, '_> {
+    type Item = (usize, char);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some((offset, chars)) = &mut self.current {
+                if let Some((index, char)) = chars.next() {
+                    return Some((*offset + index, char));
+                }
+            }
+            let (offset, line) = self.lines.next()?;
+            self.current = Some((offset, line.char_indices()));
+        }
+    }
+}
+
+impl FusedIterator for StrippedLinesCharIndices<'_, '_> {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn lines(source: &str) -> Vec<StrippedLine> {
+        // Strips the first 2 characters of every line, as if they were "> " markers.
+        source
+            .split_inclusive('\n')
+            .map(|line| StrippedLine::explicit(&line[2..]))
+            .collect()
+    }
+
+    #[test]
+    fn should_iterate_over_stripped_lines() {
+        let lines = lines("> a\n> bc\n");
+        let input = StrippedLines::new(&lines);
+        let items: Vec<_> = <StrippedLines as ItemsIndices<&str>>::items_indices(&input).collect();
+        assert_eq!(items, vec![(0, "a\n"), (4, "bc\n")]);
+    }
+
+    #[test]
+    fn should_iterate_over_stripped_characters() {
+        let lines = lines("> a\n> b\n");
+        let input = StrippedLines::new(&lines);
+        let items: Vec<_> = <StrippedLines as ItemsIndices<char>>::items_indices(&input).collect();
+        assert_eq!(items, vec![(0, 'a'), (1, '\n'), (4, 'b'), (5, '\n')]);
+    }
+
+    #[test]
+    fn should_split_within_a_line() {
+        let lines = lines("> abc\n> d\n");
+        let input = StrippedLines::new(&lines);
+        let (left, right) = input.split_at(1);
+        assert_eq!(left.lines().collect::<Vec<_>>(), vec!["a"]);
+        assert_eq!(right.lines().collect::<Vec<_>>(), vec!["bc\n", "d\n"]);
+    }
+
+    #[test]
+    fn should_split_between_lines() {
+        let lines = lines("> a\n> b\n");
+        let input = StrippedLines::new(&lines);
+        let (left, right) = input.split_at(4);
+        assert_eq!(left.lines().collect::<Vec<_>>(), vec!["a\n"]);
+        assert_eq!(right.lines().collect::<Vec<_>>(), vec!["b\n"]);
+        assert!(input.split_at(input.last_index()).1.is_empty());
+    }
+
+    #[test]
+    fn should_be_empty_when_split_at_the_same_index() {
+        let lines = lines("> abc\n");
+        let input = StrippedLines::new(&lines);
+        let (_, right) = input.split_at(1);
+        assert!(right.split_at(1).0.is_empty());
+    }
+
+    #[test]
+    fn should_find_subset_range_of_a_segment() {
+        let lines = lines("> a\n> bc\n");
+        let input = StrippedLines::new(&lines);
+        assert_eq!(input.subset_range(&lines[1].segment[1..]), (5, 7));
+    }
+
+    #[test]
+    fn should_report_lazy_continuation_lines() {
+        let source = "> a\nb\n";
+        let lines = vec![
+            StrippedLine::explicit(&source[2..4]),
+            StrippedLine::lazy(&source[4..]),
+        ];
+        let input = StrippedLines::new(&lines);
+        assert!(!input.is_lazy_continuation(0));
+        assert!(input.is_lazy_continuation(2));
+    }
+}
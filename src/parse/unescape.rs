@@ -0,0 +1,139 @@
+use super::entities::entity;
+use std::borrow::Cow;
+
+/// Replaces the [backslash escapes](https://spec.commonmark.org/0.31.2/#backslash-escapes) and the
+/// [character references](https://spec.commonmark.org/0.31.2/#entity-and-numeric-character-references)
+/// of the text with the characters they stand for.
+///
+/// Only ASCII punctuation characters can be escaped, any other backslash is kept as is. So are the
+/// references that are not valid.
+pub fn unescape(text: &str) -> Cow<'_, str> {
+    if !text.contains(['\\', '&']) {
+        return Cow::Borrowed(text);
+    }
+    let mut unescaped = String::with_capacity(text.len());
+    let mut remaining = text;
+    while let Some(index) = remaining.find(['\\', '&']) {
+        unescaped.push_str(&remaining[..index]);
+        remaining = &remaining[index..];
+        let length = match remaining[1..].chars().next() {
+            Some(character) if remaining.starts_with('\\') && character.is_ascii_punctuation() => {
+                unescaped.push(character);
+                2
+            }
+            _ if remaining.starts_with('&') => push_character_reference(&mut unescaped, remaining)
+                .unwrap_or_else(|| {
+                    unescaped.push('&');
+                    1
+                }),
+            _ => {
+                unescaped.push('\\');
+                1
+            }
+        };
+        remaining = &remaining[length..];
+    }
+    unescaped.push_str(remaining);
+    Cow::Owned(unescaped)
+}
+
+/// How long a character reference can be, as the longest one is `&CounterClockwiseContourIntegral;`.
+///
+/// There is no need to look for the end of a reference any further.
+const MAX_CHARACTER_REFERENCE_LENGTH: usize = 33;
+
+/// Pushes the characters referenced at the start of the text, which starts with a `&`.
+///
+/// Returns the length of the reference, if it is a valid one. Numeric references to invalid code
+/// points are replaced with U+FFFD.
+fn push_character_reference(buffer: &mut String, text: &str) -> Option<usize> {
+    let end = text
+        .bytes()
+        .take(MAX_CHARACTER_REFERENCE_LENGTH)
+        .position(|byte| byte == b';')?;
+    let reference = &text[1..end];
+    match reference.strip_prefix('#') {
+        Some(number) => {
+            let (digits, radix, max_length) = match number.strip_prefix(['x', 'X']) {
+                Some(digits) => (digits, 16, 6),
+                None => (number, 10, 7),
+            };
+            if digits.is_empty()
+                || digits.len() > max_length
+                || !digits.chars().all(|c| c.is_digit(radix))
+            {
+                return None;
+            }
+            let code_point = u32::from_str_radix(digits, radix).ok()?;
+            buffer.push(
+                char::from_u32(code_point)
+                    .filter(|&character| character != '\0')
+                    .unwrap_or(char::REPLACEMENT_CHARACTER),
+            );
+        }
+        None => buffer.push_str(entity(reference)?),
+    }
+    Some(end + 1)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn should_borrow_text_without_escapes() {
+        assert!(matches!(unescape("foo"), Cow::Borrowed("foo")));
+    }
+
+    #[test]
+    fn should_unescape_ascii_punctuation() {
+        assert_eq!(unescape(r"\*foo\* \[bar\]"), "*foo* [bar]");
+    }
+
+    #[test]
+    fn should_keep_other_backslashes() {
+        assert_eq!(unescape(r"\a\ \"), r"\a\ \");
+    }
+
+    #[test]
+    fn should_decode_entity_references() {
+        assert_eq!(unescape("&amp; &copy; &ngE;"), "& © \u{2267}\u{0338}");
+    }
+
+    #[test]
+    fn should_decode_the_longest_entity_reference() {
+        assert_eq!(unescape("&CounterClockwiseContourIntegral;"), "\u{2233}");
+    }
+
+    #[test]
+    fn should_decode_numeric_references() {
+        assert_eq!(unescape("&#35; &#1234; &#X22; &#xD06;"), "# Ӓ \" ആ");
+    }
+
+    #[test]
+    fn should_replace_invalid_code_points() {
+        assert_eq!(
+            unescape("&#0; &#xD800; &#x110000;"),
+            "\u{FFFD} \u{FFFD} \u{FFFD}"
+        );
+    }
+
+    #[test]
+    fn should_keep_invalid_references() {
+        assert_eq!(
+            unescape("&nbsp &x; &#; &#x; &#87654321; &#abcdef0; &ThisIsNotDefined;"),
+            "&nbsp &x; &#; &#x; &#87654321; &#abcdef0; &ThisIsNotDefined;"
+        );
+    }
+
+    #[test]
+    fn should_keep_references_too_long_to_be_valid() {
+        let reference = format!("&{};", "a".repeat(MAX_CHARACTER_REFERENCE_LENGTH));
+        assert_eq!(unescape(&reference), reference);
+    }
+
+    #[test]
+    fn should_not_unescape_references_twice() {
+        assert_eq!(unescape(r"\&amp; &amp;copy;"), "&amp; &copy;");
+    }
+}
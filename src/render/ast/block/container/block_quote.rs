@@ -0,0 +1,14 @@
+use crate::{
+    ast::block::BlockQuote,
+    render::{DisplayHtml, HtmlOptions},
+};
+
+impl DisplayHtml for BlockQuote<'_> {
+    fn display_html(&self, buffer: &mut String, options: &HtmlOptions) {
+        buffer.push_str("<blockquote>\n");
+        for block in self.blocks() {
+            block.display_html(buffer, options);
+        }
+        buffer.push_str("</blockquote>\n");
+    }
+}
@@ -0,0 +1,26 @@
+use crate::{
+    ast::block::List,
+    render::{DisplayHtml, HtmlOptions},
+};
+
+impl DisplayHtml for List<'_> {
+    fn display_html(&self, buffer: &mut String, options: &HtmlOptions) {
+        match self.start() {
+            None => buffer.push_str("<ul>\n"),
+            Some(1) => buffer.push_str("<ol>\n"),
+            Some(start) => {
+                buffer.push_str("<ol start=\"");
+                buffer.push_str(&start.to_string());
+                buffer.push_str("\">\n");
+            }
+        }
+        for item in self.items() {
+            item.display_list_item_html(buffer, self.is_tight(), options);
+        }
+        if self.is_ordered() {
+            buffer.push_str("</ol>\n");
+        } else {
+            buffer.push_str("</ul>\n");
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use crate::{
+    ast::block::{Block, Leaf, ListItem},
+    render::{DisplayHtml, HtmlOptions, push_checkbox},
+};
+
+impl ListItem<'_> {
+    /// Renders the list item, whose paragraphs are rendered without `<p>` tags when the list is tight.
+    pub(super) fn display_list_item_html(
+        &self,
+        buffer: &mut String,
+        tight: bool,
+        options: &HtmlOptions,
+    ) {
+        buffer.push_str("<li>");
+        if let Some(checked) = self.checked() {
+            push_checkbox(buffer, checked);
+        }
+        for block in self.blocks() {
+            match block {
+                Block::Leaf(Leaf::Paragraph(paragraph)) if tight => {
+                    paragraph.display_content_html(buffer, options);
+                }
+                // Blocks that don't render anything don't need a line break.
+                Block::Leaf(Leaf::BlankLine(_) | Leaf::LinkReferenceDefinition(_)) => {}
+                _ => {
+                    if !buffer.ends_with('\n') {
+                        buffer.push('\n');
+                    }
+                    block.display_html(buffer, options);
+                }
+            }
+        }
+        buffer.push_str("</li>\n");
+    }
+}
@@ -1 +1,17 @@
+mod block_quote;
+mod list;
+mod list_item;
 
+use crate::{
+    ast::block::Container,
+    render::{DisplayHtml, HtmlOptions},
+};
+
+impl DisplayHtml for Container<'_> {
+    fn display_html(&self, buffer: &mut String, options: &HtmlOptions) {
+        match self {
+            Container::BlockQuote(block_quote) => block_quote.display_html(buffer, options),
+            Container::List(list) => list.display_html(buffer, options),
+        }
+    }
+}
@@ -1,62 +1,54 @@
 use crate::{
-    Segment,
-    ast::block::{AtxHeading, LinkReferenceDefinition},
-    render::display_html::DisplayHtml,
+    ast::block::AtxHeading,
+    render::{DisplayHtml, HtmlOptions},
 };
 
 impl AtxHeading<'_> {
-    fn display_raw_content(&self, buffer: &mut String) {
-        if !self.title().is_empty() {
-            buffer.push_str(self.title());
-        }
-    }
-
-    fn display_end_of_line(&self, buffer: &mut String) {
-        if self.segment().ends_with('\n') {
-            buffer.push('\n');
+    fn display_content_html(&self, buffer: &mut String, options: &HtmlOptions) {
+        for inline in self.inlines() {
+            inline.display_html(buffer, options);
         }
     }
 }
 
 impl DisplayHtml for AtxHeading<'_> {
-    fn display_html(&self, buffer: &mut String, _: &[LinkReferenceDefinition]) {
-        // TODO: use the link refs and inline text.
+    fn display_html(&self, buffer: &mut String, options: &HtmlOptions) {
         match self.level() {
             1 => {
                 buffer.push_str("<h1>");
-                self.display_raw_content(buffer);
+                self.display_content_html(buffer, options);
                 buffer.push_str("</h1>");
-                self.display_end_of_line(buffer);
+                buffer.push('\n');
             }
             2 => {
                 buffer.push_str("<h2>");
-                self.display_raw_content(buffer);
+                self.display_content_html(buffer, options);
                 buffer.push_str("</h2>");
-                self.display_end_of_line(buffer);
+                buffer.push('\n');
             }
             3 => {
                 buffer.push_str("<h3>");
-                self.display_raw_content(buffer);
+                self.display_content_html(buffer, options);
                 buffer.push_str("</h3>");
-                self.display_end_of_line(buffer);
+                buffer.push('\n');
             }
             4 => {
                 buffer.push_str("<h4>");
-                self.display_raw_content(buffer);
+                self.display_content_html(buffer, options);
                 buffer.push_str("</h4>");
-                self.display_end_of_line(buffer);
+                buffer.push('\n');
             }
             5 => {
                 buffer.push_str("<h5>");
-                self.display_raw_content(buffer);
+                self.display_content_html(buffer, options);
                 buffer.push_str("</h5>");
-                self.display_end_of_line(buffer);
+                buffer.push('\n');
             }
             6 => {
                 buffer.push_str("<h6>");
-                self.display_raw_content(buffer);
+                self.display_content_html(buffer, options);
                 buffer.push_str("</h6>");
-                self.display_end_of_line(buffer);
+                buffer.push('\n');
             }
             _ => panic!("invalid level {:?}", self.level()),
         }
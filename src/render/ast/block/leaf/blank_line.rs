@@ -1,10 +1,10 @@
 use crate::{
-    ast::block::{BlankLine, LinkReferenceDefinition},
-    render::DisplayHtml,
+    ast::block::BlankLine,
+    render::{DisplayHtml, HtmlOptions},
 };
 
 impl DisplayHtml for BlankLine<'_> {
-    fn display_html(&self, _: &mut String, _: &[LinkReferenceDefinition]) {
+    fn display_html(&self, _: &mut String, _: &HtmlOptions) {
         // Blank lines are ignored.
     }
 }
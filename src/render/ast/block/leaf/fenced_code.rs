@@ -1,40 +1,27 @@
 use crate::{
-    ast::block::{FencedCode, LinkReferenceDefinition},
-    render::DisplayHtml,
+    ast::block::FencedCode,
+    parse::unescape,
+    render::{DisplayHtml, HtmlOptions, PushEscapedStr},
 };
 
-trait PushContentSegment {
-    fn push_content_segment(&mut self, segment: &str);
-}
-
-impl PushContentSegment for String {
-    // TODO: add the unindent.
-    fn push_content_segment(&mut self, segment: &str) {
-        for char in segment.chars() {
-            // Escape html chars!
-            match char {
-                '&' => self.push_str("&amp;"),
-                '<' => self.push_str("&lt;"),
-                '>' => self.push_str("&gt;"),
-                '"' => self.push_str("&quot;"),
-                '\'' => self.push_str("&#x27;"),
-                '/' => self.push_str("&#x2F;"),
-                _ => self.push(char),
-            }
-        }
-    }
-}
-
 impl DisplayHtml for FencedCode<'_> {
-    fn display_html(
-        &self,
-        buffer: &mut String,
-        _link_reference_definitions: &[LinkReferenceDefinition],
-    ) {
-        buffer.push_str("<pre><code>");
+    fn display_html(&self, buffer: &mut String, _: &HtmlOptions) {
+        buffer.push_str("<pre><code");
+        // The first word of the info string is the language, which is given as a class.
+        if let Some(language) = unescape(self.info_string()).split_whitespace().next() {
+            buffer.push_str(" class=\"language-");
+            buffer.push_escaped_str(language);
+            buffer.push('"');
+        }
+        buffer.push('>');
+        let content_start = buffer.len();
         for segment in self.content_segments() {
-            buffer.push_content_segment(segment);
+            buffer.push_escaped_str(segment);
+        }
+        // The last line of content, if any, always ends with a line ending.
+        if buffer.len() > content_start && !buffer.ends_with('\n') {
+            buffer.push('\n');
         }
-        buffer.push_str("</code></pre>");
+        buffer.push_str("</code></pre>\n");
     }
 }
@@ -1,18 +1,17 @@
 use crate::{
     Segments,
-    ast::block::{Html, LinkReferenceDefinition},
-    render::DisplayHtml,
+    ast::block::Html,
+    render::{DisplayHtml, HtmlOptions, push_raw_html},
 };
 
 impl DisplayHtml for Html<'_> {
-    /// HTML blocks are not escaped.
-    fn display_html(
-        &self,
-        buffer: &mut String,
-        _link_reference_definitions: &[LinkReferenceDefinition],
-    ) {
+    /// HTML blocks are not escaped, except for the disallowed tags when they are filtered.
+    fn display_html(&self, buffer: &mut String, options: &HtmlOptions) {
         for segment in self.segments() {
-            buffer.push_str(segment);
+            push_raw_html(buffer, segment, options);
+        }
+        if !buffer.ends_with('\n') {
+            buffer.push('\n');
         }
     }
 }
@@ -1,7 +1,10 @@
-use crate::{ast::block::LinkReferenceDefinition, render::DisplayHtml};
+use crate::{
+    ast::block::LinkReferenceDefinition,
+    render::{DisplayHtml, HtmlOptions},
+};
 
 impl DisplayHtml for LinkReferenceDefinition<'_> {
-    fn display_html(&self, _: &mut String, _: &[LinkReferenceDefinition]) {
+    fn display_html(&self, _: &mut String, _: &HtmlOptions) {
         // Link reference definitions are not rendered.
     }
 }
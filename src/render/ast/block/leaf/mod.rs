@@ -4,39 +4,31 @@ mod fenced_code;
 mod html;
 mod indented_code;
 mod link_reference_definition;
+mod paragraph;
+mod setext_heading;
+mod table;
 mod thematic_break;
 
 use crate::{
-    ast::block::{Leaf, LinkReferenceDefinition},
-    render::display_html::DisplayHtml,
+    ast::block::Leaf,
+    render::{DisplayHtml, HtmlOptions},
 };
 
 impl DisplayHtml for Leaf<'_> {
-    fn display_html(
-        &self,
-        buffer: &mut String,
-        link_reference_definitions: &[LinkReferenceDefinition],
-    ) {
+    fn display_html(&self, buffer: &mut String, options: &HtmlOptions) {
         match self {
-            Leaf::AtxHeading(atx_heading) => {
-                atx_heading.display_html(buffer, link_reference_definitions)
-            }
-            Leaf::BlankLine(blank_line) => {
-                blank_line.display_html(buffer, link_reference_definitions)
-            }
-            Leaf::FencedCode(fenced_code) => {
-                fenced_code.display_html(buffer, link_reference_definitions)
-            }
-            Leaf::Html(html) => html.display_html(buffer, link_reference_definitions),
-            Leaf::IndentedCode(indented_code) => {
-                indented_code.display_html(buffer, link_reference_definitions)
-            }
+            Leaf::AtxHeading(atx_heading) => atx_heading.display_html(buffer, options),
+            Leaf::BlankLine(blank_line) => blank_line.display_html(buffer, options),
+            Leaf::FencedCode(fenced_code) => fenced_code.display_html(buffer, options),
+            Leaf::Html(html) => html.display_html(buffer, options),
+            Leaf::IndentedCode(indented_code) => indented_code.display_html(buffer, options),
             Leaf::LinkReferenceDefinition(link_reference_definition) => {
-                link_reference_definition.display_html(buffer, link_reference_definitions)
-            }
-            Leaf::ThematicBreak(thematic_break) => {
-                thematic_break.display_html(buffer, link_reference_definitions)
+                link_reference_definition.display_html(buffer, options)
             }
+            Leaf::Paragraph(paragraph) => paragraph.display_html(buffer, options),
+            Leaf::SetextHeading(setext_heading) => setext_heading.display_html(buffer, options),
+            Leaf::Table(table) => table.display_html(buffer, options),
+            Leaf::ThematicBreak(thematic_break) => thematic_break.display_html(buffer, options),
         }
     }
 }
@@ -0,0 +1,23 @@
+use crate::{
+    ast::block::Paragraph,
+    render::{DisplayHtml, HtmlOptions},
+};
+
+impl DisplayHtml for Paragraph<'_> {
+    fn display_html(&self, buffer: &mut String, options: &HtmlOptions) {
+        buffer.push_str("<p>");
+        self.display_content_html(buffer, options);
+        buffer.push_str("</p>\n");
+    }
+}
+
+impl Paragraph<'_> {
+    /// Renders the content of the paragraph, without the enclosing tags.
+    ///
+    /// This is how paragraphs are rendered in tight lists.
+    pub(crate) fn display_content_html(&self, buffer: &mut String, options: &HtmlOptions) {
+        for inline in self.inlines() {
+            inline.display_html(buffer, options);
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use crate::{
+    ast::block::SetextHeading,
+    render::{DisplayHtml, HtmlOptions},
+};
+
+impl DisplayHtml for SetextHeading<'_> {
+    fn display_html(&self, buffer: &mut String, options: &HtmlOptions) {
+        let tag = match self.level() {
+            1 => "h1",
+            2 => "h2",
+            _ => panic!("invalid level {:?}", self.level()),
+        };
+        buffer.push('<');
+        buffer.push_str(tag);
+        buffer.push('>');
+        for inline in self.inlines() {
+            inline.display_html(buffer, options);
+        }
+        buffer.push_str("</");
+        buffer.push_str(tag);
+        buffer.push_str(">\n");
+    }
+}
@@ -0,0 +1,64 @@
+use crate::{
+    ast::block::{Alignment, Table, TableCell, TableRow},
+    render::{DisplayHtml, HtmlOptions},
+};
+
+impl DisplayHtml for Table<'_> {
+    fn display_html(&self, buffer: &mut String, options: &HtmlOptions) {
+        buffer.push_str("<table>\n<thead>\n");
+        self.display_row_html(buffer, options, self.header(), "th");
+        buffer.push_str("</thead>\n");
+        if !self.rows().is_empty() {
+            buffer.push_str("<tbody>\n");
+            for row in self.rows() {
+                self.display_row_html(buffer, options, row, "td");
+            }
+            buffer.push_str("</tbody>\n");
+        }
+        buffer.push_str("</table>\n");
+    }
+}
+
+impl Table<'_> {
+    /// Renders the row with one cell per column, whatever the number of cells it has.
+    fn display_row_html(
+        &self,
+        buffer: &mut String,
+        options: &HtmlOptions,
+        row: &TableRow,
+        tag: &str,
+    ) {
+        buffer.push_str("<tr>\n");
+        for (index, alignment) in self.alignments().iter().enumerate() {
+            push_cell_start_tag(buffer, tag, *alignment);
+            if let Some(cell) = row.cells().get(index) {
+                cell.display_html(buffer, options);
+            }
+            buffer.push_str("</");
+            buffer.push_str(tag);
+            buffer.push_str(">\n");
+        }
+        buffer.push_str("</tr>\n");
+    }
+}
+
+impl DisplayHtml for TableCell<'_> {
+    fn display_html(&self, buffer: &mut String, options: &HtmlOptions) {
+        for inline in self.inlines() {
+            inline.display_html(buffer, options);
+        }
+    }
+}
+
+/// Pushes the start tag of a header or data cell, with the alignment of its column, if any.
+fn push_cell_start_tag(buffer: &mut String, tag: &str, alignment: Alignment) {
+    buffer.push('<');
+    buffer.push_str(tag);
+    match alignment {
+        Alignment::None => {}
+        Alignment::Left => buffer.push_str(" align=\"left\""),
+        Alignment::Center => buffer.push_str(" align=\"center\""),
+        Alignment::Right => buffer.push_str(" align=\"right\""),
+    }
+    buffer.push('>');
+}
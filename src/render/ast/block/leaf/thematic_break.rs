@@ -1,14 +1,8 @@
-use super::DisplayHtml;
-use crate::{
-    Segment,
-    ast::block::{LinkReferenceDefinition, ThematicBreak},
-};
+use super::{DisplayHtml, HtmlOptions};
+use crate::ast::block::ThematicBreak;
 
 impl DisplayHtml for ThematicBreak<'_> {
-    fn display_html(&self, buffer: &mut String, _: &[LinkReferenceDefinition]) {
-        buffer.push_str("<hr />");
-        if self.segment().ends_with('\n') {
-            buffer.push('\n');
-        }
+    fn display_html(&self, buffer: &mut String, _: &HtmlOptions) {
+        buffer.push_str("<hr />\n");
     }
 }
@@ -1,19 +1,16 @@
 use crate::{
-    ast::block::{Block, LinkReferenceDefinition},
-    render::display_html::DisplayHtml,
+    ast::block::Block,
+    render::{DisplayHtml, HtmlOptions},
 };
 
 mod container;
 mod leaf;
 
 impl DisplayHtml for Block<'_> {
-    fn display_html(
-        &self,
-        buffer: &mut String,
-        link_reference_definitions: &[LinkReferenceDefinition],
-    ) {
+    fn display_html(&self, buffer: &mut String, options: &HtmlOptions) {
         match self {
-            Block::Leaf(leaf) => leaf.display_html(buffer, link_reference_definitions),
+            Block::Container(container) => container.display_html(buffer, options),
+            Block::Leaf(leaf) => leaf.display_html(buffer, options),
         }
     }
 }
@@ -0,0 +1,15 @@
+use crate::{
+    ast::inline::Autolink,
+    render::{DisplayHtml, HtmlOptions, PushEscapedStr, PushEscapedUrl},
+};
+
+impl DisplayHtml for Autolink<'_> {
+    fn display_html(&self, buffer: &mut String, _: &HtmlOptions) {
+        // Backslash escapes and entities are only decoded in extended autolinks.
+        buffer.push_str("<a href=\"");
+        buffer.push_escaped_url(&self.destination());
+        buffer.push_str("\">");
+        buffer.push_escaped_str(&self.text());
+        buffer.push_str("</a>");
+    }
+}
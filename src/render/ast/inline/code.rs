@@ -0,0 +1,12 @@
+use crate::{
+    ast::inline::Code,
+    render::{DisplayHtml, HtmlOptions, PushEscapedStr},
+};
+
+impl DisplayHtml for Code<'_> {
+    fn display_html(&self, buffer: &mut String, _: &HtmlOptions) {
+        buffer.push_str("<code>");
+        buffer.push_escaped_str(&self.content());
+        buffer.push_str("</code>");
+    }
+}
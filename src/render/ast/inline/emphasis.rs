@@ -0,0 +1,14 @@
+use crate::{
+    ast::inline::Emphasis,
+    render::{DisplayHtml, HtmlOptions},
+};
+
+impl DisplayHtml for Emphasis<'_> {
+    fn display_html(&self, buffer: &mut String, options: &HtmlOptions) {
+        buffer.push_str("<em>");
+        for child in self.children() {
+            child.display_html(buffer, options);
+        }
+        buffer.push_str("</em>");
+    }
+}
@@ -0,0 +1,38 @@
+use super::link::{push_destination, push_title};
+use crate::{
+    ast::inline::{Image, Inline},
+    render::{DisplayHtml, HtmlOptions, PushEscapedStr},
+};
+
+impl DisplayHtml for Image<'_> {
+    fn display_html(&self, buffer: &mut String, _: &HtmlOptions) {
+        buffer.push_str("<img src=\"");
+        push_destination(buffer, self.destination());
+        buffer.push_str("\" alt=\"");
+        push_plain_text(buffer, self.children());
+        buffer.push('"');
+        push_title(buffer, self.title());
+        buffer.push_str(" />");
+    }
+}
+
+/// Pushes the text content of the inlines, without any markup, as used in the `alt` attribute.
+fn push_plain_text(buffer: &mut String, inlines: &[Inline]) {
+    for inline in inlines {
+        match inline {
+            Inline::Autolink(autolink) => buffer.push_escaped_str(&autolink.text()),
+            Inline::Code(code) => buffer.push_escaped_str(&code.content()),
+            Inline::Emphasis(emphasis) => push_plain_text(buffer, emphasis.children()),
+            Inline::HardBreak(_) => buffer.push('\n'),
+            Inline::Image(image) => push_plain_text(buffer, image.children()),
+            Inline::Link(link) => push_plain_text(buffer, link.children()),
+            Inline::RawHtml(_) => {}
+            Inline::SoftBreak(_) => buffer.push('\n'),
+            Inline::Strikethrough(strikethrough) => {
+                push_plain_text(buffer, strikethrough.children())
+            }
+            Inline::Strong(strong) => push_plain_text(buffer, strong.children()),
+            Inline::Text(text) => buffer.push_escaped_str(&text.content()),
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use crate::{
+    ast::inline::{
+        Link,
+        link::{LinkDestination, LinkTitle},
+    },
+    render::{DisplayHtml, HtmlOptions, PushEscapedStr, PushEscapedUrl},
+};
+
+impl DisplayHtml for Link<'_> {
+    fn display_html(&self, buffer: &mut String, options: &HtmlOptions) {
+        buffer.push_str("<a href=\"");
+        push_destination(buffer, self.destination());
+        buffer.push('"');
+        push_title(buffer, self.title());
+        buffer.push('>');
+        for child in self.children() {
+            child.display_html(buffer, options);
+        }
+        buffer.push_str("</a>");
+    }
+}
+
+/// Pushes the URL of the destination, if any.
+pub(super) fn push_destination(buffer: &mut String, destination: Option<&LinkDestination>) {
+    if let Some(destination) = destination {
+        buffer.push_escaped_url(&destination.url());
+    }
+}
+
+/// Pushes the title attribute, if there is a title.
+pub(super) fn push_title(buffer: &mut String, title: Option<&LinkTitle>) {
+    if let Some(title) = title {
+        buffer.push_str(" title=\"");
+        buffer.push_escaped_str(&title.content());
+        buffer.push('"');
+    }
+}
@@ -0,0 +1,84 @@
+mod autolink;
+mod code;
+mod emphasis;
+mod image;
+mod link;
+mod strikethrough;
+mod strong;
+
+use crate::{
+    Segments,
+    ast::inline::Inline,
+    render::{DisplayHtml, HtmlOptions, PushEscapedStr, SoftBreakStyle, push_raw_html},
+};
+
+impl DisplayHtml for Inline<'_> {
+    fn display_html(&self, buffer: &mut String, options: &HtmlOptions) {
+        match self {
+            Inline::Autolink(autolink) => autolink.display_html(buffer, options),
+            Inline::Code(code) => code.display_html(buffer, options),
+            Inline::Emphasis(emphasis) => emphasis.display_html(buffer, options),
+            Inline::HardBreak(_) => buffer.push_str("<br />\n"),
+            Inline::Image(image) => image.display_html(buffer, options),
+            Inline::Link(link) => link.display_html(buffer, options),
+            Inline::RawHtml(raw_html) => {
+                for segment in raw_html.segments() {
+                    push_raw_html(buffer, segment, options);
+                }
+            }
+            Inline::SoftBreak(_) => match options.soft_break {
+                SoftBreakStyle::Newline => buffer.push('\n'),
+                SoftBreakStyle::Space => buffer.push(' '),
+                SoftBreakStyle::LineBreak => buffer.push_str("<br />\n"),
+            },
+            Inline::Strikethrough(strikethrough) => strikethrough.display_html(buffer, options),
+            Inline::Strong(strong) => strong.display_html(buffer, options),
+            Inline::Text(text) => buffer.push_escaped_str(&text.content()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        ToHtml, parse,
+        render::{HtmlOptions, SoftBreakStyle},
+    };
+
+    fn render(markdown: &str, soft_break: SoftBreakStyle) -> String {
+        parse(markdown).to_html_with_options(&HtmlOptions {
+            soft_break,
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn should_render_soft_breaks_as_newlines_by_default() {
+        assert_eq!(parse("foo\nbar").to_html(), "<p>foo\nbar</p>");
+        assert_eq!(
+            render("foo\nbar", SoftBreakStyle::Newline),
+            "<p>foo\nbar</p>"
+        );
+    }
+
+    #[test]
+    fn should_render_soft_breaks_as_spaces() {
+        assert_eq!(render("foo\nbar", SoftBreakStyle::Space), "<p>foo bar</p>");
+    }
+
+    #[test]
+    fn should_render_soft_breaks_as_line_breaks() {
+        assert_eq!(
+            render("foo\nbar", SoftBreakStyle::LineBreak),
+            "<p>foo<br />\nbar</p>"
+        );
+    }
+
+    #[test]
+    fn should_always_render_hard_breaks_as_line_breaks() {
+        assert_eq!(
+            render("foo  \nbar", SoftBreakStyle::Space),
+            "<p>foo<br />\nbar</p>"
+        );
+    }
+}
@@ -0,0 +1,14 @@
+use crate::{
+    ast::inline::Strikethrough,
+    render::{DisplayHtml, HtmlOptions},
+};
+
+impl DisplayHtml for Strikethrough<'_> {
+    fn display_html(&self, buffer: &mut String, options: &HtmlOptions) {
+        buffer.push_str("<del>");
+        for child in self.children() {
+            child.display_html(buffer, options);
+        }
+        buffer.push_str("</del>");
+    }
+}
@@ -0,0 +1,14 @@
+use crate::{
+    ast::inline::Strong,
+    render::{DisplayHtml, HtmlOptions},
+};
+
+impl DisplayHtml for Strong<'_> {
+    fn display_html(&self, buffer: &mut String, options: &HtmlOptions) {
+        buffer.push_str("<strong>");
+        for child in self.children() {
+            child.display_html(buffer, options);
+        }
+        buffer.push_str("</strong>");
+    }
+}
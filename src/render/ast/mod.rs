@@ -1,17 +1,14 @@
 mod block;
+mod inline;
 
-use super::display_html::DisplayHtml;
-use crate::ast::{Document, block::LinkReferenceDefinition};
+use super::{DisplayHtml, HtmlOptions};
+use crate::ast::Document;
 
 impl DisplayHtml for Document<'_> {
-    fn display_html(
-        &self,
-        buffer: &mut String,
-        link_reference_definitions: &[LinkReferenceDefinition],
-    ) {
+    fn display_html(&self, buffer: &mut String, options: &HtmlOptions) {
         // TODO: use some form of fold or sum'
         for block in &self.blocks {
-            block.display_html(buffer, link_reference_definitions);
+            block.display_html(buffer, options);
         }
     }
 }
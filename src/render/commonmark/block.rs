@@ -0,0 +1,586 @@
+use super::inline::{push_inlines, push_line_ending};
+use crate::{
+    Segment, Segments,
+    ast::{
+        block::{
+            Alignment, AtxHeading, Block, BlockQuote, Container, FencedCode, Html, IndentedCode,
+            Leaf, LinkReferenceDefinition, List, ListMarker, Paragraph, SetextHeading, Table,
+            TableRow, ThematicBreak,
+        },
+        inline::Inline,
+    },
+    parse::{
+        ast::block::leaf::{html::starts_html_block, paragraph::interrupts_paragraph},
+        traits::ParseLine,
+    },
+    render::DisplayCommonMark,
+};
+
+/// Pushes the blocks, separated by blank lines unless they are the content of a tight list item.
+///
+/// The blank lines of the source are not kept, as the separators take their place.
+pub(super) fn push_blocks(buffer: &mut String, blocks: &[Block], tight: bool) {
+    let blocks: Vec<_> = blocks
+        .iter()
+        .filter(|block| !matches!(block, Block::Leaf(Leaf::BlankLine(_))))
+        .collect();
+    let mut previous = None;
+    for (index, block) in blocks.iter().copied().enumerate() {
+        match (previous, block) {
+            // Indented code following a list would continue its last item, so it is fenced instead.
+            (
+                Some(&Block::Container(Container::List(_))),
+                Block::Leaf(Leaf::IndentedCode(indented_code)),
+            ) => {
+                push_separator(buffer, tight);
+                push_fenced_lines(
+                    buffer,
+                    "",
+                    &indented_code.content_lines().collect::<Vec<_>>(),
+                )
+            }
+            // A paragraph starting with what would be an HTML block can only follow a link
+            // reference definition it continues.
+            (
+                Some(&Block::Leaf(Leaf::LinkReferenceDefinition(_))),
+                Block::Leaf(Leaf::Paragraph(paragraph)),
+            ) if starts_with_html_block(paragraph.inlines()) => {
+                push_paragraph_lines(buffer, paragraph.inlines(), true)
+            }
+            (
+                Some(&Block::Leaf(Leaf::LinkReferenceDefinition(_))),
+                Block::Leaf(Leaf::SetextHeading(setext_heading)),
+            ) if starts_with_html_block(setext_heading.inlines()) => {
+                push_paragraph_lines(buffer, setext_heading.inlines(), true);
+                push_setext_heading_underline(buffer, setext_heading);
+            }
+            (previous, block) => {
+                if previous.is_some() {
+                    push_separator(buffer, tight);
+                }
+                match block {
+                    // An indented HTML block following a list would continue its last item,
+                    // unless the content of the item is indented further.
+                    Block::Container(Container::List(list))
+                        if blocks
+                            .get(index + 1)
+                            .is_some_and(|next| is_indented_html(next)) =>
+                    {
+                        push_list(buffer, list, 4)
+                    }
+                    block => block.display_commonmark(buffer),
+                }
+                // A paragraph following a block quote in a tight list item would otherwise
+                // continue the last paragraph of the quote, and so would a table.
+                if tight
+                    && matches!(block, Block::Container(Container::BlockQuote(_)))
+                    && matches!(
+                        blocks.get(index + 1),
+                        Some(Block::Leaf(
+                            Leaf::Paragraph(_) | Leaf::SetextHeading(_) | Leaf::Table(_)
+                        ))
+                    )
+                {
+                    buffer.push_str(">\n");
+                }
+            }
+        }
+        previous = Some(block);
+    }
+}
+
+fn push_separator(buffer: &mut String, tight: bool) {
+    if !tight {
+        buffer.push('\n');
+    }
+}
+
+fn starts_with_html_block(inlines: &[Inline]) -> bool {
+    let mut line = String::new();
+    push_inlines(&mut line, inlines);
+    matches!(inlines.first(), Some(Inline::RawHtml(_))) && starts_html_block(&line)
+}
+
+fn is_indented_html(block: &Block) -> bool {
+    match block {
+        Block::Leaf(Leaf::Html(html)) => html
+            .segments()
+            .next()
+            .is_some_and(|line| line.starts_with([' ', '\t'])),
+        _ => false,
+    }
+}
+
+/// Pushes the lines of the content, the first one after the first prefix, and the following ones
+/// after the continuation prefix. Blank lines don't get the trailing whitespace of the prefix.
+fn push_prefixed_lines(buffer: &mut String, content: &str, first_prefix: &str, prefix: &str) {
+    for (index, line) in content.split_inclusive('\n').enumerate() {
+        let prefix = if index == 0 { first_prefix } else { prefix };
+        if line == "\n" {
+            buffer.push_str(prefix.trim_end());
+        } else {
+            buffer.push_str(prefix);
+        }
+        buffer.push_str(line);
+    }
+}
+
+/// Pushes the line ending of the last line, if it is missing, which happens at the end of the
+/// source.
+fn end_line(buffer: &mut String) {
+    if !buffer.ends_with('\n') {
+        buffer.push('\n');
+    }
+}
+
+impl DisplayCommonMark for Block<'_> {
+    fn display_commonmark(&self, buffer: &mut String) {
+        match self {
+            Block::Container(Container::BlockQuote(block_quote)) => {
+                block_quote.display_commonmark(buffer)
+            }
+            Block::Container(Container::List(list)) => list.display_commonmark(buffer),
+            Block::Leaf(Leaf::AtxHeading(atx_heading)) => atx_heading.display_commonmark(buffer),
+            Block::Leaf(Leaf::BlankLine(_)) => {}
+            Block::Leaf(Leaf::FencedCode(fenced_code)) => fenced_code.display_commonmark(buffer),
+            Block::Leaf(Leaf::Html(html)) => html.display_commonmark(buffer),
+            Block::Leaf(Leaf::IndentedCode(indented_code)) => {
+                indented_code.display_commonmark(buffer)
+            }
+            Block::Leaf(Leaf::LinkReferenceDefinition(link_reference_definition)) => {
+                link_reference_definition.display_commonmark(buffer)
+            }
+            Block::Leaf(Leaf::Paragraph(paragraph)) => paragraph.display_commonmark(buffer),
+            Block::Leaf(Leaf::SetextHeading(setext_heading)) => {
+                setext_heading.display_commonmark(buffer)
+            }
+            Block::Leaf(Leaf::Table(table)) => table.display_commonmark(buffer),
+            // Underscores can neither be confused with a bullet list marker, nor with a setext
+            // heading underline.
+            Block::Leaf(Leaf::ThematicBreak(_)) => buffer.push_str("___\n"),
+        }
+    }
+}
+
+impl DisplayCommonMark for BlockQuote<'_> {
+    fn display_commonmark(&self, buffer: &mut String) {
+        let mut content = String::new();
+        push_blocks(&mut content, self.blocks(), false);
+        if content.is_empty() {
+            buffer.push_str(">\n");
+        } else {
+            push_prefixed_lines(buffer, &content, "> ", "> ");
+        }
+    }
+}
+
+impl DisplayCommonMark for List<'_> {
+    fn display_commonmark(&self, buffer: &mut String) {
+        push_list(buffer, self, 0);
+    }
+}
+
+/// Pushes the items of the list, the content of the last one indented by at least
+/// `last_item_indent`, when it can be.
+fn push_list(buffer: &mut String, list: &List, last_item_indent: usize) {
+    let items = list.items();
+    for (index, item) in items.iter().enumerate() {
+        if index > 0 && !list.is_tight() {
+            buffer.push('\n');
+        }
+        // The markers are kept, so that consecutive lists don't merge into one.
+        let marker = match item.marker() {
+            ListMarker::Bullet(bullet) => bullet.to_string(),
+            ListMarker::Ordered { number, delimiter } => {
+                // Only the number of the first item matters, the following ones are counted
+                // from it, as long as they fit in 9 digits.
+                let number = if index == 0 {
+                    number
+                } else {
+                    list.start()
+                        .unwrap_or(number)
+                        .saturating_add(index as u32)
+                        .min(999_999_999)
+                };
+                format!("{number}{delimiter}")
+            }
+        };
+        let mut indent = marker.len() + 1;
+        if index == items.len() - 1 {
+            indent = indent.max(last_item_indent);
+        }
+        let mut content = String::new();
+        match item.blocks().split_first() {
+            // Indented code starts on the line of the marker, which leaves the content indented by
+            // one more than the marker, so it is fenced when the content must be indented further.
+            Some((Block::Leaf(Leaf::IndentedCode(indented_code)), rest))
+                if indent > marker.len() + 1 =>
+            {
+                push_fenced_lines(
+                    &mut content,
+                    "",
+                    &indented_code.content_lines().collect::<Vec<_>>(),
+                );
+                if rest
+                    .iter()
+                    .any(|block| !matches!(block, Block::Leaf(Leaf::BlankLine(_))))
+                {
+                    push_separator(&mut content, list.is_tight());
+                }
+                push_blocks(&mut content, rest, list.is_tight());
+            }
+            _ => push_blocks(&mut content, item.blocks(), list.is_tight()),
+        }
+        let first_prefix = format!("{marker:<indent$}");
+        let first_line = content.lines().next().unwrap_or_default();
+        let code_indent = " ".repeat(marker.len() + 1);
+        let checkbox = item
+            .checked()
+            .map(|checked| if checked { "[x]" } else { "[ ]" });
+        if let Some(checkbox) = checkbox {
+            // The whitespace following the checkbox is not part of the content either, so the
+            // content starts on the next line when its whitespace matters.
+            if content.is_empty() || content.starts_with([' ', '\t']) {
+                buffer.push_str(&format!("{marker} {checkbox}\n"));
+                push_prefixed_lines(buffer, &content, &code_indent, &code_indent);
+            } else {
+                let first_prefix = format!("{first_prefix}{checkbox} ");
+                push_prefixed_lines(buffer, &content, &first_prefix, &" ".repeat(indent));
+            }
+        } else if content.is_empty() {
+            buffer.push_str(&marker);
+            buffer.push('\n');
+        } else if content.starts_with("    ")
+            && ThematicBreak::parse_line(&format!("{marker} {first_line}\n")).is_err()
+        {
+            // Indented code can start on the line of the marker, which is followed by a single
+            // space. Starting on the next line, the marker would be taken for a setext heading
+            // underline after a paragraph of a tight list.
+            push_prefixed_lines(buffer, &content, &format!("{marker} "), &code_indent);
+        } else if content.starts_with([' ', '\t'])
+            || ThematicBreak::parse_line(&format!("{first_prefix}{first_line}\n")).is_ok()
+        {
+            // The whitespace following the marker is not part of the content, so the content
+            // starts on the next line when its whitespace matters, like the one of HTML. So does
+            // the content that would make a thematic break of the marker, like an empty nested
+            // item. The content of such an item is indented by one more than the marker.
+            buffer.push_str(&marker);
+            buffer.push('\n');
+            push_prefixed_lines(buffer, &content, &code_indent, &code_indent);
+        } else {
+            push_prefixed_lines(buffer, &content, &first_prefix, &" ".repeat(indent));
+        }
+    }
+}
+
+impl DisplayCommonMark for AtxHeading<'_> {
+    fn display_commonmark(&self, buffer: &mut String) {
+        buffer.push_str(&"#".repeat(self.level().into()));
+        if !self.inlines().is_empty() {
+            buffer.push(' ');
+            push_inlines(buffer, self.inlines());
+        }
+        push_line_ending(buffer);
+    }
+}
+
+impl DisplayCommonMark for SetextHeading<'_> {
+    fn display_commonmark(&self, buffer: &mut String) {
+        push_paragraph_lines(buffer, self.inlines(), false);
+        push_setext_heading_underline(buffer, self);
+    }
+}
+
+fn push_setext_heading_underline(buffer: &mut String, setext_heading: &SetextHeading) {
+    match setext_heading.level() {
+        1 => buffer.push_str("===\n"),
+        _ => buffer.push_str("---\n"),
+    }
+}
+
+impl DisplayCommonMark for Table<'_> {
+    /// Every row is written with its leading and trailing pipes, and the cells as they are, even
+    /// the ones in excess of the columns.
+    fn display_commonmark(&self, buffer: &mut String) {
+        push_table_row(buffer, self.header());
+        buffer.push('|');
+        for alignment in self.alignments() {
+            buffer.push_str(match alignment {
+                Alignment::None => " --- |",
+                Alignment::Left => " :-- |",
+                Alignment::Center => " :-: |",
+                Alignment::Right => " --: |",
+            });
+        }
+        buffer.push('\n');
+        for row in self.rows() {
+            push_table_row(buffer, row);
+        }
+    }
+}
+
+/// Pushes the cells of the row between pipes.
+fn push_table_row(buffer: &mut String, row: &TableRow) {
+    buffer.push('|');
+    for cell in row.cells() {
+        let mut content = String::new();
+        push_inlines(&mut content, cell.inlines());
+        buffer.push(' ');
+        push_escaped_pipes(buffer, &content);
+        buffer.push_str(" |");
+    }
+    buffer.push('\n');
+}
+
+/// Pushes the content of a cell, escaping the pipes that are not already, like the ones of code
+/// spans.
+fn push_escaped_pipes(buffer: &mut String, content: &str) {
+    let mut backslashes = 0;
+    for char in content.chars() {
+        if char == '|' && backslashes % 2 == 0 {
+            buffer.push('\\');
+        }
+        backslashes = if char == '\\' { backslashes + 1 } else { 0 };
+        buffer.push(char);
+    }
+}
+
+impl DisplayCommonMark for Paragraph<'_> {
+    fn display_commonmark(&self, buffer: &mut String) {
+        push_paragraph_lines(buffer, self.inlines(), false);
+    }
+}
+
+/// Pushes the lines of the inline content of a paragraph, all of them continuing the previous
+/// line when `continued` is set, or all but the first one otherwise.
+///
+/// The continuation lines that would interrupt the paragraph, like those starting with raw HTML,
+/// are indented, as their indentation is not part of the content.
+fn push_paragraph_lines(buffer: &mut String, inlines: &[Inline], continued: bool) {
+    let mut content = String::new();
+    push_inlines(&mut content, inlines);
+    push_line_ending(&mut content);
+    for (index, line) in content.split_inclusive('\n').enumerate() {
+        if (continued || index > 0) && interrupts_paragraph(line) {
+            buffer.push_str("    ");
+        }
+        buffer.push_str(line);
+    }
+}
+
+impl DisplayCommonMark for FencedCode<'_> {
+    fn display_commonmark(&self, buffer: &mut String) {
+        let lines: Vec<_> = self.content_segments().collect();
+        push_fenced_lines(buffer, self.info_string(), &lines);
+    }
+}
+
+/// Pushes the lines of content between fences.
+///
+/// The fences are made of backticks, unless the info string contains some, and are longer than
+/// any sequence of the same character starting a line of content, so they cannot be closed early.
+fn push_fenced_lines(buffer: &mut String, info_string: &str, lines: &[&str]) {
+    let fence_char = if info_string.contains('`') { '~' } else { '`' };
+    let longest = lines
+        .iter()
+        .map(|line| {
+            line.trim_start()
+                .chars()
+                .take_while(|char| *char == fence_char)
+                .count()
+        })
+        .max()
+        .unwrap_or(0);
+    let fence = fence_char.to_string().repeat(longest.max(2) + 1);
+    buffer.push_str(&fence);
+    // An info string starting with the fence character would lengthen the fence.
+    if info_string.starts_with(fence_char) {
+        buffer.push(' ');
+    }
+    buffer.push_str(info_string);
+    buffer.push('\n');
+    for line in lines {
+        buffer.push_str(line);
+    }
+    end_line(buffer);
+    buffer.push_str(&fence);
+    buffer.push('\n');
+}
+
+impl DisplayCommonMark for IndentedCode<'_> {
+    fn display_commonmark(&self, buffer: &mut String) {
+        for line in self.content_lines() {
+            if line != "\n" {
+                buffer.push_str("    ");
+            }
+            buffer.push_str(line);
+        }
+        end_line(buffer);
+    }
+}
+
+impl DisplayCommonMark for Html<'_> {
+    fn display_commonmark(&self, buffer: &mut String) {
+        buffer.extend(self.segments());
+        end_line(buffer);
+    }
+}
+
+impl DisplayCommonMark for LinkReferenceDefinition<'_> {
+    /// The label, destination and title are kept as they are written, as links refer to the
+    /// definition by its label.
+    fn display_commonmark(&self, buffer: &mut String) {
+        buffer.push_str(self.label().segment());
+        buffer.push_str(": ");
+        buffer.push_str(self.destination().segment());
+        if let Some(title) = self.title() {
+            buffer.push(' ');
+            buffer.extend(title.segments());
+        }
+        buffer.push('\n');
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{ParseOptions, ToCommonMark, parse, parse_with_options};
+
+    #[test]
+    fn should_prefix_the_lines_of_block_quotes() {
+        assert_eq!(
+            parse("> foo\nbar\n>\n> - baz\n").to_commonmark(),
+            "> foo\n> bar\n>\n> - baz\n"
+        );
+    }
+
+    #[test]
+    fn should_indent_the_content_of_list_items() {
+        assert_eq!(
+            parse("1) foo\n\n   bar\n2)\n10) baz\n").to_commonmark(),
+            "1) foo\n\n   bar\n\n2)\n\n3) baz\n"
+        );
+    }
+
+    #[test]
+    fn should_start_list_items_on_the_next_line_when_indented() {
+        assert_eq!(parse("-\n     <div>\n").to_commonmark(), "-\n     <div>\n");
+    }
+
+    #[test]
+    fn should_start_indented_code_on_the_line_of_the_marker() {
+        assert_eq!(
+            parse("- foo\n  -     bar\n").to_commonmark(),
+            "- foo\n  -     bar\n"
+        );
+    }
+
+    #[test]
+    fn should_indent_the_last_list_item_before_indented_html() {
+        assert_eq!(
+            parse("-  foo\n\n  <div>\n").to_commonmark(),
+            "-   foo\n\n  <div>\n"
+        );
+    }
+
+    #[test]
+    fn should_fence_indented_code_of_the_last_list_item_before_indented_html() {
+        assert_eq!(
+            parse("  -     foo\n  <div>\n").to_commonmark(),
+            "-   ```\n    foo\n    ```\n\n  <div>\n"
+        );
+    }
+
+    #[test]
+    fn should_write_the_checkboxes_of_task_list_items() {
+        let options = ParseOptions {
+            task_lists: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            parse_with_options("- [X]   foo\n-  [ ]\n      <div>\n", &options).to_commonmark(),
+            "- [x] foo\n- [ ]\n     <div>\n"
+        );
+    }
+
+    #[test]
+    fn should_keep_tight_lists_tight() {
+        assert_eq!(
+            parse("* foo\n  # bar\n* baz").to_commonmark(),
+            "* foo\n  # bar\n* baz\n"
+        );
+    }
+
+    #[test]
+    fn should_choose_a_fence_longer_than_the_content() {
+        assert_eq!(
+            parse("~~~ rust\n````\n~~~").to_commonmark(),
+            "`````rust\n````\n`````\n"
+        );
+    }
+
+    #[test]
+    fn should_use_tildes_when_the_info_string_has_backticks() {
+        assert_eq!(
+            parse("~~~ a`b\ncode\n").to_commonmark(),
+            "~~~a`b\ncode\n~~~\n"
+        );
+    }
+
+    #[test]
+    fn should_fence_indented_code_following_lists() {
+        assert_eq!(
+            parse("100. foo\n\n    bar\n").to_commonmark(),
+            "100. foo\n\n```\nbar\n```\n"
+        );
+    }
+
+    #[test]
+    fn should_indent_lines_that_would_interrupt_paragraphs() {
+        assert_eq!(
+            parse("foo\n    <div>\n").to_commonmark(),
+            "foo\n    <div>\n"
+        );
+    }
+
+    #[test]
+    fn should_keep_paragraphs_continuing_link_reference_definitions() {
+        assert_eq!(
+            parse("[foo]: /url\n    <div>\n\n[bar]: /url\n<a>\n").to_commonmark(),
+            "[foo]: /url\n    <div>\n\n[bar]: /url\n<a>\n"
+        );
+    }
+
+    #[test]
+    fn should_separate_info_strings_starting_with_the_fence_character() {
+        assert_eq!(parse("~~~ ~`\n~~~").to_commonmark(), "~~~ ~`\n~~~\n");
+    }
+
+    #[test]
+    fn should_end_block_quotes_followed_by_paragraphs_in_tight_lists() {
+        assert_eq!(
+            parse("- > foo\n  >\n  bar\n").to_commonmark(),
+            "- > foo\n  >\n  bar\n"
+        );
+    }
+
+    #[test]
+    fn should_not_write_empty_nested_list_items_as_thematic_breaks() {
+        assert_eq!(parse("- - \n    -\n").to_commonmark(), "-\n  - -\n");
+    }
+
+    #[test]
+    fn should_keep_the_content_of_indented_code() {
+        assert_eq!(
+            parse("      foo\n\n    bar").to_commonmark(),
+            "      foo\n\n    bar\n"
+        );
+    }
+
+    #[test]
+    fn should_keep_link_reference_definitions_as_they_are() {
+        assert_eq!(
+            parse("[Foo]:\n  <bar baz>  'title'\n").to_commonmark(),
+            "[Foo]: <bar baz> 'title'\n"
+        );
+    }
+}
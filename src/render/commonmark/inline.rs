@@ -0,0 +1,488 @@
+use super::{push_character_reference, push_escaped};
+use crate::{
+    Segment, Segments,
+    ast::inline::{
+        Autolink, Code, Inline,
+        link::{LinkDestination, LinkTitle},
+    },
+    parse::{ast::inline::autolink::extended_autolink_at, unescape},
+    render::DisplayCommonMark,
+};
+use std::borrow::Cow;
+
+/// The extended autolinks pushed, with the index of the buffer they start at.
+type ExtendedAutolinks<'i, 'a> = Vec<(usize, &'i Autolink<'a>)>;
+
+pub(super) fn push_inlines(buffer: &mut String, inlines: &[Inline]) {
+    let mut autolinks = Vec::new();
+    push_children(buffer, inlines, None, &mut autolinks);
+    resolve_extended_autolinks(buffer, autolinks);
+}
+
+/// Pushes the inlines, which are the children of an emphasis, strong emphasis or strikethrough using the
+/// `enclosing` delimiter, if any.
+fn push_children<'i, 'a>(
+    buffer: &mut String,
+    inlines: &'i [Inline<'a>],
+    enclosing: Option<char>,
+    autolinks: &mut ExtendedAutolinks<'i, 'a>,
+) {
+    for (index, inline) in inlines.iter().enumerate() {
+        match inline {
+            Inline::Text(text) => {
+                let before = match index {
+                    0 => enclosing,
+                    _ => delimiter(&inlines[index - 1]),
+                };
+                let after = inlines.get(index + 1).map_or(enclosing, delimiter);
+                push_text_between(buffer, text.segment(), before, after);
+            }
+            // A backslash following a delimiter run would make it left-flanking, which can change
+            // the delimiters it matches. The opening delimiter is left-flanking already, and spaces
+            // would change that.
+            Inline::HardBreak(_)
+                if (index > 0 || enclosing.is_none()) && buffer.ends_with(['*', '_', '~']) =>
+            {
+                buffer.push_str("  \n")
+            }
+            inline => push_inline(buffer, inline, autolinks),
+        }
+        // A text ending with an ampersand could start a character reference with the following text.
+        if let (Inline::Text(_), Some(Inline::Text(next))) = (inline, inlines.get(index + 1)) {
+            if buffer.ends_with('&')
+                && next
+                    .content()
+                    .starts_with(|c: char| c.is_ascii_alphanumeric() || c == '#')
+            {
+                buffer.pop();
+                buffer.push_str("\\&");
+            }
+        }
+        // A text ending with an exclamation mark would turn the following link into an image.
+        if matches!(inline, Inline::Text(_))
+            && matches!(inlines.get(index + 1), Some(Inline::Link(_)))
+            && buffer.ends_with('!')
+        {
+            buffer.pop();
+            buffer.push_str("\\!");
+        }
+    }
+}
+
+/// Pushes the inline, keeping track of the extended autolinks within it, which can only be written
+/// as they are once what follows them is known.
+fn push_inline<'i, 'a>(
+    buffer: &mut String,
+    inline: &'i Inline<'a>,
+    autolinks: &mut ExtendedAutolinks<'i, 'a>,
+) {
+    match inline {
+        Inline::Autolink(autolink) if autolink.is_extended() => {
+            autolinks.push((buffer.len(), autolink));
+            buffer.push_str(autolink.segment());
+        }
+        Inline::Emphasis(emphasis) => {
+            let delimiter = emphasis.delimiter();
+            buffer.push(delimiter);
+            push_children(buffer, emphasis.children(), Some(delimiter), autolinks);
+            buffer.push(delimiter);
+        }
+        Inline::Strikethrough(strikethrough) => {
+            let delimiters = "~".repeat(strikethrough.delimiter_length());
+            buffer.push_str(&delimiters);
+            push_children(buffer, strikethrough.children(), Some('~'), autolinks);
+            buffer.push_str(&delimiters);
+        }
+        Inline::Strong(strong) => {
+            let delimiters = strong.delimiter().to_string().repeat(2);
+            buffer.push_str(&delimiters);
+            push_children(
+                buffer,
+                strong.children(),
+                Some(strong.delimiter()),
+                autolinks,
+            );
+            buffer.push_str(&delimiters);
+        }
+        inline => inline.display_commonmark(buffer),
+    }
+}
+
+/// Makes sure that the extended autolinks are still recognized as they are once followed by the
+/// rest of the buffer. The text that would extend them is written with character references, which
+/// are trimmed from their end, and they are written as inline links when that is not enough.
+fn resolve_extended_autolinks(buffer: &mut String, autolinks: ExtendedAutolinks) {
+    // From the last, so that rewriting one does not move the others.
+    let mut next_start = buffer.len();
+    for (start, autolink) in autolinks.into_iter().rev() {
+        let end = start + autolink.segment().len();
+        let mut position = end;
+        let mut limit = next_start;
+        let original = buffer[end..limit].to_string();
+        loop {
+            let line_end = buffer[start..]
+                .find('\n')
+                .map_or(buffer.len(), |index| start + index);
+            let recognized = extended_autolink_at(&buffer[..line_end], start);
+            if recognized.is_some_and(|(segment, _)| start + segment.len() == end) {
+                break;
+            }
+            let following = &buffer[position..line_end.min(limit)];
+            let mut chars = following.chars();
+            let (length, char) = match (chars.next(), chars.next()) {
+                (Some('\\'), Some(escaped)) if escaped.is_ascii_punctuation() => (2, escaped),
+                (Some(char), _) if !char.is_whitespace() && !"[]`<".contains(char) => {
+                    (char.len_utf8(), char)
+                }
+                // Nothing can be rewritten past the end of the autolink, the start of another
+                // inline, or the next extended autolink.
+                _ => {
+                    // The following text is written as it was, as it will be after the link.
+                    buffer.replace_range(end..limit, &original);
+                    let mut link = String::from("[");
+                    push_text(&mut link, &autolink.text());
+                    push_link_end(&mut link, &autolink.destination(), None);
+                    buffer.replace_range(start..end, &link);
+                    break;
+                }
+            };
+            let reference_length = following
+                .strip_prefix("&#")
+                .map(|rest| rest.find(|c: char| !c.is_ascii_digit()).unwrap_or(0))
+                .filter(|&digits| digits > 0 && following[2 + digits..].starts_with(';'))
+                .map(|digits| digits + 3);
+            if let Some(reference_length) = reference_length {
+                position += reference_length;
+            } else if "?!.,:*_~;)".contains(char) {
+                position += length;
+            } else {
+                let mut reference = String::new();
+                push_character_reference(&mut reference, char);
+                buffer.replace_range(position..position + length, &reference);
+                position += reference.len();
+                limit = limit + reference.len() - length;
+            }
+        }
+        next_start = start;
+    }
+}
+
+impl DisplayCommonMark for Inline<'_> {
+    fn display_commonmark(&self, buffer: &mut String) {
+        match self {
+            Inline::Autolink(autolink) if !autolink.is_extended() => {
+                buffer.push_str(autolink.segment())
+            }
+            Inline::Autolink(_)
+            | Inline::Emphasis(_)
+            | Inline::Strikethrough(_)
+            | Inline::Strong(_) => {
+                let mut autolinks = Vec::new();
+                push_inline(buffer, self, &mut autolinks);
+                resolve_extended_autolinks(buffer, autolinks);
+            }
+            Inline::Code(code) => code.display_commonmark(buffer),
+            Inline::HardBreak(_) => buffer.push_str("\\\n"),
+            // Reference links are written inline, as their label could be escaped differently
+            // than the definition's.
+            Inline::Image(image) => {
+                buffer.push_str("![");
+                push_inlines(buffer, image.children());
+                push_link_end(buffer, &destination_url(image.destination()), image.title());
+            }
+            Inline::Link(link) => {
+                buffer.push('[');
+                push_inlines(buffer, link.children());
+                push_link_end(buffer, &destination_url(link.destination()), link.title());
+            }
+            Inline::RawHtml(raw_html) => buffer.extend(raw_html.segments()),
+            Inline::SoftBreak(_) => push_line_ending(buffer),
+            Inline::Text(text) => push_text(buffer, &text.content()),
+        }
+    }
+}
+
+impl DisplayCommonMark for Code<'_> {
+    /// The backtick strings are longer than any in the content. The content is padded with spaces
+    /// when it would otherwise lose the ones it starts and ends with, or merge with the backticks.
+    fn display_commonmark(&self, buffer: &mut String) {
+        let content = self.content();
+        let longest = content
+            .split(|char| char != '`')
+            .map(str::len)
+            .max()
+            .unwrap_or(0);
+        let backticks = "`".repeat(longest + 1);
+        let padded = content.starts_with('`')
+            || content.ends_with('`')
+            || (content.starts_with(' ')
+                && content.ends_with(' ')
+                && !content.chars().all(|char| char == ' '));
+        buffer.push_str(&backticks);
+        if padded {
+            buffer.push(' ');
+        }
+        buffer.push_str(&content);
+        if padded {
+            buffer.push(' ');
+        }
+        buffer.push_str(&backticks);
+    }
+}
+
+/// The delimiter of an emphasis, strong emphasis or strikethrough.
+fn delimiter(inline: &Inline) -> Option<char> {
+    match inline {
+        Inline::Emphasis(emphasis) => Some(emphasis.delimiter()),
+        Inline::Strikethrough(_) => Some('~'),
+        Inline::Strong(strong) => Some(strong.delimiter()),
+        _ => None,
+    }
+}
+
+/// Pushes the text found between the delimiters `before` and `after`, if any, from its segment.
+///
+/// The unescaped delimiter characters adjacent to the delimiters are kept as they are, as they
+/// belong to the same delimiter runs, whose lengths decide which delimiters match.
+fn push_text_between(
+    buffer: &mut String,
+    segment: &str,
+    before: Option<char>,
+    after: Option<char>,
+) {
+    let start = before.map_or(0, |delimiter| {
+        segment.len() - segment.trim_start_matches(delimiter).len()
+    });
+    let end = after.map_or(segment.len(), |delimiter| {
+        let trimmed = segment[start..].trim_end_matches(delimiter);
+        let mut end = start + trimmed.len();
+        // The first character of the run is escaped by an odd number of backslashes.
+        let backslashes = trimmed
+            .chars()
+            .rev()
+            .take_while(|char| *char == '\\')
+            .count();
+        if backslashes % 2 == 1 && end < segment.len() {
+            end += 1;
+        }
+        end
+    });
+    buffer.push_str(&segment[..start]);
+    push_text(buffer, &unescape(&segment[start..end]));
+    buffer.push_str(&segment[end..]);
+}
+
+/// Pushes the text, escaping the characters that would otherwise be taken for markup.
+///
+/// The characters of the inline markup are always escaped, whereas the ones starting blocks are
+/// only escaped at the start of a line, where they matter.
+fn push_text(buffer: &mut String, text: &str) {
+    let mut chars = text.chars().peekable();
+    while let Some(char) = chars.next() {
+        let line = &buffer[buffer.rfind('\n').map_or(0, |index| index + 1)..];
+        let at_line_start = line.is_empty();
+        match char {
+            // Pipes would otherwise separate the cells of tables, and tildes delimit strikethrough.
+            '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '|' | '~' => {
+                buffer.push('\\');
+                buffer.push(char);
+            }
+            '>' | '-' | '+' | '=' if at_line_start => {
+                buffer.push('\\');
+                buffer.push(char);
+            }
+            // Closing sequences of ATX headings are preceded by a space.
+            '#' if at_line_start || line.ends_with(' ') => buffer.push_str("\\#"),
+            // The delimiter of an ordered list item marker.
+            '.' | ')'
+                if (1..=9).contains(&line.len()) && line.chars().all(|c| c.is_ascii_digit()) =>
+            {
+                buffer.push('\\');
+                buffer.push(char);
+            }
+            // The indentation of a line is not part of its content.
+            ' ' | '\t' if at_line_start => push_character_reference(buffer, char),
+            '&' if chars
+                .peek()
+                .is_some_and(|next| next.is_ascii_alphanumeric() || *next == '#') =>
+            {
+                buffer.push_str("\\&")
+            }
+            // Line feeds, which can only come from character references, would end the line. The
+            // other control characters are kept as they are, as their character references end
+            // with punctuation, which changes whether the delimiters next to them open or close.
+            // The carriage returns ending lines are written by `push_line_ending`.
+            '\n' => push_character_reference(buffer, char),
+            char => buffer.push(char),
+        }
+    }
+}
+
+/// Ends the line, writing the carriage return ending it as a character reference, as it would
+/// otherwise be taken for part of the line ending.
+pub(super) fn push_line_ending(buffer: &mut String) {
+    if buffer.ends_with('\r') {
+        buffer.pop();
+        push_character_reference(buffer, '\r');
+    }
+    buffer.push('\n');
+}
+
+/// Pushes the end of a link or an image, from the closing bracket of its description, in the
+/// inline form.
+fn push_link_end(buffer: &mut String, url: &str, title: Option<&LinkTitle>) {
+    buffer.push_str("](");
+    if url.is_empty() || url.contains(|char: char| char == ' ' || char.is_ascii_control()) {
+        buffer.push('<');
+        push_escaped(buffer, url, &['\\', '<', '>']);
+        buffer.push('>');
+    } else {
+        push_escaped(buffer, url, &['\\', '<', '>', '(', ')']);
+    }
+    if let Some(title) = title {
+        buffer.push_str(" \"");
+        push_escaped(buffer, &title.content(), &['\\', '"']);
+        buffer.push('"');
+    }
+    buffer.push(')');
+}
+
+fn destination_url<'d>(destination: Option<&'d LinkDestination>) -> Cow<'d, str> {
+    destination.map(LinkDestination::url).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{ParseOptions, ToCommonMark, parse, parse_with_options};
+
+    #[test]
+    fn should_escape_inline_markup_in_text() {
+        assert_eq!(
+            parse(r"\*foo\* \[bar] \`baz\` <\qux>").to_commonmark(),
+            "\\*foo\\* \\[bar\\] \\`baz\\` \\<\\\\qux>\n"
+        );
+    }
+
+    #[test]
+    fn should_escape_block_markup_at_the_start_of_lines() {
+        assert_eq!(
+            parse("\\# foo #\n\\- bar -\n1\\. baz 2.\n\\===").to_commonmark(),
+            "\\# foo \\#\n\\- bar -\n1\\. baz 2.\n\\===\n"
+        );
+    }
+
+    #[test]
+    fn should_escape_what_looks_like_character_references() {
+        assert_eq!(parse("&amp;amp; & foo").to_commonmark(), "\\&amp; & foo\n");
+    }
+
+    #[test]
+    fn should_keep_control_characters_next_to_delimiters() {
+        assert_eq!(
+            parse("*\u{e}* a\r_b_\r").to_commonmark(),
+            "*\u{e}* a\r_b_\n"
+        );
+    }
+
+    #[test]
+    fn should_write_carriage_returns_ending_lines_with_character_references() {
+        assert_eq!(
+            parse("foo\r\r\nbar&#13;").to_commonmark(),
+            "foo&#13;\nbar&#13;\n"
+        );
+    }
+
+    #[test]
+    fn should_keep_the_delimiters_of_emphasis() {
+        assert_eq!(
+            parse("*_foo_* __bar__").to_commonmark(),
+            "*_foo_* __bar__\n"
+        );
+    }
+
+    #[test]
+    fn should_keep_the_delimiters_of_strikethrough() {
+        let options = ParseOptions {
+            strikethrough: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            parse_with_options("~~foo~~ ~\\~bar~", &options).to_commonmark(),
+            "~~foo~~ ~\\~bar~\n"
+        );
+    }
+
+    #[test]
+    fn should_write_extended_autolinks_as_they_are() {
+        let options = ParseOptions {
+            autolinks: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            parse_with_options("*www.foo.com*, (foo@bar.baz)", &options).to_commonmark(),
+            "*www.foo.com*, (foo@bar.baz)\n"
+        );
+    }
+
+    #[test]
+    fn should_write_what_would_extend_extended_autolinks_with_character_references() {
+        let options = ParseOptions {
+            autolinks: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            parse_with_options("www.foo.com&copy;", &options).to_commonmark(),
+            "www.foo.com&#169;\n"
+        );
+    }
+
+    #[test]
+    fn should_write_links_inline() {
+        assert_eq!(
+            parse("[foo][bar] ![baz](<a b> 'c\"d')\n\n[bar]: /url\n").to_commonmark(),
+            "[foo](/url) ![baz](<a b> \"c\\\"d\")\n\n[bar]: /url\n"
+        );
+    }
+
+    #[test]
+    fn should_pad_code_spans_when_needed() {
+        assert_eq!(
+            parse("`` `foo` `` `  bar  ` ``` `` ```").to_commonmark(),
+            "`` `foo` `` `  bar  ` ``` `` ```\n"
+        );
+    }
+
+    #[test]
+    fn should_not_turn_links_into_images() {
+        assert_eq!(
+            parse("foo\\![bar](/url) baz!").to_commonmark(),
+            "foo\\![bar](/url) baz!\n"
+        );
+    }
+
+    #[test]
+    fn should_keep_delimiter_runs() {
+        assert_eq!(
+            parse("__&amp;\n2) \\__a__ ***b*c*").to_commonmark(),
+            "__&\n2\\) \\__a__ ***b*c*\n"
+        );
+    }
+
+    #[test]
+    fn should_write_hard_breaks_with_spaces_after_delimiters() {
+        assert_eq!(parse("*a-**  \nb").to_commonmark(), "*a-**  \nb\n");
+    }
+
+    #[test]
+    fn should_write_hard_breaks_with_backslashes_after_opening_delimiters() {
+        assert_eq!(parse("a *\\\nb*").to_commonmark(), "a *\\\nb*\n");
+    }
+
+    #[test]
+    fn should_write_hard_breaks_with_backslashes() {
+        assert_eq!(
+            parse("foo  \nbar\nbaz").to_commonmark(),
+            "foo\\\nbar\nbaz\n"
+        );
+    }
+}
@@ -0,0 +1,70 @@
+mod block;
+mod inline;
+
+use super::DisplayCommonMark;
+use crate::ast::Document;
+use block::push_blocks;
+
+impl DisplayCommonMark for Document<'_> {
+    fn display_commonmark(&self, buffer: &mut String) {
+        push_blocks(buffer, self.blocks(), false);
+    }
+}
+
+/// Pushes the text, escaping the characters of `special` with a backslash.
+///
+/// Ampersands that could start a character reference are escaped too, and the line endings and
+/// other control characters are written as numeric character references, so the text stays on
+/// one line. The null character is kept as it is, as its character reference decodes to the
+/// replacement character instead.
+fn push_escaped(buffer: &mut String, text: &str, special: &[char]) {
+    let mut chars = text.chars().peekable();
+    while let Some(char) = chars.next() {
+        match char {
+            '&' if chars
+                .peek()
+                .is_some_and(|next| next.is_ascii_alphanumeric() || *next == '#') =>
+            {
+                buffer.push_str("\\&")
+            }
+            char if special.contains(&char) => {
+                buffer.push('\\');
+                buffer.push(char);
+            }
+            char if char.is_ascii_control() && !matches!(char, '\t' | '\0') => {
+                push_character_reference(buffer, char)
+            }
+            char => buffer.push(char),
+        }
+    }
+}
+
+fn push_character_reference(buffer: &mut String, char: char) {
+    buffer.push_str(&format!("&#{};", char as u32));
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{ToCommonMark, parse};
+
+    #[test]
+    fn should_separate_blocks_with_blank_lines() {
+        assert_eq!(
+            parse("# foo\nbar\n\n\n***\n").to_commonmark(),
+            "# foo\n\nbar\n\n___\n"
+        );
+    }
+
+    #[test]
+    fn should_keep_null_characters() {
+        assert_eq!(
+            parse("a\0b [c](<d\0e> \"f\0g\")").to_commonmark(),
+            "a\0b [c](<d\0e> \"f\0g\")\n"
+        );
+    }
+
+    #[test]
+    fn should_work_with_empty_source() {
+        assert_eq!(parse("").to_commonmark(), "");
+    }
+}
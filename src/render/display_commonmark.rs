@@ -0,0 +1,3 @@
+pub(crate) trait DisplayCommonMark {
+    fn display_commonmark(&self, buffer: &mut String);
+}
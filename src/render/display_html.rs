@@ -1,9 +1,57 @@
-use crate::ast::block::LinkReferenceDefinition;
+use super::HtmlOptions;
 
 pub(crate) trait DisplayHtml {
-    fn display_html(
-        &self,
-        buffer: &mut String,
-        link_reference_definitions: &[LinkReferenceDefinition],
-    );
+    fn display_html(&self, buffer: &mut String, options: &HtmlOptions);
+}
+
+/// The tags that the tag filter escapes, as they change how the HTML that follows them is parsed.
+const DISALLOWED_TAGS: [&str; 9] = [
+    "title",
+    "textarea",
+    "style",
+    "xmp",
+    "iframe",
+    "noembed",
+    "noframes",
+    "script",
+    "plaintext",
+];
+
+/// Pushes the raw HTML as it is, unless the options filter the disallowed tags.
+pub(crate) fn push_raw_html(buffer: &mut String, html: &str, options: &HtmlOptions) {
+    if !options.tag_filter {
+        buffer.push_str(html);
+        return;
+    }
+    let mut start = 0;
+    for (index, _) in html.match_indices('<') {
+        if is_disallowed_tag(&html[index + 1..]) {
+            buffer.push_str(&html[start..index]);
+            buffer.push_str("&lt;");
+            start = index + 1;
+        }
+    }
+    buffer.push_str(&html[start..]);
+}
+
+/// Whether the HTML following a `<` is an opening or closing tag with a disallowed name.
+fn is_disallowed_tag(html: &str) -> bool {
+    let html = html.strip_prefix('/').unwrap_or(html);
+    DISALLOWED_TAGS.iter().any(|name| {
+        html.get(..name.len())
+            .is_some_and(|prefix| prefix.eq_ignore_ascii_case(name))
+            && html[name.len()..].chars().next().is_none_or(|character| {
+                matches!(character, '>' | '/') || character.is_ascii_whitespace()
+            })
+    })
+}
+
+/// Pushes the disabled checkbox of a task list item, followed by the space separating it from the
+/// content of the item.
+pub(crate) fn push_checkbox(buffer: &mut String, checked: bool) {
+    if checked {
+        buffer.push_str("<input type=\"checkbox\" checked=\"\" disabled=\"\" /> ");
+    } else {
+        buffer.push_str("<input type=\"checkbox\" disabled=\"\" /> ");
+    }
 }
@@ -0,0 +1,47 @@
+pub(crate) trait PushEscapedStr {
+    /// Pushes the text, escaping the characters that have a special meaning in HTML.
+    fn push_escaped_str(&mut self, text: &str);
+}
+
+impl PushEscapedStr for String {
+    fn push_escaped_str(&mut self, text: &str) {
+        for char in text.chars() {
+            match char {
+                '&' => self.push_str("&amp;"),
+                '<' => self.push_str("&lt;"),
+                '>' => self.push_str("&gt;"),
+                '"' => self.push_str("&quot;"),
+                _ => self.push(char),
+            }
+        }
+    }
+}
+
+pub(crate) trait PushEscapedUrl {
+    /// Pushes the URL, percent-encoding the characters that are not allowed in one, then escaping
+    /// the characters that have a special meaning in HTML.
+    ///
+    /// Existing percent-encoded sequences are kept as they are.
+    fn push_escaped_url(&mut self, url: &str);
+}
+
+impl PushEscapedUrl for String {
+    fn push_escaped_url(&mut self, url: &str) {
+        let bytes = url.as_bytes();
+        for (index, &byte) in bytes.iter().enumerate() {
+            match byte {
+                b'&' => self.push_str("&amp;"),
+                b'%' if bytes
+                    .get(index + 1..index + 3)
+                    .is_some_and(|digits| digits.iter().all(u8::is_ascii_hexdigit)) =>
+                {
+                    self.push('%')
+                }
+                byte if byte.is_ascii_alphanumeric() || b";/?:@=+$,-_.!~*'()#".contains(&byte) => {
+                    self.push(byte as char)
+                }
+                byte => self.push_str(&format!("%{byte:02X}")),
+            }
+        }
+    }
+}
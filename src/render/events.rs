@@ -0,0 +1,277 @@
+use super::{
+    HtmlOptions, PushEscapedStr, PushEscapedUrl, SoftBreakStyle, push_checkbox, push_raw_html,
+};
+use crate::{CodeBlockKind, Event, Tag, ast::block::Alignment};
+
+/// Renders the events as HTML, the same way [ToHtml](crate::ToHtml) renders a document.
+///
+/// The events are expected to be well nested, as produced by [events](crate::events), although they
+/// can be filtered or rewritten in between.
+pub fn html<'a>(events: impl IntoIterator<Item = Event<'a>>, options: &HtmlOptions) -> String {
+    let mut buffer = String::new();
+    let mut events = events.into_iter();
+    // Where the content of the current code block starts in the buffer.
+    let mut code_block_start = 0;
+    // Whether the cells being rendered are the ones of a table header, and whether the body of the
+    // current table was started, which only happens when it has rows.
+    let mut in_table_head = false;
+    let mut in_table_body = false;
+    while let Some(event) = events.next() {
+        match event {
+            Event::Start(Tag::Image { destination, title }) => {
+                buffer.push_str("<img src=\"");
+                buffer.push_escaped_url(&destination);
+                buffer.push_str("\" alt=\"");
+                push_alt_text(&mut buffer, &mut events);
+                buffer.push('"');
+                push_title(&mut buffer, title.as_deref());
+                buffer.push_str(" />");
+            }
+            Event::Start(Tag::TableHead) => {
+                in_table_head = true;
+                buffer.push_str("<thead>\n<tr>\n");
+            }
+            Event::End(Tag::TableHead) => {
+                in_table_head = false;
+                buffer.push_str("</tr>\n</thead>\n");
+            }
+            Event::Start(Tag::TableRow) => {
+                if !in_table_body {
+                    in_table_body = true;
+                    buffer.push_str("<tbody>\n");
+                }
+                buffer.push_str("<tr>\n");
+            }
+            Event::Start(Tag::TableCell(alignment)) => {
+                push_cell_start_tag(&mut buffer, cell_tag(in_table_head), alignment)
+            }
+            Event::End(Tag::TableCell(_)) => {
+                buffer.push_str("</");
+                buffer.push_str(cell_tag(in_table_head));
+                buffer.push_str(">\n");
+            }
+            Event::End(Tag::Table(_)) => {
+                if in_table_body {
+                    in_table_body = false;
+                    buffer.push_str("</tbody>\n");
+                }
+                buffer.push_str("</table>\n");
+            }
+            Event::Start(tag) => {
+                if is_block(&tag) {
+                    start_block(&mut buffer);
+                }
+                push_start_tag(&mut buffer, &tag);
+                code_block_start = buffer.len();
+            }
+            Event::End(Tag::CodeBlock(_)) => {
+                // The last line of content, if any, always ends with a line ending.
+                if buffer.len() > code_block_start && !buffer.ends_with('\n') {
+                    buffer.push('\n');
+                }
+                buffer.push_str("</code></pre>\n");
+            }
+            Event::End(tag) => push_end_tag(&mut buffer, &tag),
+            Event::Text(text) => buffer.push_escaped_str(&text),
+            Event::Code(code) => {
+                buffer.push_str("<code>");
+                buffer.push_escaped_str(&code);
+                buffer.push_str("</code>");
+            }
+            Event::Html(html) | Event::InlineHtml(html) => {
+                push_raw_html(&mut buffer, &html, options)
+            }
+            Event::SoftBreak => match options.soft_break {
+                SoftBreakStyle::Newline => buffer.push('\n'),
+                SoftBreakStyle::Space => buffer.push(' '),
+                SoftBreakStyle::LineBreak => buffer.push_str("<br />\n"),
+            },
+            Event::HardBreak => buffer.push_str("<br />\n"),
+            Event::TaskListMarker(checked) => push_checkbox(&mut buffer, checked),
+            Event::Rule => {
+                start_block(&mut buffer);
+                buffer.push_str("<hr />\n");
+            }
+        }
+    }
+    // Every block ends with a line ending, except the last one.
+    if buffer.ends_with('\n') {
+        buffer.pop();
+    }
+    buffer
+}
+
+fn is_block(tag: &Tag) -> bool {
+    matches!(
+        tag,
+        Tag::Paragraph
+            | Tag::Heading(_)
+            | Tag::BlockQuote
+            | Tag::CodeBlock(_)
+            | Tag::HtmlBlock
+            | Tag::List(_)
+            | Tag::Table(_)
+    )
+}
+
+/// The tag of the cells of the table header, or of the table body.
+fn cell_tag(in_table_head: bool) -> &'static str {
+    if in_table_head { "th" } else { "td" }
+}
+
+fn push_cell_start_tag(buffer: &mut String, tag: &str, alignment: Alignment) {
+    buffer.push('<');
+    buffer.push_str(tag);
+    match alignment {
+        Alignment::None => {}
+        Alignment::Left => buffer.push_str(" align=\"left\""),
+        Alignment::Center => buffer.push_str(" align=\"center\""),
+        Alignment::Right => buffer.push_str(" align=\"right\""),
+    }
+    buffer.push('>');
+}
+
+/// Blocks start on their own line, which only matters after the content of a tight list item.
+fn start_block(buffer: &mut String) {
+    if !buffer.is_empty() && !buffer.ends_with('\n') {
+        buffer.push('\n');
+    }
+}
+
+fn push_start_tag(buffer: &mut String, tag: &Tag) {
+    match tag {
+        Tag::Paragraph => buffer.push_str("<p>"),
+        Tag::Heading(level) => {
+            buffer.push_str("<h");
+            buffer.push_str(&level.to_string());
+            buffer.push('>');
+        }
+        Tag::BlockQuote => buffer.push_str("<blockquote>\n"),
+        Tag::CodeBlock(CodeBlockKind::Indented) => buffer.push_str("<pre><code>"),
+        Tag::CodeBlock(CodeBlockKind::Fenced(info_string)) => {
+            buffer.push_str("<pre><code");
+            // The first word of the info string is the language, which is given as a class.
+            if let Some(language) = info_string.split_whitespace().next() {
+                buffer.push_str(" class=\"language-");
+                buffer.push_escaped_str(language);
+                buffer.push('"');
+            }
+            buffer.push('>');
+        }
+        Tag::HtmlBlock => {}
+        Tag::List(None) => buffer.push_str("<ul>\n"),
+        Tag::List(Some(1)) => buffer.push_str("<ol>\n"),
+        Tag::List(Some(start)) => {
+            buffer.push_str("<ol start=\"");
+            buffer.push_str(&start.to_string());
+            buffer.push_str("\">\n");
+        }
+        Tag::Item => buffer.push_str("<li>"),
+        Tag::Emphasis => buffer.push_str("<em>"),
+        Tag::Strong => buffer.push_str("<strong>"),
+        Tag::Strikethrough => buffer.push_str("<del>"),
+        Tag::Link { destination, title } => {
+            buffer.push_str("<a href=\"");
+            buffer.push_escaped_url(destination);
+            buffer.push('"');
+            push_title(buffer, title.as_deref());
+            buffer.push('>');
+        }
+        Tag::Image { .. } => unreachable!("images are rendered with their description"),
+        Tag::Table(_) => buffer.push_str("<table>\n"),
+        Tag::TableHead | Tag::TableRow | Tag::TableCell(_) => {
+            unreachable!("table rows and cells are rendered with the state of their table")
+        }
+    }
+}
+
+fn push_end_tag(buffer: &mut String, tag: &Tag) {
+    match tag {
+        Tag::Paragraph => buffer.push_str("</p>\n"),
+        Tag::Heading(level) => {
+            buffer.push_str("</h");
+            buffer.push_str(&level.to_string());
+            buffer.push_str(">\n");
+        }
+        Tag::BlockQuote => buffer.push_str("</blockquote>\n"),
+        Tag::CodeBlock(_) => buffer.push_str("</code></pre>\n"),
+        Tag::HtmlBlock => {
+            if !buffer.ends_with('\n') {
+                buffer.push('\n');
+            }
+        }
+        Tag::List(None) => buffer.push_str("</ul>\n"),
+        Tag::List(Some(_)) => buffer.push_str("</ol>\n"),
+        Tag::Item => buffer.push_str("</li>\n"),
+        Tag::Emphasis => buffer.push_str("</em>"),
+        Tag::Strong => buffer.push_str("</strong>"),
+        Tag::Strikethrough => buffer.push_str("</del>"),
+        Tag::Link { .. } => buffer.push_str("</a>"),
+        Tag::Image { .. } => unreachable!("images are rendered with their description"),
+        Tag::TableRow => buffer.push_str("</tr>\n"),
+        Tag::Table(_) | Tag::TableHead | Tag::TableCell(_) => {
+            unreachable!("tables and their cells are rendered with the state of their table")
+        }
+    }
+}
+
+fn push_title(buffer: &mut String, title: Option<&str>) {
+    if let Some(title) = title {
+        buffer.push_str(" title=\"");
+        buffer.push_escaped_str(title);
+        buffer.push('"');
+    }
+}
+
+/// Pushes the text of the image description, up to the end of the image, without any markup.
+fn push_alt_text<'a>(buffer: &mut String, events: &mut impl Iterator<Item = Event<'a>>) {
+    let mut depth = 0;
+    for event in events {
+        match event {
+            Event::Start(_) => depth += 1,
+            Event::End(_) if depth == 0 => return,
+            Event::End(_) => depth -= 1,
+            Event::Text(text) | Event::Code(text) => buffer.push_escaped_str(&text),
+            Event::SoftBreak | Event::HardBreak => buffer.push('\n'),
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{ToHtml, events, parse};
+
+    #[test]
+    fn should_render_like_the_document() {
+        let source =
+            "# foo\n\n- *bar* ![baz `qux`](/url \"title\")\n\n  > <a>\n\n```rust\nfn\n```\n";
+        assert_eq!(
+            html(events(source), &HtmlOptions::default()),
+            parse(source).to_html()
+        );
+    }
+
+    #[test]
+    fn should_render_rewritten_events() {
+        let events = events("foo *bar*").map(|event| match event {
+            Event::Start(Tag::Emphasis) => Event::Start(Tag::Strong),
+            Event::End(Tag::Emphasis) => Event::End(Tag::Strong),
+            event => event,
+        });
+        assert_eq!(
+            html(events, &HtmlOptions::default()),
+            "<p>foo <strong>bar</strong></p>"
+        );
+    }
+
+    #[test]
+    fn should_render_soft_breaks_according_to_the_options() {
+        let options = HtmlOptions {
+            soft_break: SoftBreakStyle::Space,
+            ..Default::default()
+        };
+        assert_eq!(html(events("foo\nbar"), &options), "<p>foo bar</p>");
+    }
+}
@@ -1,4 +1,13 @@
 mod ast;
+mod commonmark;
+mod display_commonmark;
 mod display_html;
+mod escape;
+mod events;
+mod options;
 
+pub(crate) use display_commonmark::*;
 pub(crate) use display_html::*;
+pub(crate) use escape::*;
+pub use events::*;
+pub use options::*;
@@ -0,0 +1,24 @@
+/// The options of the HTML rendering.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HtmlOptions {
+    /// How the soft line breaks are rendered.
+    pub soft_break: SoftBreakStyle,
+    /// Whether the raw HTML is rendered with the
+    /// [disallowed tags](https://github.github.com/gfm/#disallowed-raw-html-extension-) filtered,
+    /// their leading `<` being escaped so that they are displayed as text.
+    pub tag_filter: bool,
+}
+
+/// How a [soft line break](https://spec.commonmark.org/0.31.2/#soft-line-breaks) is rendered.
+///
+/// Whatever the choice, hard line breaks are always rendered as `<br />`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SoftBreakStyle {
+    /// The line ending is kept, which browsers display as a space.
+    #[default]
+    Newline,
+    /// The line ending is replaced by a space.
+    Space,
+    /// The line ending is rendered as a hard line break, so the lines are displayed as they are written.
+    LineBreak,
+}
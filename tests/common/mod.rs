@@ -0,0 +1,219 @@
+//! Helpers shared by the integration tests.
+use markdown::{
+    CodeBlockKind, Event, ParseOptions, Segments, Tag, ToCommonMark,
+    ast::{
+        Document,
+        block::{Block, Container, Leaf},
+        inline::Inline,
+    },
+    events_with_options, parse_with_options,
+};
+use std::fmt::Write;
+
+/// Asserts that the markdown rendered from the document parses back to the same content.
+///
+/// The events are compared, as well as outlines of the documents, rather than the documents, as the
+/// rendering normalizes the syntax. The consecutive texts are merged, as escaping can split them
+/// differently, the indented code blocks are taken for fenced ones without info string, as the
+/// rendering can fence them, and the last line of the source is ended, as the rendering always ends
+/// it.
+///
+/// Rendering the markdown again must not change it. The markdown is always parsed with the options.
+pub fn assert_round_trip(markdown: &str, options: &ParseOptions) {
+    let rendered = parse_with_options(markdown, options).to_commonmark();
+    let mut markdown = markdown.to_string();
+    if !markdown.is_empty() && !markdown.ends_with('\n') {
+        markdown.push('\n');
+    }
+    assert_eq!(
+        merged_events(&rendered, options),
+        merged_events(&markdown, options),
+        "rendered markdown:\n{rendered}"
+    );
+    assert_eq!(
+        outline(&parse_with_options(&rendered, options)),
+        outline(&parse_with_options(&markdown, options)),
+        "rendered markdown:\n{rendered}"
+    );
+    assert_eq!(
+        parse_with_options(&rendered, options).to_commonmark(),
+        rendered,
+        "the rendering is not stable"
+    );
+}
+
+fn merged_events<'a>(markdown: &'a str, options: &ParseOptions) -> Vec<Event<'a>> {
+    let mut merged: Vec<Event> = Vec::new();
+    for event in events_with_options(markdown, options) {
+        let event = match event {
+            Event::Start(Tag::CodeBlock(CodeBlockKind::Indented)) => {
+                Event::Start(Tag::CodeBlock(CodeBlockKind::Fenced("".into())))
+            }
+            Event::End(Tag::CodeBlock(CodeBlockKind::Indented)) => {
+                Event::End(Tag::CodeBlock(CodeBlockKind::Fenced("".into())))
+            }
+            event => event,
+        };
+        match (merged.last_mut(), event) {
+            (Some(Event::Text(text)), Event::Text(next)) => text.to_mut().push_str(&next),
+            (_, event) => merged.push(event),
+        }
+    }
+    merged
+}
+
+/// Outlines the nodes of the document, leaving out their spans and the syntax the rendering
+/// normalizes, like the delimiters, the kinds of code blocks, and how texts are escaped and split.
+/// Missing destinations are taken for empty ones.
+/// The blank lines are left out, as the rendering separates the blocks its own way.
+fn outline(document: &Document) -> String {
+    let mut outline = String::new();
+    push_blocks(&mut outline, document.blocks(), 0);
+    outline
+}
+
+fn push_blocks(outline: &mut String, blocks: &[Block], depth: usize) {
+    for block in blocks {
+        push_block(outline, block, depth);
+    }
+}
+
+fn push_block(outline: &mut String, block: &Block, depth: usize) {
+    let indent = "  ".repeat(depth);
+    match block {
+        Block::Container(Container::BlockQuote(block_quote)) => {
+            writeln!(outline, "{indent}block quote").unwrap();
+            push_blocks(outline, block_quote.blocks(), depth + 1);
+        }
+        Block::Container(Container::List(list)) => {
+            writeln!(
+                outline,
+                "{indent}list ordered={} start={:?} tight={}",
+                list.is_ordered(),
+                list.start(),
+                list.is_tight()
+            )
+            .unwrap();
+            for item in list.items() {
+                writeln!(outline, "{indent}  item checked={:?}", item.checked()).unwrap();
+                push_blocks(outline, item.blocks(), depth + 2);
+            }
+        }
+        Block::Leaf(Leaf::AtxHeading(heading)) => {
+            writeln!(outline, "{indent}heading {}", heading.level()).unwrap();
+            push_inlines(outline, heading.inlines(), depth + 1);
+        }
+        Block::Leaf(Leaf::SetextHeading(heading)) => {
+            writeln!(outline, "{indent}heading {}", heading.level()).unwrap();
+            push_inlines(outline, heading.inlines(), depth + 1);
+        }
+        Block::Leaf(Leaf::BlankLine(_)) => {}
+        Block::Leaf(Leaf::FencedCode(code)) => {
+            let content: String = code.content_segments().collect();
+            writeln!(outline, "{indent}code {:?} {content:?}", code.info_string()).unwrap();
+        }
+        Block::Leaf(Leaf::IndentedCode(code)) => {
+            let content: String = code.content_lines().collect();
+            writeln!(outline, "{indent}code \"\" {content:?}").unwrap();
+        }
+        Block::Leaf(Leaf::Html(html)) => {
+            let content: String = html.segments().collect();
+            writeln!(outline, "{indent}html {content:?}").unwrap();
+        }
+        Block::Leaf(Leaf::LinkReferenceDefinition(definition)) => {
+            writeln!(
+                outline,
+                "{indent}definition {:?} {:?} {:?}",
+                definition.label().normalized(),
+                definition.destination().url(),
+                definition.title().map(|title| title.content())
+            )
+            .unwrap();
+        }
+        Block::Leaf(Leaf::Paragraph(paragraph)) => {
+            writeln!(outline, "{indent}paragraph").unwrap();
+            push_inlines(outline, paragraph.inlines(), depth + 1);
+        }
+        Block::Leaf(Leaf::Table(table)) => {
+            writeln!(outline, "{indent}table {:?}", table.alignments()).unwrap();
+            for row in std::iter::once(table.header()).chain(table.rows()) {
+                writeln!(outline, "{indent}  row").unwrap();
+                for cell in row.cells() {
+                    writeln!(outline, "{indent}    cell").unwrap();
+                    push_inlines(outline, cell.inlines(), depth + 3);
+                }
+            }
+        }
+        Block::Leaf(Leaf::ThematicBreak(_)) => writeln!(outline, "{indent}thematic break").unwrap(),
+    }
+}
+
+fn push_inlines(outline: &mut String, inlines: &[Inline], depth: usize) {
+    let indent = "  ".repeat(depth);
+    let mut text = String::new();
+    for inline in inlines {
+        if let Inline::Text(inline) = inline {
+            text.push_str(&inline.content());
+            continue;
+        }
+        if !text.is_empty() {
+            writeln!(outline, "{indent}text {text:?}").unwrap();
+            text.clear();
+        }
+        match inline {
+            Inline::Autolink(autolink) => {
+                writeln!(outline, "{indent}autolink {:?}", autolink.destination()).unwrap()
+            }
+            Inline::Code(code) => writeln!(outline, "{indent}code {:?}", code.content()).unwrap(),
+            Inline::Emphasis(emphasis) => {
+                writeln!(outline, "{indent}emphasis").unwrap();
+                push_inlines(outline, emphasis.children(), depth + 1);
+            }
+            Inline::HardBreak(_) => writeln!(outline, "{indent}hard break").unwrap(),
+            Inline::Image(image) => {
+                writeln!(
+                    outline,
+                    "{indent}image {:?} {:?}",
+                    image
+                        .destination()
+                        .map(|destination| destination.url())
+                        .unwrap_or_default(),
+                    image.title().map(|title| title.content())
+                )
+                .unwrap();
+                push_inlines(outline, image.children(), depth + 1);
+            }
+            Inline::Link(link) => {
+                writeln!(
+                    outline,
+                    "{indent}link {:?} {:?}",
+                    link.destination()
+                        .map(|destination| destination.url())
+                        .unwrap_or_default(),
+                    link.title().map(|title| title.content())
+                )
+                .unwrap();
+                push_inlines(outline, link.children(), depth + 1);
+            }
+            Inline::RawHtml(html) => writeln!(
+                outline,
+                "{indent}html {:?}",
+                html.segments().collect::<String>()
+            )
+            .unwrap(),
+            Inline::SoftBreak(_) => writeln!(outline, "{indent}soft break").unwrap(),
+            Inline::Strikethrough(strikethrough) => {
+                writeln!(outline, "{indent}strikethrough").unwrap();
+                push_inlines(outline, strikethrough.children(), depth + 1);
+            }
+            Inline::Strong(strong) => {
+                writeln!(outline, "{indent}strong").unwrap();
+                push_inlines(outline, strong.children(), depth + 1);
+            }
+            Inline::Text(_) => unreachable!(),
+        }
+    }
+    if !text.is_empty() {
+        writeln!(outline, "{indent}text {text:?}").unwrap();
+    }
+}
@@ -0,0 +1,91 @@
+//! Round trips of generated markdown through the CommonMark rendering.
+mod common;
+
+use common::assert_round_trip;
+use markdown::ParseOptions;
+use proptest::prelude::*;
+
+/// A piece of inline content, with the characters most likely to be taken for markup.
+fn inline() -> impl Strategy<Value = String> {
+    prop_oneof![
+        "[a-z]{1,6}",
+        Just(" ".to_string()),
+        "[ !\"#&()*+\\-.:;<=>\\[\\\\\\]_`|~]{1,3}",
+        "[0-9]{1,3}[.)]",
+        Just("&amp;".to_string()),
+        Just("&copy;".to_string()),
+        Just("&#35;".to_string()),
+        "\\*[a-z]{1,4}\\*",
+        "__[a-z]{1,4}__",
+        "`{1,2}[a-z `]{0,4}`{1,2}",
+        "!?\\[[a-z*]{1,4}\\]\\(/[a-z]{0,3}( \"[a-z\"]{0,3}\")?\\)",
+        "<https?://[a-z]{1,4}>",
+        "<[a-z]{1,3}>",
+        Just("\\".to_string()),
+        Just("  \n".to_string()),
+        Just("\n".to_string()),
+    ]
+}
+
+fn line() -> impl Strategy<Value = String> {
+    prop::collection::vec(inline(), 1..6).prop_map(|inlines| inlines.concat())
+}
+
+/// A block, prefixed with the markers of nested containers or not.
+fn block() -> impl Strategy<Value = String> {
+    let prefix = prop_oneof![
+        Just(""),
+        Just("> "),
+        Just("- "),
+        Just("* "),
+        Just("1. "),
+        Just("2) "),
+        Just("    "),
+        Just("  "),
+    ];
+    let content = prop_oneof![
+        line(),
+        line().prop_map(|line| format!("# {line}")),
+        line().prop_map(|line| format!("{line}\n===")),
+        line().prop_map(|line| format!("{line}\n---")),
+        "[a-z`~ ]{0,6}".prop_map(|code| format!("```\n{code}\n```")),
+        "[a-z`~ ]{0,6}".prop_map(|code| format!("~~~ info\n{code}")),
+        Just("***".to_string()),
+        Just("<div>\nfoo\n</div>".to_string()),
+        Just("[foo]: /url 'title'".to_string()),
+        Just("".to_string()),
+    ];
+    (prop::collection::vec(prefix, 0..3), content)
+        .prop_map(|(prefixes, content)| format!("{}{content}", prefixes.concat()))
+}
+
+fn document() -> impl Strategy<Value = String> {
+    prop::collection::vec(block(), 0..6).prop_map(|blocks| blocks.join("\n"))
+}
+
+/// A source made mostly of the characters that are meaningful in markdown, or an arbitrary source.
+fn source() -> impl Strategy<Value = String> {
+    prop_oneof![
+        "[ \t\r\n>*_`~#=+\\-0-9.)a-c\\[\\]():'\"<!/\\\\&;|w@]{0,120}",
+        ".{0,60}",
+    ]
+}
+
+proptest! {
+    #[test]
+    fn should_render_markdown_parsing_back_to_the_same_content(markdown in document()) {
+        assert_round_trip(&markdown, &Default::default());
+    }
+
+    #[test]
+    fn should_render_arbitrary_sources_parsing_back_to_the_same_content(source in source()) {
+        assert_round_trip(&source, &Default::default());
+    }
+
+    #[test]
+    fn should_render_arbitrary_sources_parsing_back_to_the_same_content_with_gfm(
+        source in source()
+    ) {
+        assert_round_trip(&source, &ParseOptions::gfm());
+    }
+}
@@ -0,0 +1,659 @@
+//! These tests are the examples of the extensions taken from the
+//! [GFM specification](https://github.github.com/gfm/).
+mod common;
+
+use common::assert_round_trip;
+use markdown::{ParseOptions, ToHtml, events_with_options, parse_with_options, render};
+
+macro_rules! test {
+    ($name:ident, $options:expr, $markdown:expr, $expected:expr) => {
+        #[test]
+        fn $name() {
+            let options = $options;
+            let document = parse_with_options($markdown, &options);
+            assert_eq!(document.to_html(), $expected);
+            let events = events_with_options($markdown, &options);
+            assert_eq!(render::html(events, &Default::default()), $expected);
+            assert_round_trip($markdown, &options);
+        }
+    };
+}
+
+mod tables {
+    use super::*;
+
+    fn options() -> ParseOptions {
+        ParseOptions {
+            tables: true,
+            ..Default::default()
+        }
+    }
+
+    test!(
+        example_198,
+        options(),
+        r"| foo | bar |
+| --- | --- |
+| baz | bim |",
+        r"<table>
+<thead>
+<tr>
+<th>foo</th>
+<th>bar</th>
+</tr>
+</thead>
+<tbody>
+<tr>
+<td>baz</td>
+<td>bim</td>
+</tr>
+</tbody>
+</table>"
+    );
+    test!(
+        example_199,
+        options(),
+        r"| abc | defghi |
+:-: | -----------:
+bar | baz",
+        r#"<table>
+<thead>
+<tr>
+<th align="center">abc</th>
+<th align="right">defghi</th>
+</tr>
+</thead>
+<tbody>
+<tr>
+<td align="center">bar</td>
+<td align="right">baz</td>
+</tr>
+</tbody>
+</table>"#
+    );
+    test!(
+        example_200,
+        options(),
+        r"| f\|oo  |
+| ------ |
+| b `\|` az |
+| b **\|** im |",
+        r"<table>
+<thead>
+<tr>
+<th>f|oo</th>
+</tr>
+</thead>
+<tbody>
+<tr>
+<td>b <code>|</code> az</td>
+</tr>
+<tr>
+<td>b <strong>|</strong> im</td>
+</tr>
+</tbody>
+</table>"
+    );
+    test!(
+        example_201,
+        options(),
+        r"| abc | def |
+| --- | --- |
+| bar | baz |
+> bar",
+        r"<table>
+<thead>
+<tr>
+<th>abc</th>
+<th>def</th>
+</tr>
+</thead>
+<tbody>
+<tr>
+<td>bar</td>
+<td>baz</td>
+</tr>
+</tbody>
+</table>
+<blockquote>
+<p>bar</p>
+</blockquote>"
+    );
+    test!(
+        example_202,
+        options(),
+        r"| abc | def |
+| --- | --- |
+| bar | baz |
+bar
+
+bar",
+        r"<table>
+<thead>
+<tr>
+<th>abc</th>
+<th>def</th>
+</tr>
+</thead>
+<tbody>
+<tr>
+<td>bar</td>
+<td>baz</td>
+</tr>
+<tr>
+<td>bar</td>
+<td></td>
+</tr>
+</tbody>
+</table>
+<p>bar</p>"
+    );
+    test!(
+        example_203,
+        options(),
+        r"| abc | def |
+| --- |
+| bar |",
+        r"<p>| abc | def |
+| --- |
+| bar |</p>"
+    );
+    test!(
+        example_204,
+        options(),
+        r"| abc | def |
+| --- | --- |
+| bar |
+| bar | baz | boo |",
+        r"<table>
+<thead>
+<tr>
+<th>abc</th>
+<th>def</th>
+</tr>
+</thead>
+<tbody>
+<tr>
+<td>bar</td>
+<td></td>
+</tr>
+<tr>
+<td>bar</td>
+<td>baz</td>
+</tr>
+</tbody>
+</table>"
+    );
+    test!(
+        example_205,
+        options(),
+        r"| abc | def |
+| --- | --- |",
+        r"<table>
+<thead>
+<tr>
+<th>abc</th>
+<th>def</th>
+</tr>
+</thead>
+</table>"
+    );
+    test!(
+        should_not_parse_tables_by_default,
+        ParseOptions::default(),
+        r"| abc |
+| --- |",
+        r"<p>| abc |
+| --- |</p>"
+    );
+    test!(
+        should_interrupt_paragraphs,
+        options(),
+        r"foo
+| abc |
+| --- |
+| bar |
+- baz",
+        r"<p>foo</p>
+<table>
+<thead>
+<tr>
+<th>abc</th>
+</tr>
+</thead>
+<tbody>
+<tr>
+<td>bar</td>
+</tr>
+</tbody>
+</table>
+<ul>
+<li>baz</li>
+</ul>"
+    );
+    test!(
+        should_work_in_containers,
+        options(),
+        r"> | abc |
+> | :-- |
+> bar",
+        r#"<blockquote>
+<table>
+<thead>
+<tr>
+<th align="left">abc</th>
+</tr>
+</thead>
+<tbody>
+<tr>
+<td align="left">bar</td>
+</tr>
+</tbody>
+</table>
+</blockquote>"#
+    );
+    test!(
+        should_not_take_lazy_lines_as_rows,
+        options(),
+        r"> | abc |
+> | --- |
+bar",
+        r"<blockquote>
+<table>
+<thead>
+<tr>
+<th>abc</th>
+</tr>
+</thead>
+</table>
+</blockquote>
+<p>bar</p>"
+    );
+    test!(
+        should_take_delimiter_rows_from_lazy_continuation_lines,
+        options(),
+        r"> abc | def
+--- | ---
+bar",
+        r"<blockquote>
+<table>
+<thead>
+<tr>
+<th>abc</th>
+<th>def</th>
+</tr>
+</thead>
+</table>
+</blockquote>
+<p>bar</p>"
+    );
+    test!(
+        should_take_the_header_from_paragraph_continuation_text,
+        options(),
+        r"foo
+    abc | def
+--- | ---",
+        r"<p>foo</p>
+<table>
+<thead>
+<tr>
+<th>abc</th>
+<th>def</th>
+</tr>
+</thead>
+</table>"
+    );
+    test!(
+        should_continue_blocks_after_tables,
+        options(),
+        r"| abc |
+| --- |
+-
+- bar",
+        r"<table>
+<thead>
+<tr>
+<th>abc</th>
+</tr>
+</thead>
+</table>
+<ul>
+<li></li>
+<li>bar</li>
+</ul>"
+    );
+}
+
+mod strikethrough {
+    use super::*;
+
+    fn options() -> ParseOptions {
+        ParseOptions {
+            strikethrough: true,
+            ..Default::default()
+        }
+    }
+
+    test!(
+        example_491,
+        options(),
+        r"~~Hi~~ Hello, ~there~ world!",
+        r"<p><del>Hi</del> Hello, <del>there</del> world!</p>"
+    );
+    test!(
+        example_492,
+        options(),
+        r"This ~~has a
+
+new paragraph~~.",
+        r"<p>This ~~has a</p>
+<p>new paragraph~~.</p>"
+    );
+    test!(
+        example_493,
+        options(),
+        r"This will ~~~not~~~ strike.",
+        r"<p>This will ~~~not~~~ strike.</p>"
+    );
+    test!(
+        should_not_match_runs_of_different_lengths,
+        options(),
+        r"~~foo~ bar~",
+        r"<p>~~foo~ bar~</p>"
+    );
+    test!(
+        should_nest_emphasis,
+        options(),
+        r"~~*foo* **bar**~~ *~baz~*",
+        r"<p><del><em>foo</em> <strong>bar</strong></del> <em><del>baz</del></em></p>"
+    );
+    test!(
+        should_still_parse_fenced_code_with_tildes,
+        options(),
+        r"~~~
+foo
+~~~",
+        r"<pre><code>foo
+</code></pre>"
+    );
+    test!(
+        should_be_text_without_the_extension,
+        ParseOptions::default(),
+        r"~~foo~~",
+        r"<p>~~foo~~</p>"
+    );
+}
+
+/// The checkboxes are rendered the way the reference implementation renders them, which differs
+/// from the examples of the specification in the order of the attributes and the closing slash.
+mod task_lists {
+    use super::*;
+
+    fn options() -> ParseOptions {
+        ParseOptions {
+            task_lists: true,
+            ..Default::default()
+        }
+    }
+
+    test!(
+        example_279,
+        options(),
+        r"- [ ] foo
+- [x] bar",
+        r#"<ul>
+<li><input type="checkbox" disabled="" /> foo</li>
+<li><input type="checkbox" checked="" disabled="" /> bar</li>
+</ul>"#
+    );
+    test!(
+        example_280,
+        options(),
+        r"- [x] foo
+  - [ ] bar
+  - [x] baz
+- [ ] bim",
+        r#"<ul>
+<li><input type="checkbox" checked="" disabled="" /> foo
+<ul>
+<li><input type="checkbox" disabled="" /> bar</li>
+<li><input type="checkbox" checked="" disabled="" /> baz</li>
+</ul>
+</li>
+<li><input type="checkbox" disabled="" /> bim</li>
+</ul>"#
+    );
+    test!(
+        should_put_the_checkbox_before_the_paragraphs_of_loose_items,
+        options(),
+        r"1. [X] foo
+
+   bar",
+        r#"<ol>
+<li><input type="checkbox" checked="" disabled="" /> 
+<p>foo</p>
+<p>bar</p>
+</li>
+</ol>"#
+    );
+    test!(
+        should_require_whitespace_after_the_checkbox,
+        options(),
+        r"- [ ]foo
+- [x]",
+        r#"<ul>
+<li>[ ]foo</li>
+<li><input type="checkbox" checked="" disabled="" /> </li>
+</ul>"#
+    );
+    test!(
+        should_only_follow_the_list_marker,
+        options(),
+        r"-
+  [ ] foo
+- > [ ] bar",
+        r"<ul>
+<li>[ ] foo</li>
+<li>
+<blockquote>
+<p>[ ] bar</p>
+</blockquote>
+</li>
+</ul>"
+    );
+    test!(
+        should_be_text_without_the_extension,
+        ParseOptions::default(),
+        r"- [ ] foo",
+        r"<ul>
+<li>[ ] foo</li>
+</ul>"
+    );
+}
+
+mod autolinks {
+    use super::*;
+
+    fn options() -> ParseOptions {
+        ParseOptions {
+            autolinks: true,
+            ..Default::default()
+        }
+    }
+
+    test!(
+        example_621,
+        options(),
+        r"www.commonmark.org",
+        r#"<p><a href="http://www.commonmark.org">www.commonmark.org</a></p>"#
+    );
+    test!(
+        example_622,
+        options(),
+        r"Visit www.commonmark.org/help for more information.",
+        r#"<p>Visit <a href="http://www.commonmark.org/help">www.commonmark.org/help</a> for more information.</p>"#
+    );
+    test!(
+        example_623,
+        options(),
+        r"Visit www.commonmark.org.
+
+Visit www.commonmark.org/a.b.",
+        r#"<p>Visit <a href="http://www.commonmark.org">www.commonmark.org</a>.</p>
+<p>Visit <a href="http://www.commonmark.org/a.b">www.commonmark.org/a.b</a>.</p>"#
+    );
+    test!(
+        example_624,
+        options(),
+        r"www.google.com/search?q=Markup+(business)
+
+www.google.com/search?q=Markup+(business)))
+
+(www.google.com/search?q=Markup+(business))
+
+(www.google.com/search?q=Markup+(business)",
+        r#"<p><a href="http://www.google.com/search?q=Markup+(business)">www.google.com/search?q=Markup+(business)</a></p>
+<p><a href="http://www.google.com/search?q=Markup+(business)">www.google.com/search?q=Markup+(business)</a>))</p>
+<p>(<a href="http://www.google.com/search?q=Markup+(business)">www.google.com/search?q=Markup+(business)</a>)</p>
+<p>(<a href="http://www.google.com/search?q=Markup+(business)">www.google.com/search?q=Markup+(business)</a></p>"#
+    );
+    test!(
+        example_625,
+        options(),
+        r"www.google.com/search?q=(business))+ok",
+        r#"<p><a href="http://www.google.com/search?q=(business))+ok">www.google.com/search?q=(business))+ok</a></p>"#
+    );
+    test!(
+        example_626,
+        options(),
+        r"www.google.com/search?q=commonmark&hl=en
+
+www.google.com/search?q=commonmark&hl;",
+        r#"<p><a href="http://www.google.com/search?q=commonmark&amp;hl=en">www.google.com/search?q=commonmark&amp;hl=en</a></p>
+<p><a href="http://www.google.com/search?q=commonmark">www.google.com/search?q=commonmark</a>&amp;hl;</p>"#
+    );
+    test!(
+        example_627,
+        options(),
+        r"www.commonmark.org/he<lp",
+        r#"<p><a href="http://www.commonmark.org/he">www.commonmark.org/he</a>&lt;lp</p>"#
+    );
+    test!(
+        example_628,
+        options(),
+        r"http://commonmark.org
+
+(Visit https://encrypted.google.com/search?q=Markup+(business))",
+        r#"<p><a href="http://commonmark.org">http://commonmark.org</a></p>
+<p>(Visit <a href="https://encrypted.google.com/search?q=Markup+(business)">https://encrypted.google.com/search?q=Markup+(business)</a>)</p>"#
+    );
+    test!(
+        example_629,
+        options(),
+        r"foo@bar.baz",
+        r#"<p><a href="mailto:foo@bar.baz">foo@bar.baz</a></p>"#
+    );
+    test!(
+        example_630,
+        options(),
+        r"hello@mail+xyz.example isn't valid, but hello+xyz@mail.example is.",
+        r#"<p>hello@mail+xyz.example isn't valid, but <a href="mailto:hello+xyz@mail.example">hello+xyz@mail.example</a> is.</p>"#
+    );
+    test!(
+        example_631,
+        options(),
+        r"a.b-c_d@a.b
+
+a.b-c_d@a.b.
+
+a.b-c_d@a.b-
+
+a.b-c_d@a.b_",
+        r#"<p><a href="mailto:a.b-c_d@a.b">a.b-c_d@a.b</a></p>
+<p><a href="mailto:a.b-c_d@a.b">a.b-c_d@a.b</a>.</p>
+<p>a.b-c_d@a.b-</p>
+<p>a.b-c_d@a.b_</p>"#
+    );
+    test!(
+        should_reject_underscores_in_the_last_two_segments_of_the_domain,
+        options(),
+        r"www._foo.bar.baz www.foo._bar.baz http://foo.bar_baz",
+        r#"<p><a href="http://www._foo.bar.baz">www._foo.bar.baz</a> www.foo._bar.baz http://foo.bar_baz</p>"#
+    );
+    test!(
+        should_only_start_after_whitespace_or_delimiters,
+        options(),
+        r"xwww.foo.bar *www.foo.bar* (http://foo.bar)",
+        r#"<p>xwww.foo.bar <em><a href="http://www.foo.bar">www.foo.bar</a></em> (<a href="http://foo.bar">http://foo.bar</a>)</p>"#
+    );
+    test!(
+        should_not_be_linked_within_links,
+        options(),
+        r"[www.foo.bar](/url) [see http://foo.bar]",
+        r#"<p><a href="/url">www.foo.bar</a> [see <a href="http://foo.bar">http://foo.bar</a>]</p>"#
+    );
+    test!(
+        should_decode_escapes_and_character_references,
+        options(),
+        r"foo\.bar\@baz\.com https://a.com/?a=1&amp;b=2\_c",
+        r#"<p><a href="mailto:foo.bar@baz.com">foo.bar@baz.com</a> <a href="https://a.com/?a=1&amp;b=2_c">https://a.com/?a=1&amp;b=2_c</a></p>"#
+    );
+    test!(
+        should_be_text_without_the_extension,
+        ParseOptions::default(),
+        r"www.commonmark.org",
+        r"<p>www.commonmark.org</p>"
+    );
+}
+
+mod tag_filter {
+    use super::*;
+    use markdown::{ToCommonMark, events, parse, render::HtmlOptions};
+
+    fn options() -> HtmlOptions {
+        HtmlOptions {
+            tag_filter: true,
+            ..Default::default()
+        }
+    }
+
+    fn assert_filtered(markdown: &str, expected: &str) {
+        assert_eq!(parse(markdown).to_html_with_options(&options()), expected);
+        assert_eq!(render::html(events(markdown), &options()), expected);
+    }
+
+    #[test]
+    fn example_652() {
+        assert_filtered(
+            r"<strong> <title> <style> <em>
+
+<blockquote>
+  <xmp> is disallowed.  <XMP> is also disallowed.
+</blockquote>",
+            r"<p><strong> &lt;title> &lt;style> <em></p>
+<blockquote>
+  &lt;xmp> is disallowed.  &lt;XMP> is also disallowed.
+</blockquote>",
+        );
+    }
+
+    #[test]
+    fn should_filter_closing_tags_and_tags_with_attributes() {
+        assert_filtered(
+            "<script src=\"x.js\"></script>\n\nfoo <iframe/> </textarea>",
+            "&lt;script src=\"x.js\">&lt;/script>\n<p>foo &lt;iframe/> &lt;/textarea></p>",
+        );
+    }
+
+    #[test]
+    fn should_keep_tags_that_only_start_like_disallowed_ones() {
+        assert_filtered("foo <scripts> <titles/>", "<p>foo <scripts> <titles/></p>");
+    }
+
+    #[test]
+    fn should_keep_the_original_html_in_the_document() {
+        let markdown = "<script>\nalert(1)\n</script>";
+        assert_eq!(parse(markdown).to_html(), markdown);
+        assert_eq!(parse(markdown).to_commonmark(), format!("{markdown}\n"));
+    }
+}
@@ -0,0 +1,196 @@
+//! Inputs that take quadratic time, or worse, to parse without care, like the pathological tests of
+//! [cmark](https://github.com/commonmark/cmark/blob/master/test/pathological_tests.py).
+use markdown::{ParseOptions, ToHtml, events_with_options, parse_with_options, render};
+use std::time::{Duration, Instant};
+
+/// How long parsing and rendering any of the inputs can take, with or without the extensions, which
+/// is far from what a quadratic implementation takes even on a fast machine, and far above what a
+/// linear one takes on a slow one, even when the tests share it.
+const TIME_LIMIT: Duration = Duration::from_secs(20);
+
+macro_rules! test {
+    ($name:ident, $markdown:expr) => {
+        #[test]
+        fn $name() {
+            let markdown: String = $markdown;
+            let start = Instant::now();
+            for options in [ParseOptions::default(), ParseOptions::gfm()] {
+                let html = parse_with_options(&markdown, &options).to_html();
+                let events = events_with_options(&markdown, &options);
+                assert_eq!(render::html(events, &Default::default()), html);
+            }
+            let elapsed = start.elapsed();
+            assert!(elapsed < TIME_LIMIT, "took {elapsed:?}");
+        }
+    };
+}
+
+mod links {
+    use super::*;
+
+    test!(should_parse_unclosed_inline_links, "[a](".repeat(16000));
+    test!(should_parse_unclosed_images, "![a](".repeat(16000));
+    test!(should_parse_undefined_references, "[a]".repeat(16000));
+    test!(
+        should_parse_unclosed_brackets,
+        "[".repeat(16000) + &"]".repeat(16000)
+    );
+    test!(
+        should_parse_links_within_unclosed_brackets,
+        "[".repeat(16000) + &"[a](b)".repeat(16000)
+    );
+    test!(
+        should_parse_nested_parentheses_in_destinations,
+        "[a](".to_string() + &"(".repeat(16000)
+    );
+    test!(should_parse_links_on_many_lines, "[a](b)\n".repeat(4000));
+    test!(
+        should_parse_many_links_on_a_line,
+        "[a](b) text here ".repeat(16000)
+    );
+    test!(
+        should_parse_long_link_texts,
+        "[".to_string() + &"www.a.b ".repeat(16000) + "](c)"
+    );
+    test!(
+        should_parse_many_references_to_many_definitions,
+        (0..4000)
+            .map(|index| format!("[{index}]: /{index}\n"))
+            .chain((0..4000).map(|index| format!("[{index}]\n")))
+            .collect()
+    );
+}
+
+mod autolinks {
+    use super::*;
+
+    test!(
+        should_trim_many_closing_parentheses,
+        "www.a.b/".to_string() + &")".repeat(40000)
+    );
+    test!(
+        should_trim_many_closing_parentheses_after_schemes,
+        "http://a.b/".to_string() + &")".repeat(40000)
+    );
+}
+
+mod character_references {
+    use super::*;
+
+    test!(
+        should_parse_unterminated_references,
+        "&a".repeat(500000) + ";"
+    );
+    test!(
+        should_parse_unterminated_references_in_destinations,
+        "[a](".to_string() + &"&a".repeat(500000) + ";)"
+    );
+}
+
+mod emphasis {
+    use super::*;
+
+    test!(
+        should_parse_nested_emphasis,
+        "*a ".repeat(800) + &" a*".repeat(800)
+    );
+    test!(
+        should_parse_nested_strong_emphasis,
+        "**a ".repeat(800) + &" a**".repeat(800)
+    );
+    test!(
+        should_parse_nested_images,
+        "![a ".repeat(800) + &" a](b)".repeat(800)
+    );
+    test!(
+        should_parse_deeply_nested_emphasis,
+        "*a ".repeat(40000) + &" a*".repeat(40000)
+    );
+    test!(
+        should_parse_deeply_nested_images,
+        "![a ".repeat(40000) + &" a](b)".repeat(40000)
+    );
+    test!(
+        should_parse_deeply_nested_emphasis_and_images,
+        "![*a ".repeat(20000) + &" a*](b)".repeat(20000)
+    );
+    test!(should_parse_many_emphasis, "*a* ".repeat(10000));
+    test!(
+        should_parse_many_closers_with_no_openers,
+        "a_ ".repeat(30000)
+    );
+    test!(
+        should_parse_many_openers_with_no_closers,
+        "_a ".repeat(30000)
+    );
+    test!(
+        should_parse_mismatched_openers_and_closers,
+        "*a_ ".repeat(30000)
+    );
+    test!(
+        should_parse_openers_and_closers_multiple_of_3,
+        "a**b".to_string() + &"c* ".repeat(30000)
+    );
+    test!(
+        should_parse_closers_leaving_unused_delimiters,
+        "a**b c* ".repeat(10000)
+    );
+    test!(
+        should_parse_link_openers_and_emphasis_closers,
+        "[ a_".repeat(30000)
+    );
+}
+
+mod raw_html {
+    use super::*;
+
+    test!(should_parse_unclosed_comments, "a <!--".repeat(10000));
+    test!(
+        should_parse_unclosed_processing_instructions,
+        "a <?".repeat(10000)
+    );
+    test!(should_parse_unclosed_declarations, "a <!A".repeat(10000));
+    test!(
+        should_parse_unclosed_cdata_sections,
+        "a <![CDATA[".repeat(10000)
+    );
+}
+
+mod strikethrough {
+    use super::*;
+
+    test!(should_parse_many_strikethroughs, "~~a~~ ".repeat(10000));
+    test!(
+        should_parse_many_openers_with_no_closers,
+        "~~a ".repeat(30000)
+    );
+    test!(
+        should_parse_mismatched_openers_and_closers,
+        "~a~~ ".repeat(30000)
+    );
+}
+
+mod containers {
+    use super::*;
+
+    test!(
+        should_parse_block_quotes_continued_lazily,
+        "> a\nb\n".repeat(4000)
+    );
+    test!(
+        should_parse_nested_block_quotes_continued_lazily,
+        "> > a\nb\n".repeat(4000)
+    );
+    test!(
+        should_parse_list_items_continued_lazily,
+        "- a\n".to_string() + &"  a\nb\n".repeat(4000)
+    );
+    test!(
+        should_parse_lazy_continuation_lines_in_block_quotes,
+        "> a\n".to_string() + &"b\n".repeat(4000)
+    );
+    test!(
+        should_parse_lazy_continuation_lines_in_list_items,
+        "- a\n".to_string() + &"b\n".repeat(4000)
+    );
+}
@@ -0,0 +1,25 @@
+//! Reconstruction of arbitrary sources from the segments of their documents.
+use markdown::{ParseOptions, Segments, parse, parse_with_options};
+use proptest::prelude::*;
+
+/// A source made mostly of the characters that are meaningful in markdown, so that it has all kinds
+/// of nodes, the ones of the extensions included, or an arbitrary source.
+fn source() -> impl Strategy<Value = String> {
+    prop_oneof![
+        "[ \t\r\n>*_`~#=+\\-0-9.)a-cwx\\[\\]():'\"<!/\\\\&;|@]{0,120}",
+        ".{0,60}",
+    ]
+}
+
+proptest! {
+    #[test]
+    fn should_reproduce_the_source(source in source()) {
+        prop_assert_eq!(parse(&source).segments().collect::<String>(), source);
+    }
+
+    #[test]
+    fn should_reproduce_the_source_with_gfm(source in source()) {
+        let document = parse_with_options(&source, &ParseOptions::gfm());
+        prop_assert_eq!(document.segments().collect::<String>(), source);
+    }
+}
@@ -1,5 +1,8 @@
 //! These tests are the examples taken from the [specification](https://spec.commonmark.org/0.31.2/).
-use markdown::{ToHtml, parse};
+mod common;
+
+use common::assert_round_trip;
+use markdown::{ToHtml, events, parse, render};
 
 // TODO: put all the tests.
 macro_rules! test {
@@ -8,6 +11,9 @@ macro_rules! test {
         fn $name() {
             let document = parse($markdown);
             assert_eq!(document.to_html(), $expected);
+            let events = events($markdown);
+            assert_eq!(render::html(events, &Default::default()), $expected);
+            assert_round_trip($markdown, &Default::default());
         }
     };
 }
@@ -30,6 +36,21 @@ mod atx_heading {
 <h5>foo</h5>
 <h6>foo</h6>"
     );
+    test!(example_63, r"####### foo", r"<p>####### foo</p>");
+    test!(
+        example_64,
+        r"#5 bolt
+
+#hashtag",
+        r"<p>#5 bolt</p>
+<p>#hashtag</p>"
+    );
+    test!(example_65, r"\## foo", r"<p>## foo</p>");
+    test!(
+        example_66,
+        r"# foo *bar* \*baz\*",
+        r"<h1>foo <em>bar</em> *baz*</h1>"
+    );
     test!(
         exmple_67,
         r"#                  foo                     ",
@@ -43,6 +64,19 @@ mod atx_heading {
         r"<h3>foo</h3>
 <h2>foo</h2>
 <h1>foo</h1>"
+    );
+    test!(
+        example_69,
+        r"    # foo",
+        r"<pre><code># foo
+</code></pre>"
+    );
+    test!(
+        example_70,
+        r"foo
+    # bar",
+        r"<p>foo
+# bar</p>"
     );
     test!(
         example_71,
@@ -62,16 +96,15 @@ mod atx_heading {
     test!(example_74, r"### foo ### b", r"<h3>foo ### b</h3>");
     test!(example_75, r"# foo#", r"<h1>foo#</h1>");
 
-    /* test!(
+    test!(
         example_76,
         r"### foo \###
-    ## foo #\##
-    # foo \#",
+## foo #\##
+# foo \#",
         r"<h3>foo ###</h3>
-    <h2>foo ###</h2>
-    <h1>foo #</h1>"
-    ); */
-
+<h2>foo ###</h2>
+<h1>foo #</h1>"
+    );
     test!(
         example_77,
         r"****
@@ -82,6 +115,15 @@ mod atx_heading {
 <hr />"
     );
 
+    test!(
+        example_78,
+        r"Foo bar
+# baz
+Bar foo",
+        r"<p>Foo bar</p>
+<h1>baz</h1>
+<p>Bar foo</p>"
+    );
     test!(
         exmple_79,
         r"##
@@ -93,7 +135,176 @@ mod atx_heading {
     );
 }
 
-/* mod blank_line {
+mod autolink {
+    use super::*;
+
+    test!(
+        example_593,
+        r"<http://foo.bar.baz>",
+        r#"<p><a href="http://foo.bar.baz">http://foo.bar.baz</a></p>"#
+    );
+    test!(
+        example_594,
+        r"<http://foo.bar.baz/test?q=hello&id=22&boolean>",
+        r#"<p><a href="http://foo.bar.baz/test?q=hello&amp;id=22&amp;boolean">http://foo.bar.baz/test?q=hello&amp;id=22&amp;boolean</a></p>"#
+    );
+    test!(
+        example_595,
+        r"<irc://foo.bar:2233/baz>",
+        r#"<p><a href="irc://foo.bar:2233/baz">irc://foo.bar:2233/baz</a></p>"#
+    );
+    test!(
+        example_596,
+        r"<MAILTO:FOO@BAR.BAZ>",
+        r#"<p><a href="MAILTO:FOO@BAR.BAZ">MAILTO:FOO@BAR.BAZ</a></p>"#
+    );
+    test!(
+        example_597,
+        r"<a+b+c:d>",
+        r#"<p><a href="a+b+c:d">a+b+c:d</a></p>"#
+    );
+    test!(
+        example_598,
+        r"<made-up-scheme://foo,bar>",
+        r#"<p><a href="made-up-scheme://foo,bar">made-up-scheme://foo,bar</a></p>"#
+    );
+    test!(
+        example_599,
+        r"<http://../>",
+        r#"<p><a href="http://../">http://../</a></p>"#
+    );
+    test!(
+        example_600,
+        r"<localhost:5001/foo>",
+        r#"<p><a href="localhost:5001/foo">localhost:5001/foo</a></p>"#
+    );
+    test!(
+        example_601,
+        r"<http://foo.bar/baz bim>",
+        r"<p>&lt;http://foo.bar/baz bim&gt;</p>"
+    );
+    test!(
+        example_602,
+        r"<http://example.com/\[\>",
+        r#"<p><a href="http://example.com/%5C%5B%5C">http://example.com/\[\</a></p>"#
+    );
+    test!(
+        example_603,
+        r"<foo@bar.example.com>",
+        r#"<p><a href="mailto:foo@bar.example.com">foo@bar.example.com</a></p>"#
+    );
+    test!(
+        example_604,
+        r"<foo+special@Bar.baz-bar0.com>",
+        r#"<p><a href="mailto:foo+special@Bar.baz-bar0.com">foo+special@Bar.baz-bar0.com</a></p>"#
+    );
+    test!(
+        example_605,
+        r"<foo\+@bar.example.com>",
+        r"<p>&lt;foo+@bar.example.com&gt;</p>"
+    );
+    test!(example_606, r"<>", r"<p>&lt;&gt;</p>");
+    test!(
+        example_607,
+        r"< http://foo.bar >",
+        r"<p>&lt; http://foo.bar &gt;</p>"
+    );
+    test!(example_608, r"<m:abc>", r"<p>&lt;m:abc&gt;</p>");
+    test!(example_609, r"<foo.bar.baz>", r"<p>&lt;foo.bar.baz&gt;</p>");
+    test!(
+        example_610,
+        r"http://example.com",
+        r"<p>http://example.com</p>"
+    );
+    test!(
+        example_611,
+        r"foo@bar.example.com",
+        r"<p>foo@bar.example.com</p>"
+    );
+}
+
+mod backslash_escape {
+    use super::*;
+
+    test!(
+        example_12,
+        r#"\!\"\#\$\%\&\'\(\)\*\+\,\-\.\/\:\;\<\=\>\?\@\[\\\]\^\_\`\{\|\}\~"#,
+        r"<p>!&quot;#$%&amp;'()*+,-./:;&lt;=&gt;?@[\]^_`{|}~</p>"
+    );
+    test!(example_13, r"\	\A\a\ \3\φ\«", r"<p>\	\A\a\ \3\φ\«</p>");
+    test!(
+        example_14,
+        r#"\*not emphasized*
+\<br/> not a tag
+\[not a link](/foo)
+\`not code`
+1\. not a list
+\* not a list
+\# not a heading
+\[foo]: /url "not a reference"
+\&ouml; not a character entity"#,
+        r"<p>*not emphasized*
+&lt;br/&gt; not a tag
+[not a link](/foo)
+`not code`
+1. not a list
+* not a list
+# not a heading
+[foo]: /url &quot;not a reference&quot;
+&amp;ouml; not a character entity</p>"
+    );
+    test!(example_15, r"\\*emphasis*", r"<p>\<em>emphasis</em></p>");
+    test!(
+        example_16,
+        r"foo\
+bar",
+        r"<p>foo<br />
+bar</p>"
+    );
+    test!(example_17, r"`` \[\` ``", r"<p><code>\[\`</code></p>");
+    test!(
+        example_18,
+        r"    \[\]",
+        r"<pre><code>\[\]
+</code></pre>"
+    );
+    test!(
+        example_19,
+        r"~~~
+\[\]
+~~~",
+        r"<pre><code>\[\]
+</code></pre>"
+    );
+    test!(
+        example_20,
+        r"<http://example.com?find=\*>",
+        r#"<p><a href="http://example.com?find=%5C*">http://example.com?find=\*</a></p>"#
+    );
+    test!(example_21, r#"<a href="/bar\/)">"#, r#"<a href="/bar\/)">"#);
+    test!(
+        example_22,
+        r#"[foo](/bar\* "ti\*tle")"#,
+        r#"<p><a href="/bar*" title="ti*tle">foo</a></p>"#
+    );
+    test!(
+        example_23,
+        r#"[foo]
+
+[foo]: /bar\* "ti\*tle""#,
+        r#"<p><a href="/bar*" title="ti*tle">foo</a></p>"#
+    );
+    test!(
+        example_24,
+        r"``` foo\+bar
+foo
+```",
+        r#"<pre><code class="language-foo+bar">foo
+</code></pre>"#
+    );
+}
+
+mod blank_line {
     use super::*;
 
     test!(
@@ -109,9 +320,104 @@ aaa
         r"<p>aaa</p>
 <h1>aaa</h1>"
     );
-} */
+}
+
+mod entity_reference {
+    use super::*;
+
+    test!(
+        example_25,
+        r"&nbsp; &amp; &copy; &AElig; &Dcaron;
+&frac34; &HilbertSpace; &DifferentialD;
+&ClockwiseContourIntegral; &ngE;",
+        r"<p>  &amp; © Æ Ď
+¾ ℋ ⅆ
+∲ ≧̸</p>"
+    );
+    test!(example_26, r"&#35; &#1234; &#992; &#0;", r"<p># Ӓ Ϡ �</p>");
+    test!(example_27, r"&#X22; &#XD06; &#xcab;", r"<p>&quot; ആ ಫ</p>");
+    test!(
+        example_28,
+        r"&nbsp &x; &#; &#x;
+&#87654321;
+&#abcdef0;
+&ThisIsNotDefined; &hi?;",
+        r"<p>&amp;nbsp &amp;x; &amp;#; &amp;#x;
+&amp;#87654321;
+&amp;#abcdef0;
+&amp;ThisIsNotDefined; &amp;hi?;</p>"
+    );
+    test!(example_29, r"&copy", r"<p>&amp;copy</p>");
+    test!(example_30, r"&MadeUpEntity;", r"<p>&amp;MadeUpEntity;</p>");
+    test!(
+        example_31,
+        r#"<a href="&ouml;&ouml;.html">"#,
+        r#"<a href="&ouml;&ouml;.html">"#
+    );
+    test!(
+        example_32,
+        r#"[foo](/f&ouml;&ouml; "f&ouml;&ouml;")"#,
+        r#"<p><a href="/f%C3%B6%C3%B6" title="föö">foo</a></p>"#
+    );
+    test!(
+        example_33,
+        r#"[foo]
+
+[foo]: /f&ouml;&ouml; "f&ouml;&ouml;""#,
+        r#"<p><a href="/f%C3%B6%C3%B6" title="föö">foo</a></p>"#
+    );
+    test!(
+        example_34,
+        r"``` f&ouml;&ouml;
+foo
+```",
+        r#"<pre><code class="language-föö">foo
+</code></pre>"#
+    );
+    test!(
+        example_35,
+        r"`f&ouml;&ouml;`",
+        r"<p><code>f&amp;ouml;&amp;ouml;</code></p>"
+    );
+    test!(
+        example_36,
+        r"    f&ouml;f&ouml;",
+        r"<pre><code>f&amp;ouml;f&amp;ouml;
+</code></pre>"
+    );
+    test!(
+        example_37,
+        r"&#42;foo&#42;
+*foo*",
+        r"<p>*foo*
+<em>foo</em></p>"
+    );
+    test!(
+        example_38,
+        r"&#42; foo
+
+* foo",
+        r"<p>* foo</p>
+<ul>
+<li>foo</li>
+</ul>"
+    );
+    test!(
+        example_39,
+        r"foo&#10;&#10;bar",
+        r"<p>foo
 
-/* mod fenced_code {
+bar</p>"
+    );
+    test!(example_40, r"&#9;foo", r"<p>	foo</p>");
+    test!(
+        example_41,
+        r"[a](url &quot;tit&quot;)",
+        r"<p>[a](url &quot;tit&quot;)</p>"
+    );
+}
+
+mod fenced_code {
     use super::*;
 
     test!(
@@ -193,133 +499,1651 @@ aaa",
 aaa
 </code></pre>"
     );
-}*/
+    test!(
+        example_128,
+        r"> ```
+> aaa
 
-mod html {
-    use super::*;
+bbb",
+        r"<blockquote>
+<pre><code>aaa
+</code></pre>
+</blockquote>
+<p>bbb</p>"
+    );
+    test!(
+        example_129,
+        r"```
 
+  
+```",
+        r"<pre><code>
+  
+</code></pre>"
+    );
     test!(
-        example_150,
-        r" <div>
-  *hello*
-         <foo><a>",
-        r" <div>
-  *hello*
-         <foo><a>"
+        example_130,
+        r"```
+```",
+        r"<pre><code></code></pre>"
     );
     test!(
-        example_151,
-        r"</div>
-*foo*",
-        r"</div>
-*foo*"
+        example_134,
+        r"    ```
+    aaa
+    ```",
+        r"<pre><code>```
+aaa
+```
+</code></pre>"
     );
     test!(
-        example_153,
-        r#"<div id="foo"
-  class="bar">
-</div>"#,
-        r#"<div id="foo"
-  class="bar">
-</div>"#
+        example_135,
+        r"```
+aaa
+  ```",
+        r"<pre><code>aaa
+</code></pre>"
     );
     test!(
-        example_154,
-        r#"<div id="foo" class="bar
-  baz">
-</div>"#,
-        r#"<div id="foo" class="bar
-  baz">
-</div>"#
+        example_136,
+        r"   ```
+aaa
+  ```",
+        r"<pre><code>aaa
+</code></pre>"
     );
     test!(
-        example_156,
-        r#"<div id="foo"
-*hi*"#,
-        r#"<div id="foo"
-*hi*"#
+        example_137,
+        r"```
+aaa
+    ```",
+        r"<pre><code>aaa
+    ```
+</code></pre>"
     );
     test!(
-        example_157,
-        r#"<div class
-foo"#,
-        r#"<div class
-foo"#
+        example_138,
+        r"``` ```
+aaa",
+        r"<p><code> </code>
+aaa</p>"
     );
     test!(
-        example_158,
-        r#"<div *???-&&&-<---
-*foo*"#,
-        r#"<div *???-&&&-<---
-*foo*"#
+        example_139,
+        r"~~~~~~
+aaa
+~~~ ~~",
+        r"<pre><code>aaa
+~~~ ~~
+</code></pre>"
     );
     test!(
-        example_159,
-        r#"<div><a href="bar">*foo*</a></div>"#,
-        r#"<div><a href="bar">*foo*</a></div>"#
+        example_140,
+        r"foo
+```
+bar
+```
+baz",
+        r"<p>foo</p>
+<pre><code>bar
+</code></pre>
+<p>baz</p>"
     );
     test!(
-        example_160,
-        r#"<table><tr><td>
-foo
-</td></tr></table>"#,
-        r#"<table><tr><td>
-foo
-</td></tr></table>"#
+        example_141,
+        r"foo
+---
+~~~
+bar
+~~~
+# baz",
+        r"<h2>foo</h2>
+<pre><code>bar
+</code></pre>
+<h1>baz</h1>"
     );
     test!(
-        example_161,
-        r#"<div></div>
-``` c
-int x = 33;
-```"#,
-        r#"<div></div>
-``` c
-int x = 33;
-```"#
+        example_142,
+        r"```ruby
+def foo(x)
+  return 3
+end
+```",
+        r#"<pre><code class="language-ruby">def foo(x)
+  return 3
+end
+</code></pre>"#
     );
     test!(
-        example_162,
-        r#"<a href="foo">
-*bar*
-</a>"#,
-        r#"<a href="foo">
-*bar*
-</a>"#
+        example_143,
+        r"~~~~    ruby startline=3 $%@#$
+def foo(x)
+  return 3
+end
+~~~~~~~",
+        r#"<pre><code class="language-ruby">def foo(x)
+  return 3
+end
+</code></pre>"#
     );
     test!(
-        example_163,
-        r#"<Warning>
-*bar*
-</Warning>"#,
-        r#"<Warning>
-*bar*
-</Warning>"#
+        example_144,
+        r"````;
+````",
+        r#"<pre><code class="language-;"></code></pre>"#
     );
     test!(
-        example_164,
-        r#"<i class="foo">
-*bar*
-</i>"#,
-        r#"<i class="foo">
-*bar*
-</i>"#
+        example_145,
+        r"``` aa ```
+foo",
+        r"<p><code>aa</code>
+foo</p>"
     );
     test!(
-        example_165,
-        r#"</ins>
-*bar*"#,
-        r#"</ins>
-*bar*"#
+        example_146,
+        r"~~~ aa ``` ~~~
+foo
+~~~",
+        r#"<pre><code class="language-aa">foo
+</code></pre>"#
     );
     test!(
-        example_166,
-        r#"<del>
-*foo*
-</del>"#,
-        r#"<del>
-*foo*
-</del>"#
+        example_147,
+        r"```
+``` aaa
+```",
+        r"<pre><code>``` aaa
+</code></pre>"
+    );
+}
+
+mod block_quote {
+    use super::*;
+
+    test!(
+        example_228,
+        r"> # Foo
+> bar
+> baz",
+        r"<blockquote>
+<h1>Foo</h1>
+<p>bar
+baz</p>
+</blockquote>"
+    );
+    test!(
+        example_229,
+        r"># Foo
+>bar
+> baz",
+        r"<blockquote>
+<h1>Foo</h1>
+<p>bar
+baz</p>
+</blockquote>"
+    );
+    test!(
+        example_230,
+        r"   > # Foo
+   > bar
+ > baz",
+        r"<blockquote>
+<h1>Foo</h1>
+<p>bar
+baz</p>
+</blockquote>"
+    );
+    test!(
+        example_231,
+        r"    > # Foo
+    > bar
+    > baz",
+        r"<pre><code>&gt; # Foo
+&gt; bar
+&gt; baz
+</code></pre>"
+    );
+    test!(
+        example_232,
+        r"> # Foo
+> bar
+baz",
+        r"<blockquote>
+<h1>Foo</h1>
+<p>bar
+baz</p>
+</blockquote>"
+    );
+    test!(
+        example_233,
+        r"> bar
+baz
+> foo",
+        r"<blockquote>
+<p>bar
+baz
+foo</p>
+</blockquote>"
+    );
+    test!(
+        example_234,
+        r"> foo
+---",
+        r"<blockquote>
+<p>foo</p>
+</blockquote>
+<hr />"
+    );
+    test!(
+        example_235,
+        r"> - foo
+- bar",
+        r"<blockquote>
+<ul>
+<li>foo</li>
+</ul>
+</blockquote>
+<ul>
+<li>bar</li>
+</ul>"
+    );
+    test!(
+        example_236,
+        r">     foo
+    bar",
+        r"<blockquote>
+<pre><code>foo
+</code></pre>
+</blockquote>
+<pre><code>bar
+</code></pre>"
+    );
+    test!(
+        example_237,
+        r"> ```
+foo
+```",
+        r"<blockquote>
+<pre><code></code></pre>
+</blockquote>
+<p>foo</p>
+<pre><code></code></pre>"
+    );
+    test!(
+        example_238,
+        r"> foo
+    - bar",
+        r"<blockquote>
+<p>foo
+- bar</p>
+</blockquote>"
+    );
+    test!(
+        example_239,
+        r">",
+        r"<blockquote>
+</blockquote>"
+    );
+    test!(
+        example_240,
+        r">
+>  
+> ",
+        r"<blockquote>
+</blockquote>"
+    );
+    test!(
+        example_241,
+        r">
+> foo
+>  ",
+        r"<blockquote>
+<p>foo</p>
+</blockquote>"
+    );
+    test!(
+        example_242,
+        r"> foo
+
+> bar",
+        r"<blockquote>
+<p>foo</p>
+</blockquote>
+<blockquote>
+<p>bar</p>
+</blockquote>"
+    );
+    test!(
+        example_243,
+        r"> foo
+> bar",
+        r"<blockquote>
+<p>foo
+bar</p>
+</blockquote>"
+    );
+    test!(
+        example_244,
+        r"> foo
+>
+> bar",
+        r"<blockquote>
+<p>foo</p>
+<p>bar</p>
+</blockquote>"
+    );
+    test!(
+        example_245,
+        r"foo
+> bar",
+        r"<p>foo</p>
+<blockquote>
+<p>bar</p>
+</blockquote>"
+    );
+    test!(
+        example_246,
+        r"> aaa
+***
+> bbb",
+        r"<blockquote>
+<p>aaa</p>
+</blockquote>
+<hr />
+<blockquote>
+<p>bbb</p>
+</blockquote>"
+    );
+    test!(
+        example_247,
+        r"> bar
+baz",
+        r"<blockquote>
+<p>bar
+baz</p>
+</blockquote>"
+    );
+    test!(
+        example_248,
+        r"> bar
+
+baz",
+        r"<blockquote>
+<p>bar</p>
+</blockquote>
+<p>baz</p>"
+    );
+    test!(
+        example_249,
+        r"> bar
+>
+baz",
+        r"<blockquote>
+<p>bar</p>
+</blockquote>
+<p>baz</p>"
+    );
+    test!(
+        example_250,
+        r"> > > foo
+bar",
+        r"<blockquote>
+<blockquote>
+<blockquote>
+<p>foo
+bar</p>
+</blockquote>
+</blockquote>
+</blockquote>"
+    );
+    test!(
+        example_251,
+        r">>> foo
+> bar
+>>baz",
+        r"<blockquote>
+<blockquote>
+<blockquote>
+<p>foo
+bar
+baz</p>
+</blockquote>
+</blockquote>
+</blockquote>"
+    );
+    test!(
+        example_252,
+        r">     code
+
+>    not code",
+        r"<blockquote>
+<pre><code>code
+</code></pre>
+</blockquote>
+<blockquote>
+<p>not code</p>
+</blockquote>"
+    );
+}
+
+mod code_span {
+    use super::*;
+
+    test!(example_327, r"`hi`lo`", r"<p><code>hi</code>lo`</p>");
+    test!(example_328, r"`foo`", r"<p><code>foo</code></p>");
+    test!(
+        example_329,
+        r"`` foo ` bar ``",
+        r"<p><code>foo ` bar</code></p>"
+    );
+    test!(example_330, r"` `` `", r"<p><code>``</code></p>");
+    test!(example_331, r"`  ``  `", r"<p><code> `` </code></p>");
+    test!(example_332, r"` a`", r"<p><code> a</code></p>");
+    test!(example_333, r"` b `", r"<p><code> b </code></p>");
+    test!(
+        example_334,
+        r"` `
+`  `",
+        r"<p><code> </code>
+<code>  </code></p>"
+    );
+    test!(
+        example_335,
+        r"``
+foo
+bar  
+baz
+``",
+        r"<p><code>foo bar   baz</code></p>"
+    );
+    test!(
+        example_336,
+        r"``
+foo 
+``",
+        r"<p><code>foo </code></p>"
+    );
+    test!(
+        example_337,
+        r"`foo   bar 
+baz`",
+        r"<p><code>foo   bar  baz</code></p>"
+    );
+    test!(example_338, r"`foo\`bar`", r"<p><code>foo\</code>bar`</p>");
+    test!(example_339, r"``foo`bar``", r"<p><code>foo`bar</code></p>");
+    test!(
+        example_340,
+        r"` foo `` bar `",
+        r"<p><code>foo `` bar</code></p>"
+    );
+    test!(example_341, r"*foo`*`", r"<p>*foo<code>*</code></p>");
+    test!(
+        example_342,
+        r"[not a `link](/foo`)",
+        r"<p>[not a <code>link](/foo</code>)</p>"
+    );
+    test!(
+        example_343,
+        r#"`<a href="`">`"#,
+        r"<p><code>&lt;a href=&quot;</code>&quot;&gt;`</p>"
+    );
+    test!(example_344, r#"<a href="`">`"#, r#"<p><a href="`">`</p>"#);
+    test!(
+        example_345,
+        r"`<http://foo.bar.`baz>`",
+        r"<p><code>&lt;http://foo.bar.</code>baz&gt;`</p>"
+    );
+    test!(
+        example_346,
+        r"<http://foo.bar.`baz>`",
+        r#"<p><a href="http://foo.bar.%60baz">http://foo.bar.`baz</a>`</p>"#
+    );
+    test!(example_347, r"```foo``", r"<p>```foo``</p>");
+    test!(example_348, r"`foo", r"<p>`foo</p>");
+    test!(example_349, r"`foo``bar``", r"<p>`foo<code>bar</code></p>");
+}
+
+mod emphasis {
+    use super::*;
+
+    test!(example_350, r"*foo bar*", r"<p><em>foo bar</em></p>");
+    test!(example_351, r"a * foo bar*", r"<p>a * foo bar*</p>");
+    test!(example_352, r#"a*"foo"*"#, r"<p>a*&quot;foo&quot;*</p>");
+    test!(example_353, r"* a *", r"<p>* a *</p>");
+    test!(example_354, r"foo*bar*", r"<p>foo<em>bar</em></p>");
+    test!(example_355, r"5*6*78", r"<p>5<em>6</em>78</p>");
+    test!(example_356, r"_foo bar_", r"<p><em>foo bar</em></p>");
+    test!(example_357, r"_ foo bar_", r"<p>_ foo bar_</p>");
+    test!(example_358, r#"a_"foo"_"#, r"<p>a_&quot;foo&quot;_</p>");
+    test!(example_359, r"foo_bar_", r"<p>foo_bar_</p>");
+    test!(example_360, r"5_6_78", r"<p>5_6_78</p>");
+    test!(
+        example_361,
+        r"пристаням_стремятся_",
+        r"<p>пристаням_стремятся_</p>"
+    );
+    test!(example_362, r#"aa_"bb"_cc"#, r"<p>aa_&quot;bb&quot;_cc</p>");
+    test!(example_363, r"foo-_(bar)_", r"<p>foo-<em>(bar)</em></p>");
+    test!(example_364, r"_foo*", r"<p>_foo*</p>");
+    test!(example_365, r"*foo bar *", r"<p>*foo bar *</p>");
+    test!(
+        example_366,
+        r"*foo bar
+*",
+        r"<p>*foo bar
+*</p>"
+    );
+    test!(example_367, r"*(*foo)", r"<p>*(*foo)</p>");
+    test!(example_368, r"*(*foo*)*", r"<p><em>(<em>foo</em>)</em></p>");
+    test!(example_369, r"*foo*bar", r"<p><em>foo</em>bar</p>");
+    test!(example_370, r"_foo bar _", r"<p>_foo bar _</p>");
+    test!(example_371, r"_(_foo)", r"<p>_(_foo)</p>");
+    test!(example_372, r"_(_foo_)_", r"<p><em>(<em>foo</em>)</em></p>");
+    test!(example_373, r"_foo_bar", r"<p>_foo_bar</p>");
+    test!(
+        example_374,
+        r"_пристаням_стремятся",
+        r"<p>_пристаням_стремятся</p>"
+    );
+    test!(
+        example_375,
+        r"_foo_bar_baz_",
+        r"<p><em>foo_bar_baz</em></p>"
+    );
+    test!(example_376, r"_(bar)_.", r"<p><em>(bar)</em>.</p>");
+    test!(
+        example_377,
+        r"**foo bar**",
+        r"<p><strong>foo bar</strong></p>"
+    );
+    test!(example_378, r"** foo bar**", r"<p>** foo bar**</p>");
+    test!(example_379, r#"a**"foo"**"#, r"<p>a**&quot;foo&quot;**</p>");
+    test!(
+        example_380,
+        r"foo**bar**",
+        r"<p>foo<strong>bar</strong></p>"
+    );
+    test!(
+        example_381,
+        r"__foo bar__",
+        r"<p><strong>foo bar</strong></p>"
+    );
+    test!(example_382, r"__ foo bar__", r"<p>__ foo bar__</p>");
+    test!(
+        example_383,
+        r"__
+foo bar__",
+        r"<p>__
+foo bar__</p>"
+    );
+    test!(example_384, r#"a__"foo"__"#, r"<p>a__&quot;foo&quot;__</p>");
+    test!(example_385, r"foo__bar__", r"<p>foo__bar__</p>");
+    test!(example_386, r"5__6__78", r"<p>5__6__78</p>");
+    test!(
+        example_387,
+        r"пристаням__стремятся__",
+        r"<p>пристаням__стремятся__</p>"
+    );
+    test!(
+        example_388,
+        r"__foo, __bar__, baz__",
+        r"<p><strong>foo, <strong>bar</strong>, baz</strong></p>"
+    );
+    test!(
+        example_389,
+        r"foo-__(bar)__",
+        r"<p>foo-<strong>(bar)</strong></p>"
+    );
+    test!(example_390, r"**foo bar **", r"<p>**foo bar **</p>");
+    test!(example_391, r"**(**foo)", r"<p>**(**foo)</p>");
+    test!(
+        example_392,
+        r"*(**foo**)*",
+        r"<p><em>(<strong>foo</strong>)</em></p>"
+    );
+    test!(
+        example_393,
+        r"**Gomphocarpus (*Gomphocarpus physocarpus*, syn.
+*Asclepias physocarpa*)**",
+        r"<p><strong>Gomphocarpus (<em>Gomphocarpus physocarpus</em>, syn.
+<em>Asclepias physocarpa</em>)</strong></p>"
+    );
+    test!(
+        example_394,
+        r#"**foo "*bar*" foo**"#,
+        r"<p><strong>foo &quot;<em>bar</em>&quot; foo</strong></p>"
+    );
+    test!(
+        example_395,
+        r"**foo**bar",
+        r"<p><strong>foo</strong>bar</p>"
+    );
+    test!(example_396, r"__foo bar __", r"<p>__foo bar __</p>");
+    test!(example_397, r"__(__foo)", r"<p>__(__foo)</p>");
+    test!(
+        example_398,
+        r"_(__foo__)_",
+        r"<p><em>(<strong>foo</strong>)</em></p>"
+    );
+    test!(example_399, r"__foo__bar", r"<p>__foo__bar</p>");
+    test!(
+        example_400,
+        r"__пристаням__стремятся",
+        r"<p>__пристаням__стремятся</p>"
+    );
+    test!(
+        example_401,
+        r"__foo__bar__baz__",
+        r"<p><strong>foo__bar__baz</strong></p>"
+    );
+    test!(
+        example_402,
+        r"__(bar)__.",
+        r"<p><strong>(bar)</strong>.</p>"
+    );
+    test!(
+        example_403,
+        r"*foo [bar](/url)*",
+        r#"<p><em>foo <a href="/url">bar</a></em></p>"#
+    );
+    test!(
+        example_404,
+        r"*foo
+bar*",
+        r"<p><em>foo
+bar</em></p>"
+    );
+    test!(
+        example_405,
+        r"_foo __bar__ baz_",
+        r"<p><em>foo <strong>bar</strong> baz</em></p>"
+    );
+    test!(
+        example_406,
+        r"_foo _bar_ baz_",
+        r"<p><em>foo <em>bar</em> baz</em></p>"
+    );
+    test!(
+        example_407,
+        r"__foo_ bar_",
+        r"<p><em><em>foo</em> bar</em></p>"
+    );
+    test!(
+        example_408,
+        r"*foo *bar**",
+        r"<p><em>foo <em>bar</em></em></p>"
+    );
+    test!(
+        example_409,
+        r"*foo **bar** baz*",
+        r"<p><em>foo <strong>bar</strong> baz</em></p>"
+    );
+    test!(
+        example_410,
+        r"*foo**bar**baz*",
+        r"<p><em>foo<strong>bar</strong>baz</em></p>"
+    );
+    test!(example_411, r"*foo**bar*", r"<p><em>foo**bar</em></p>");
+    test!(
+        example_412,
+        r"***foo** bar*",
+        r"<p><em><strong>foo</strong> bar</em></p>"
+    );
+    test!(
+        example_413,
+        r"*foo **bar***",
+        r"<p><em>foo <strong>bar</strong></em></p>"
+    );
+    test!(
+        example_414,
+        r"*foo**bar***",
+        r"<p><em>foo<strong>bar</strong></em></p>"
+    );
+    test!(
+        example_415,
+        r"foo***bar***baz",
+        r"<p>foo<em><strong>bar</strong></em>baz</p>"
+    );
+    test!(
+        example_416,
+        r"foo******bar*********baz",
+        r"<p>foo<strong><strong><strong>bar</strong></strong></strong>***baz</p>"
+    );
+    test!(
+        example_417,
+        r"*foo **bar *baz* bim** bop*",
+        r"<p><em>foo <strong>bar <em>baz</em> bim</strong> bop</em></p>"
+    );
+    test!(
+        example_418,
+        r"*foo [*bar*](/url)*",
+        r#"<p><em>foo <a href="/url"><em>bar</em></a></em></p>"#
+    );
+    test!(
+        example_419,
+        r"** is not an empty emphasis",
+        r"<p>** is not an empty emphasis</p>"
+    );
+    test!(
+        example_420,
+        r"**** is not an empty strong emphasis",
+        r"<p>**** is not an empty strong emphasis</p>"
+    );
+    test!(
+        example_421,
+        r"**foo [bar](/url)**",
+        r#"<p><strong>foo <a href="/url">bar</a></strong></p>"#
+    );
+    test!(
+        example_422,
+        r"**foo
+bar**",
+        r"<p><strong>foo
+bar</strong></p>"
+    );
+    test!(
+        example_423,
+        r"__foo _bar_ baz__",
+        r"<p><strong>foo <em>bar</em> baz</strong></p>"
+    );
+    test!(
+        example_424,
+        r"__foo __bar__ baz__",
+        r"<p><strong>foo <strong>bar</strong> baz</strong></p>"
+    );
+    test!(
+        example_425,
+        r"____foo__ bar__",
+        r"<p><strong><strong>foo</strong> bar</strong></p>"
+    );
+    test!(
+        example_426,
+        r"**foo **bar****",
+        r"<p><strong>foo <strong>bar</strong></strong></p>"
+    );
+    test!(
+        example_427,
+        r"**foo *bar* baz**",
+        r"<p><strong>foo <em>bar</em> baz</strong></p>"
+    );
+    test!(
+        example_428,
+        r"**foo*bar*baz**",
+        r"<p><strong>foo<em>bar</em>baz</strong></p>"
+    );
+    test!(
+        example_429,
+        r"***foo* bar**",
+        r"<p><strong><em>foo</em> bar</strong></p>"
+    );
+    test!(
+        example_430,
+        r"**foo *bar***",
+        r"<p><strong>foo <em>bar</em></strong></p>"
+    );
+    test!(
+        example_431,
+        r"**foo *bar **baz**
+bim* bop**",
+        r"<p><strong>foo <em>bar <strong>baz</strong>
+bim</em> bop</strong></p>"
+    );
+    test!(
+        example_432,
+        r"**foo [*bar*](/url)**",
+        r#"<p><strong>foo <a href="/url"><em>bar</em></a></strong></p>"#
+    );
+    test!(
+        example_433,
+        r"__ is not an empty emphasis",
+        r"<p>__ is not an empty emphasis</p>"
+    );
+    test!(
+        example_434,
+        r"____ is not an empty strong emphasis",
+        r"<p>____ is not an empty strong emphasis</p>"
+    );
+    test!(example_435, r"foo ***", r"<p>foo ***</p>");
+    test!(example_436, r"foo *\**", r"<p>foo <em>*</em></p>");
+    test!(example_437, r"foo *_*", r"<p>foo <em>_</em></p>");
+    test!(example_438, r"foo *****", r"<p>foo *****</p>");
+    test!(example_439, r"foo **\***", r"<p>foo <strong>*</strong></p>");
+    test!(example_440, r"foo **_**", r"<p>foo <strong>_</strong></p>");
+    test!(example_441, r"**foo*", r"<p>*<em>foo</em></p>");
+    test!(example_442, r"*foo**", r"<p><em>foo</em>*</p>");
+    test!(example_443, r"***foo**", r"<p>*<strong>foo</strong></p>");
+    test!(example_444, r"****foo*", r"<p>***<em>foo</em></p>");
+    test!(example_445, r"**foo***", r"<p><strong>foo</strong>*</p>");
+    test!(example_446, r"*foo****", r"<p><em>foo</em>***</p>");
+    test!(example_447, r"foo ___", r"<p>foo ___</p>");
+    test!(example_448, r"foo _\__", r"<p>foo <em>_</em></p>");
+    test!(example_449, r"foo _*_", r"<p>foo <em>*</em></p>");
+    test!(example_450, r"foo _____", r"<p>foo _____</p>");
+    test!(example_451, r"foo __\___", r"<p>foo <strong>_</strong></p>");
+    test!(example_452, r"foo __*__", r"<p>foo <strong>*</strong></p>");
+    test!(example_453, r"__foo_", r"<p>_<em>foo</em></p>");
+    test!(example_454, r"_foo__", r"<p><em>foo</em>_</p>");
+    test!(example_455, r"___foo__", r"<p>_<strong>foo</strong></p>");
+    test!(example_456, r"____foo_", r"<p>___<em>foo</em></p>");
+    test!(example_457, r"__foo___", r"<p><strong>foo</strong>_</p>");
+    test!(example_458, r"_foo____", r"<p><em>foo</em>___</p>");
+    test!(example_459, r"**foo**", r"<p><strong>foo</strong></p>");
+    test!(example_460, r"*_foo_*", r"<p><em><em>foo</em></em></p>");
+    test!(example_461, r"__foo__", r"<p><strong>foo</strong></p>");
+    test!(example_462, r"_*foo*_", r"<p><em><em>foo</em></em></p>");
+    test!(
+        example_463,
+        r"****foo****",
+        r"<p><strong><strong>foo</strong></strong></p>"
+    );
+    test!(
+        example_464,
+        r"____foo____",
+        r"<p><strong><strong>foo</strong></strong></p>"
+    );
+    test!(
+        example_465,
+        r"******foo******",
+        r"<p><strong><strong><strong>foo</strong></strong></strong></p>"
+    );
+    test!(
+        example_466,
+        r"***foo***",
+        r"<p><em><strong>foo</strong></em></p>"
+    );
+    test!(
+        example_467,
+        r"_____foo_____",
+        r"<p><em><strong><strong>foo</strong></strong></em></p>"
+    );
+    test!(
+        example_468,
+        r"*foo _bar* baz_",
+        r"<p><em>foo _bar</em> baz_</p>"
+    );
+    test!(
+        example_469,
+        r"*foo __bar *baz bim__ bam*",
+        r"<p><em>foo <strong>bar *baz bim</strong> bam</em></p>"
+    );
+    test!(
+        example_470,
+        r"**foo **bar baz**",
+        r"<p>**foo <strong>bar baz</strong></p>"
+    );
+    test!(
+        example_471,
+        r"*foo *bar baz*",
+        r"<p>*foo <em>bar baz</em></p>"
+    );
+    test!(
+        example_472,
+        r"*[bar*](/url)",
+        r#"<p>*<a href="/url">bar*</a></p>"#
+    );
+    test!(
+        example_473,
+        r"_foo [bar_](/url)",
+        r#"<p>_foo <a href="/url">bar_</a></p>"#
+    );
+    test!(
+        example_474,
+        r#"*<img src="foo" title="*"/>"#,
+        r#"<p>*<img src="foo" title="*"/></p>"#
+    );
+    test!(
+        example_475,
+        r#"**<a href="**">"#,
+        r#"<p>**<a href="**"></p>"#
+    );
+    test!(
+        example_476,
+        r#"__<a href="__">"#,
+        r#"<p>__<a href="__"></p>"#
+    );
+    test!(example_477, r"*a `*`*", r"<p><em>a <code>*</code></em></p>");
+    test!(example_478, r"_a `_`_", r"<p><em>a <code>_</code></em></p>");
+    test!(
+        example_479,
+        r"**a<http://foo.bar/?q=**>",
+        r#"<p>**a<a href="http://foo.bar/?q=**">http://foo.bar/?q=**</a></p>"#
+    );
+    test!(
+        example_480,
+        r"__a<http://foo.bar/?q=__>",
+        r#"<p>__a<a href="http://foo.bar/?q=__">http://foo.bar/?q=__</a></p>"#
+    );
+    test!(
+        example_481,
+        r#"[link](/uri "title")"#,
+        r#"<p><a href="/uri" title="title">link</a></p>"#
+    );
+}
+
+mod hard_line_break {
+    use super::*;
+
+    test!(
+        example_633,
+        r"foo  
+baz",
+        r"<p>foo<br />
+baz</p>"
+    );
+    test!(
+        example_634,
+        r"foo\
+baz",
+        r"<p>foo<br />
+baz</p>"
+    );
+    test!(
+        example_635,
+        r"foo       
+baz",
+        r"<p>foo<br />
+baz</p>"
+    );
+    test!(
+        example_636,
+        r"foo  
+     bar",
+        r"<p>foo<br />
+bar</p>"
+    );
+    test!(
+        example_637,
+        r"foo\
+     bar",
+        r"<p>foo<br />
+bar</p>"
+    );
+    test!(
+        example_638,
+        r"*foo  
+bar*",
+        r"<p><em>foo<br />
+bar</em></p>"
+    );
+    test!(
+        example_639,
+        r"*foo\
+bar*",
+        r"<p><em>foo<br />
+bar</em></p>"
+    );
+    test!(
+        example_640,
+        r"`code  
+span`",
+        r"<p><code>code   span</code></p>"
+    );
+    test!(
+        example_641,
+        r"`code\
+span`",
+        r"<p><code>code\ span</code></p>"
+    );
+    test!(
+        example_642,
+        r#"<a href="foo  
+bar">"#,
+        r#"<p><a href="foo  
+bar"></p>"#
+    );
+    test!(
+        example_643,
+        r#"<a href="foo\
+bar">"#,
+        r#"<p><a href="foo\
+bar"></p>"#
+    );
+    test!(example_644, r"foo\", r"<p>foo\</p>");
+    test!(example_645, r"foo  ", r"<p>foo</p>");
+    test!(example_646, r"### foo\", r"<h3>foo\</h3>");
+    test!(example_647, r"### foo  ", r"<h3>foo</h3>");
+}
+
+mod html {
+    use super::*;
+
+    test!(
+        example_148,
+        r"<table><tr><td>
+<pre>
+**Hello**,
+
+_world_.
+</pre>
+</td></tr></table>",
+        r"<table><tr><td>
+<pre>
+**Hello**,
+<p><em>world</em>.
+</pre></p>
+</td></tr></table>"
+    );
+    test!(
+        example_149,
+        r"<table>
+  <tr>
+    <td>
+           hi
+    </td>
+  </tr>
+</table>
+
+okay.",
+        r"<table>
+  <tr>
+    <td>
+           hi
+    </td>
+  </tr>
+</table>
+<p>okay.</p>"
+    );
+    test!(
+        example_150,
+        r" <div>
+  *hello*
+         <foo><a>",
+        r" <div>
+  *hello*
+         <foo><a>"
+    );
+    test!(
+        example_151,
+        r"</div>
+*foo*",
+        r"</div>
+*foo*"
+    );
+    test!(
+        example_152,
+        r#"<DIV CLASS="foo">
+
+*Markdown*
+
+</DIV>"#,
+        r#"<DIV CLASS="foo">
+<p><em>Markdown</em></p>
+</DIV>"#
+    );
+    test!(
+        example_153,
+        r#"<div id="foo"
+  class="bar">
+</div>"#,
+        r#"<div id="foo"
+  class="bar">
+</div>"#
+    );
+    test!(
+        example_154,
+        r#"<div id="foo" class="bar
+  baz">
+</div>"#,
+        r#"<div id="foo" class="bar
+  baz">
+</div>"#
+    );
+    test!(
+        example_155,
+        r"<div>
+*foo*
+
+*bar*",
+        r"<div>
+*foo*
+<p><em>bar</em></p>"
+    );
+    test!(
+        example_156,
+        r#"<div id="foo"
+*hi*"#,
+        r#"<div id="foo"
+*hi*"#
+    );
+    test!(
+        example_157,
+        r#"<div class
+foo"#,
+        r#"<div class
+foo"#
+    );
+    test!(
+        example_158,
+        r#"<div *???-&&&-<---
+*foo*"#,
+        r#"<div *???-&&&-<---
+*foo*"#
+    );
+    test!(
+        example_159,
+        r#"<div><a href="bar">*foo*</a></div>"#,
+        r#"<div><a href="bar">*foo*</a></div>"#
+    );
+    test!(
+        example_160,
+        r#"<table><tr><td>
+foo
+</td></tr></table>"#,
+        r#"<table><tr><td>
+foo
+</td></tr></table>"#
+    );
+    test!(
+        example_161,
+        r#"<div></div>
+``` c
+int x = 33;
+```"#,
+        r#"<div></div>
+``` c
+int x = 33;
+```"#
+    );
+    test!(
+        example_162,
+        r#"<a href="foo">
+*bar*
+</a>"#,
+        r#"<a href="foo">
+*bar*
+</a>"#
+    );
+    test!(
+        example_163,
+        r#"<Warning>
+*bar*
+</Warning>"#,
+        r#"<Warning>
+*bar*
+</Warning>"#
+    );
+    test!(
+        example_164,
+        r#"<i class="foo">
+*bar*
+</i>"#,
+        r#"<i class="foo">
+*bar*
+</i>"#
+    );
+    test!(
+        example_165,
+        r#"</ins>
+*bar*"#,
+        r#"</ins>
+*bar*"#
+    );
+    test!(
+        example_166,
+        r#"<del>
+*foo*
+</del>"#,
+        r#"<del>
+*foo*
+</del>"#
+    );
+    test!(
+        example_167,
+        r"<del>
+
+*foo*
+
+</del>",
+        r"<del>
+<p><em>foo</em></p>
+</del>"
+    );
+    test!(
+        example_168,
+        r"<del>*foo*</del>",
+        r"<p><del><em>foo</em></del></p>"
+    );
+    test!(
+        example_169,
+        r#"<pre language="haskell"><code>
+import Text.HTML.TagSoup
+
+main :: IO ()
+main = print $ parseTags tags
+</code></pre>
+okay"#,
+        r#"<pre language="haskell"><code>
+import Text.HTML.TagSoup
+
+main :: IO ()
+main = print $ parseTags tags
+</code></pre>
+<p>okay</p>"#
+    );
+    test!(
+        example_170,
+        r#"<script type="text/javascript">
+// JavaScript example
+
+document.getElementById("demo").innerHTML = "Hello JavaScript!";
+</script>
+okay"#,
+        r#"<script type="text/javascript">
+// JavaScript example
+
+document.getElementById("demo").innerHTML = "Hello JavaScript!";
+</script>
+<p>okay</p>"#
+    );
+    test!(
+        example_171,
+        r"<textarea>
+
+*foo*
+
+_bar_
+
+</textarea>",
+        r"<textarea>
+
+*foo*
+
+_bar_
+
+</textarea>"
+    );
+    test!(
+        example_172,
+        r#"<style
+  type="text/css">
+h1 {color:red;}
+
+p {color:blue;}
+</style>
+okay"#,
+        r#"<style
+  type="text/css">
+h1 {color:red;}
+
+p {color:blue;}
+</style>
+<p>okay</p>"#
+    );
+    test!(
+        example_173,
+        r#"<style
+  type="text/css">
+
+foo"#,
+        r#"<style
+  type="text/css">
+
+foo"#
+    );
+    test!(
+        example_174,
+        r"> <div>
+> foo
+
+bar",
+        r"<blockquote>
+<div>
+foo
+</blockquote>
+<p>bar</p>"
+    );
+    test!(
+        example_175,
+        r"- <div>
+- foo",
+        r"<ul>
+<li>
+<div>
+</li>
+<li>foo</li>
+</ul>"
+    );
+    test!(
+        example_176,
+        r"<style>p{color:red;}</style>
+*foo*",
+        r"<style>p{color:red;}</style>
+<p><em>foo</em></p>"
+    );
+    test!(
+        example_177,
+        r"<!-- foo -->*bar*
+*baz*",
+        r"<!-- foo -->*bar*
+<p><em>baz</em></p>"
+    );
+    test!(
+        example_178,
+        r"<script>
+foo
+</script>1. *bar*",
+        r"<script>
+foo
+</script>1. *bar*"
+    );
+    test!(
+        example_179,
+        r"<!-- Foo
+
+bar
+   baz -->
+okay",
+        r"<!-- Foo
+
+bar
+   baz -->
+<p>okay</p>"
+    );
+    test!(
+        example_180,
+        r"<?php
+
+  echo '>';
+
+?>
+okay",
+        r"<?php
+
+  echo '>';
+
+?>
+<p>okay</p>"
+    );
+    test!(example_181, r"<!DOCTYPE html>", r"<!DOCTYPE html>");
+    test!(
+        example_182,
+        r"<![CDATA[
+function matchwo(a,b)
+{
+  if (a < b && a < 0) then {
+    return 1;
+
+  } else {
+
+    return 0;
+  }
+}
+]]>
+okay",
+        r"<![CDATA[
+function matchwo(a,b)
+{
+  if (a < b && a < 0) then {
+    return 1;
+
+  } else {
+
+    return 0;
+  }
+}
+]]>
+<p>okay</p>"
+    );
+    test!(
+        example_183,
+        r"  <!-- foo -->
+
+    <!-- foo -->",
+        r"  <!-- foo -->
+<pre><code>&lt;!-- foo --&gt;
+</code></pre>"
+    );
+    test!(
+        example_184,
+        r"  <div>
+
+    <div>",
+        r"  <div>
+<pre><code>&lt;div&gt;
+</code></pre>"
+    );
+    test!(
+        example_185,
+        r"Foo
+<div>
+bar
+</div>",
+        r"<p>Foo</p>
+<div>
+bar
+</div>"
+    );
+    test!(
+        example_186,
+        r"<div>
+bar
+</div>
+*foo*",
+        r"<div>
+bar
+</div>
+*foo*"
+    );
+    test!(
+        example_187,
+        r#"Foo
+<a href="bar">
+baz"#,
+        r#"<p>Foo
+<a href="bar">
+baz</p>"#
+    );
+    test!(
+        example_188,
+        r"<div>
+
+*Emphasized* text.
+
+</div>",
+        r"<div>
+<p><em>Emphasized</em> text.</p>
+</div>"
+    );
+    test!(
+        example_189,
+        r"<div>
+*Emphasized* text.
+</div>",
+        r"<div>
+*Emphasized* text.
+</div>"
+    );
+    test!(
+        example_190,
+        r"<table>
+
+<tr>
+
+<td>
+Hi
+</td>
+
+</tr>
+
+</table>",
+        r"<table>
+<tr>
+<td>
+Hi
+</td>
+</tr>
+</table>"
+    );
+    test!(
+        example_191,
+        r"<table>
+
+  <tr>
+
+    <td>
+      Hi
+    </td>
+
+  </tr>
+
+</table>",
+        r"<table>
+  <tr>
+<pre><code>&lt;td&gt;
+  Hi
+&lt;/td&gt;
+</code></pre>
+  </tr>
+</table>"
+    );
+}
+
+mod image {
+    use super::*;
+
+    test!(
+        example_571,
+        r#"![foo](/url "title")"#,
+        r#"<p><img src="/url" alt="foo" title="title" /></p>"#
+    );
+    test!(
+        example_572,
+        r#"![foo *bar*]
+
+[foo *bar*]: train.jpg "train & tracks""#,
+        r#"<p><img src="train.jpg" alt="foo bar" title="train &amp; tracks" /></p>"#
+    );
+    test!(
+        example_573,
+        r"![foo ![bar](/url)](/url2)",
+        r#"<p><img src="/url2" alt="foo bar" /></p>"#
+    );
+    test!(
+        example_574,
+        r"![foo [bar](/url)](/url2)",
+        r#"<p><img src="/url2" alt="foo bar" /></p>"#
+    );
+    test!(
+        example_575,
+        r#"![foo *bar*][]
+
+[foo *bar*]: train.jpg "train & tracks""#,
+        r#"<p><img src="train.jpg" alt="foo bar" title="train &amp; tracks" /></p>"#
+    );
+    test!(
+        example_576,
+        r#"![foo *bar*][foobar]
+
+[FOOBAR]: train.jpg "train & tracks""#,
+        r#"<p><img src="train.jpg" alt="foo bar" title="train &amp; tracks" /></p>"#
+    );
+    test!(
+        example_577,
+        r"![foo](train.jpg)",
+        r#"<p><img src="train.jpg" alt="foo" /></p>"#
+    );
+    test!(
+        example_578,
+        r#"My ![foo bar](/path/to/train.jpg  "title"   )"#,
+        r#"<p>My <img src="/path/to/train.jpg" alt="foo bar" title="title" /></p>"#
+    );
+    test!(
+        example_579,
+        r"![foo](<url>)",
+        r#"<p><img src="url" alt="foo" /></p>"#
+    );
+    test!(
+        example_580,
+        r"![](/url)",
+        r#"<p><img src="/url" alt="" /></p>"#
+    );
+    test!(
+        example_581,
+        r"![foo][bar]
+
+[bar]: /url",
+        r#"<p><img src="/url" alt="foo" /></p>"#
+    );
+    test!(
+        example_582,
+        r"![foo][bar]
+
+[BAR]: /url",
+        r#"<p><img src="/url" alt="foo" /></p>"#
+    );
+    test!(
+        example_583,
+        r#"![foo][]
+
+[foo]: /url "title""#,
+        r#"<p><img src="/url" alt="foo" title="title" /></p>"#
+    );
+    test!(
+        example_584,
+        r#"![*foo* bar][]
+
+[*foo* bar]: /url "title""#,
+        r#"<p><img src="/url" alt="foo bar" title="title" /></p>"#
+    );
+    test!(
+        example_585,
+        r#"![Foo][]
+
+[foo]: /url "title""#,
+        r#"<p><img src="/url" alt="Foo" title="title" /></p>"#
+    );
+    test!(
+        example_586,
+        r#"![foo] 
+[]
+
+[foo]: /url "title""#,
+        r#"<p><img src="/url" alt="foo" title="title" />
+[]</p>"#
+    );
+    test!(
+        example_587,
+        r#"![foo]
+
+[foo]: /url "title""#,
+        r#"<p><img src="/url" alt="foo" title="title" /></p>"#
+    );
+    test!(
+        example_588,
+        r#"![*foo* bar]
+
+[*foo* bar]: /url "title""#,
+        r#"<p><img src="/url" alt="foo bar" title="title" /></p>"#
+    );
+    test!(
+        example_589,
+        r#"![[foo]]
+
+[[foo]]: /url "title""#,
+        r"<p>![[foo]]</p>
+<p>[[foo]]: /url &quot;title&quot;</p>"
+    );
+    test!(
+        example_590,
+        r#"![Foo]
+
+[foo]: /url "title""#,
+        r#"<p><img src="/url" alt="Foo" title="title" /></p>"#
+    );
+    test!(
+        example_591,
+        r#"!\[foo]
+
+[foo]: /url "title""#,
+        r"<p>![foo]</p>"
+    );
+    test!(
+        example_592,
+        r#"\![foo]
+
+[foo]: /url "title""#,
+        r#"<p>!<a href="/url" title="title">foo</a></p>"#
     );
 }
 
@@ -327,88 +2151,2526 @@ mod indented_code {
     use super::*;
 
     test!(
-        example_107,
-        r"    a simple
-      indented code block
-",
-        r"<pre><code>a simple
-  indented code block
-</code></pre>"
+        example_107,
+        r"    a simple
+      indented code block
+",
+        r"<pre><code>a simple
+  indented code block
+</code></pre>"
+    );
+    test!(
+        example_108,
+        r"  - foo
+
+    bar",
+        r"<ul>
+<li>
+<p>foo</p>
+<p>bar</p>
+</li>
+</ul>"
+    );
+    test!(
+        example_109,
+        r"1.  foo
+
+    - bar",
+        r"<ol>
+<li>
+<p>foo</p>
+<ul>
+<li>bar</li>
+</ul>
+</li>
+</ol>"
+    );
+    test!(
+        example_110,
+        r"    <a/>
+    *hi*
+
+    - one",
+        r"<pre><code>&lt;a/&gt;
+*hi*
+
+- one
+</code></pre>"
+    );
+    test!(
+        example_111,
+        r"    chunk1
+
+    chunk2
+
+
+
+    chunk3
+",
+        r"<pre><code>chunk1
+
+chunk2
+
+
+
+chunk3
+</code></pre>"
+    );
+    // Added terminating new line in input.
+    test!(
+        example_112,
+        r"    chunk1
+      
+      chunk2
+",
+        r"<pre><code>chunk1
+  
+  chunk2
+</code></pre>"
+    );
+    test!(
+        example_113,
+        r"Foo
+    bar
+",
+        r"<p>Foo
+bar</p>"
+    );
+    test!(
+        example_114,
+        r"    foo
+bar",
+        r"<pre><code>foo
+</code></pre>
+<p>bar</p>"
+    );
+    test!(
+        example_115,
+        r"# Heading
+    foo
+Heading
+------
+    foo
+----",
+        r"<h1>Heading</h1>
+<pre><code>foo
+</code></pre>
+<h2>Heading</h2>
+<pre><code>foo
+</code></pre>
+<hr />"
+    );
+    // Added terminating new line in input.
+    test!(
+        example_116,
+        r"        foo
+    bar
+",
+        r"<pre><code>    foo
+bar
+</code></pre>"
+    );
+    test!(
+        example_117,
+        r"
+    
+    foo
+    ",
+        r"<pre><code>foo
+</code></pre>"
+    );
+    // Added terminating new line in input.
+    test!(
+        example_118,
+        r"    foo  
+",
+        r"<pre><code>foo  
+</code></pre>"
+    );
+}
+
+mod link {
+    use super::*;
+
+    test!(
+        example_481,
+        r#"[link](/uri "title")"#,
+        r#"<p><a href="/uri" title="title">link</a></p>"#
+    );
+    test!(
+        example_482,
+        r"[link](/uri)",
+        r#"<p><a href="/uri">link</a></p>"#
+    );
+    test!(
+        example_483,
+        r"[](./target.md)",
+        r#"<p><a href="./target.md"></a></p>"#
+    );
+    test!(example_484, r"[link]()", r#"<p><a href="">link</a></p>"#);
+    test!(example_485, r"[link](<>)", r#"<p><a href="">link</a></p>"#);
+    test!(example_486, r"[]()", r#"<p><a href=""></a></p>"#);
+    test!(example_487, r"[link](/my uri)", r"<p>[link](/my uri)</p>");
+    test!(
+        example_488,
+        r"[link](</my uri>)",
+        r#"<p><a href="/my%20uri">link</a></p>"#
+    );
+    test!(
+        example_489,
+        r"[link](foo
+bar)",
+        r"<p>[link](foo
+bar)</p>"
+    );
+    test!(
+        example_490,
+        r"[link](<foo
+bar>)",
+        r"<p>[link](<foo
+bar>)</p>"
+    );
+    test!(example_491, r"[a](<b)c>)", r#"<p><a href="b)c">a</a></p>"#);
+    test!(
+        example_492,
+        r"[link](<foo\>)",
+        r"<p>[link](&lt;foo&gt;)</p>"
+    );
+    test!(
+        example_493,
+        r"[a](<b)c
+[a](<b)c>
+[a](<b>c)",
+        r"<p>[a](&lt;b)c
+[a](&lt;b)c&gt;
+[a](<b>c)</p>"
+    );
+    test!(
+        example_494,
+        r"[link](\(foo\))",
+        r#"<p><a href="(foo)">link</a></p>"#
+    );
+    test!(
+        example_495,
+        r"[link](foo(and(bar)))",
+        r#"<p><a href="foo(and(bar))">link</a></p>"#
+    );
+    test!(
+        example_496,
+        r"[link](foo(and(bar))",
+        r"<p>[link](foo(and(bar))</p>"
+    );
+    test!(
+        example_497,
+        r"[link](foo\(and\(bar\))",
+        r#"<p><a href="foo(and(bar)">link</a></p>"#
+    );
+    test!(
+        example_498,
+        r"[link](<foo(and(bar)>)",
+        r#"<p><a href="foo(and(bar)">link</a></p>"#
+    );
+    test!(
+        example_499,
+        r"[link](foo\)\:)",
+        r#"<p><a href="foo):">link</a></p>"#
+    );
+    test!(
+        example_500,
+        r"[link](#fragment)
+
+[link](http://example.com#fragment)
+
+[link](http://example.com?foo=3#frag)",
+        r##"<p><a href="#fragment">link</a></p>
+<p><a href="http://example.com#fragment">link</a></p>
+<p><a href="http://example.com?foo=3#frag">link</a></p>"##
+    );
+    test!(
+        example_501,
+        r"[link](foo\bar)",
+        r#"<p><a href="foo%5Cbar">link</a></p>"#
+    );
+    test!(
+        example_502,
+        r"[link](foo%20b&auml;)",
+        r#"<p><a href="foo%20b%C3%A4">link</a></p>"#
+    );
+    test!(
+        example_503,
+        r#"[link]("title")"#,
+        r#"<p><a href="%22title%22">link</a></p>"#
+    );
+    test!(
+        example_504,
+        r#"[link](/url "title")
+[link](/url 'title')
+[link](/url (title))"#,
+        r#"<p><a href="/url" title="title">link</a>
+<a href="/url" title="title">link</a>
+<a href="/url" title="title">link</a></p>"#
+    );
+    test!(
+        example_505,
+        r#"[link](/url "title \"&quot;")"#,
+        r#"<p><a href="/url" title="title &quot;&quot;">link</a></p>"#
+    );
+    test!(
+        example_506,
+        r#"[link](/url "title")"#,
+        r#"<p><a href="/url%C2%A0%22title%22">link</a></p>"#
+    );
+    test!(
+        example_507,
+        r#"[link](/url "title "and" title")"#,
+        r"<p>[link](/url &quot;title &quot;and&quot; title&quot;)</p>"
+    );
+    test!(
+        example_508,
+        r#"[link](/url 'title "and" title')"#,
+        r#"<p><a href="/url" title="title &quot;and&quot; title">link</a></p>"#
+    );
+    test!(
+        example_509,
+        r#"[link](   /uri
+  "title"  )"#,
+        r#"<p><a href="/uri" title="title">link</a></p>"#
+    );
+    test!(example_510, r"[link] (/uri)", r"<p>[link] (/uri)</p>");
+    test!(
+        example_511,
+        r"[link [foo [bar]]](/uri)",
+        r#"<p><a href="/uri">link [foo [bar]]</a></p>"#
+    );
+    test!(
+        example_512,
+        r"[link] bar](/uri)",
+        r"<p>[link] bar](/uri)</p>"
+    );
+    test!(
+        example_513,
+        r"[link [bar](/uri)",
+        r#"<p>[link <a href="/uri">bar</a></p>"#
+    );
+    test!(
+        example_514,
+        r"[link \[bar](/uri)",
+        r#"<p><a href="/uri">link [bar</a></p>"#
+    );
+    test!(
+        example_515,
+        r"[link *foo **bar** `#`*](/uri)",
+        r#"<p><a href="/uri">link <em>foo <strong>bar</strong> <code>#</code></em></a></p>"#
+    );
+    test!(
+        example_516,
+        r"[![moon](moon.jpg)](/uri)",
+        r#"<p><a href="/uri"><img src="moon.jpg" alt="moon" /></a></p>"#
+    );
+    test!(
+        example_517,
+        r"[foo [bar](/uri)](/uri)",
+        r#"<p>[foo <a href="/uri">bar</a>](/uri)</p>"#
+    );
+    test!(
+        example_518,
+        r"[foo *[bar [baz](/uri)](/uri)*](/uri)",
+        r#"<p>[foo <em>[bar <a href="/uri">baz</a>](/uri)</em>](/uri)</p>"#
+    );
+    test!(
+        example_519,
+        r"![[[foo](uri1)](uri2)](uri3)",
+        r#"<p><img src="uri3" alt="[foo](uri2)" /></p>"#
+    );
+    test!(
+        example_520,
+        r"*[foo*](/uri)",
+        r#"<p>*<a href="/uri">foo*</a></p>"#
+    );
+    test!(
+        example_521,
+        r"[foo *bar](baz*)",
+        r#"<p><a href="baz*">foo *bar</a></p>"#
+    );
+    test!(
+        example_522,
+        r"*foo [bar* baz]",
+        r"<p><em>foo [bar</em> baz]</p>"
+    );
+    test!(
+        example_523,
+        r#"[foo <bar attr="](baz)">"#,
+        r#"<p>[foo <bar attr="](baz)"></p>"#
+    );
+    test!(
+        example_524,
+        r"[foo`](/uri)`",
+        r"<p>[foo<code>](/uri)</code></p>"
+    );
+    test!(
+        example_525,
+        r"[foo<http://example.com/?search=](uri)>",
+        r#"<p>[foo<a href="http://example.com/?search=%5D(uri)">http://example.com/?search=](uri)</a></p>"#
+    );
+    test!(
+        example_526,
+        r#"[foo][bar]
+
+[bar]: /url "title""#,
+        r#"<p><a href="/url" title="title">foo</a></p>"#
+    );
+    test!(
+        example_527,
+        r"[link [foo [bar]]][ref]
+
+[ref]: /uri",
+        r#"<p><a href="/uri">link [foo [bar]]</a></p>"#
+    );
+    test!(
+        example_528,
+        r"[link \[bar][ref]
+
+[ref]: /uri",
+        r#"<p><a href="/uri">link [bar</a></p>"#
+    );
+    test!(
+        example_529,
+        r"[link *foo **bar** `#`*][ref]
+
+[ref]: /uri",
+        r#"<p><a href="/uri">link <em>foo <strong>bar</strong> <code>#</code></em></a></p>"#
+    );
+    test!(
+        example_530,
+        r"[![moon](moon.jpg)][ref]
+
+[ref]: /uri",
+        r#"<p><a href="/uri"><img src="moon.jpg" alt="moon" /></a></p>"#
+    );
+    test!(
+        example_531,
+        r"[foo [bar](/uri)][ref]
+
+[ref]: /uri",
+        r#"<p>[foo <a href="/uri">bar</a>]<a href="/uri">ref</a></p>"#
+    );
+    test!(
+        example_532,
+        r"[foo *bar [baz][ref]*][ref]
+
+[ref]: /uri",
+        r#"<p>[foo <em>bar <a href="/uri">baz</a></em>]<a href="/uri">ref</a></p>"#
+    );
+    test!(
+        example_533,
+        r"*[foo*][ref]
+
+[ref]: /uri",
+        r#"<p>*<a href="/uri">foo*</a></p>"#
+    );
+    test!(
+        example_534,
+        r"[foo *bar][ref]*
+
+[ref]: /uri",
+        r#"<p><a href="/uri">foo *bar</a>*</p>"#
+    );
+    test!(
+        example_535,
+        r#"[foo <bar attr="][ref]">
+
+[ref]: /uri"#,
+        r#"<p>[foo <bar attr="][ref]"></p>"#
+    );
+    test!(
+        example_536,
+        r"[foo`][ref]`
+
+[ref]: /uri",
+        r"<p>[foo<code>][ref]</code></p>"
+    );
+    test!(
+        example_537,
+        r"[foo<http://example.com/?search=][ref]>
+
+[ref]: /uri",
+        r#"<p>[foo<a href="http://example.com/?search=%5D%5Bref%5D">http://example.com/?search=][ref]</a></p>"#
+    );
+    test!(
+        example_538,
+        r#"[foo][BaR]
+
+[bar]: /url "title""#,
+        r#"<p><a href="/url" title="title">foo</a></p>"#
+    );
+    test!(
+        example_539,
+        r"[ẞ]
+
+[SS]: /url",
+        r#"<p><a href="/url">ẞ</a></p>"#
+    );
+    test!(
+        example_540,
+        r"[Foo
+  bar]: /url
+
+[Baz][Foo bar]",
+        r#"<p><a href="/url">Baz</a></p>"#
+    );
+    test!(
+        example_541,
+        r#"[foo] [bar]
+
+[bar]: /url "title""#,
+        r#"<p>[foo] <a href="/url" title="title">bar</a></p>"#
+    );
+    test!(
+        example_542,
+        r#"[foo]
+[bar]
+
+[bar]: /url "title""#,
+        r#"<p>[foo]
+<a href="/url" title="title">bar</a></p>"#
+    );
+    test!(
+        example_543,
+        r"[foo]: /url1
+
+[foo]: /url2
+
+[bar][foo]",
+        r#"<p><a href="/url1">bar</a></p>"#
+    );
+    test!(
+        example_544,
+        r"[bar][foo\!]
+
+[foo!]: /url",
+        r"<p>[bar][foo!]</p>"
+    );
+    test!(
+        example_545,
+        r"[foo][ref[]
+
+[ref[]: /uri",
+        r"<p>[foo][ref[]</p>
+<p>[ref[]: /uri</p>"
+    );
+    test!(
+        example_546,
+        r"[foo][ref[bar]]
+
+[ref[bar]]: /uri",
+        r"<p>[foo][ref[bar]]</p>
+<p>[ref[bar]]: /uri</p>"
+    );
+    test!(
+        example_547,
+        r"[[[foo]]]
+
+[[[foo]]]: /url",
+        r"<p>[[[foo]]]</p>
+<p>[[[foo]]]: /url</p>"
+    );
+    test!(
+        example_548,
+        r"[foo][ref\[]
+
+[ref\[]: /uri",
+        r#"<p><a href="/uri">foo</a></p>"#
+    );
+    test!(
+        example_549,
+        r"[bar\\]: /uri
+
+[bar\\]",
+        r#"<p><a href="/uri">bar\</a></p>"#
+    );
+    test!(
+        example_550,
+        r"[]
+
+[]: /uri",
+        r"<p>[]</p>
+<p>[]: /uri</p>"
+    );
+    test!(
+        example_551,
+        r"[
+ ]
+
+[
+ ]: /uri",
+        r"<p>[
+]</p>
+<p>[
+]: /uri</p>"
+    );
+    test!(
+        example_552,
+        r#"[foo][]
+
+[foo]: /url "title""#,
+        r#"<p><a href="/url" title="title">foo</a></p>"#
+    );
+    test!(
+        example_553,
+        r#"[*foo* bar][]
+
+[*foo* bar]: /url "title""#,
+        r#"<p><a href="/url" title="title"><em>foo</em> bar</a></p>"#
+    );
+    test!(
+        example_554,
+        r#"[Foo][]
+
+[foo]: /url "title""#,
+        r#"<p><a href="/url" title="title">Foo</a></p>"#
+    );
+    test!(
+        example_555,
+        r#"[foo] 
+[]
+
+[foo]: /url "title""#,
+        r#"<p><a href="/url" title="title">foo</a>
+[]</p>"#
+    );
+    test!(
+        example_556,
+        r#"[foo]
+
+[foo]: /url "title""#,
+        r#"<p><a href="/url" title="title">foo</a></p>"#
+    );
+    test!(
+        example_557,
+        r#"[*foo* bar]
+
+[*foo* bar]: /url "title""#,
+        r#"<p><a href="/url" title="title"><em>foo</em> bar</a></p>"#
+    );
+    test!(
+        example_558,
+        r#"[[*foo* bar]]
+
+[*foo* bar]: /url "title""#,
+        r#"<p>[<a href="/url" title="title"><em>foo</em> bar</a>]</p>"#
+    );
+    test!(
+        example_559,
+        r"[[bar [foo]
+
+[foo]: /url",
+        r#"<p>[[bar <a href="/url">foo</a></p>"#
+    );
+    test!(
+        example_560,
+        r#"[Foo]
+
+[foo]: /url "title""#,
+        r#"<p><a href="/url" title="title">Foo</a></p>"#
+    );
+    test!(
+        example_561,
+        r"[foo] bar
+
+[foo]: /url",
+        r#"<p><a href="/url">foo</a> bar</p>"#
+    );
+    test!(
+        example_562,
+        r#"\[foo]
+
+[foo]: /url "title""#,
+        r"<p>[foo]</p>"
+    );
+    test!(
+        example_563,
+        r"[foo*]: /url
+
+*[foo*]",
+        r#"<p>*<a href="/url">foo*</a></p>"#
+    );
+    test!(
+        example_564,
+        r"[foo][bar]
+
+[foo]: /url1
+[bar]: /url2",
+        r#"<p><a href="/url2">foo</a></p>"#
+    );
+    test!(
+        example_565,
+        r"[foo][]
+
+[foo]: /url1",
+        r#"<p><a href="/url1">foo</a></p>"#
+    );
+    test!(
+        example_566,
+        r"[foo]()
+
+[foo]: /url1",
+        r#"<p><a href="">foo</a></p>"#
+    );
+    test!(
+        example_567,
+        r"[foo](not a link)
+
+[foo]: /url1",
+        r#"<p><a href="/url1">foo</a>(not a link)</p>"#
+    );
+    test!(
+        example_568,
+        r"[foo][bar][baz]
+
+[baz]: /url",
+        r#"<p>[foo]<a href="/url">bar</a></p>"#
+    );
+    test!(
+        example_569,
+        r"[foo][bar][baz]
+
+[baz]: /url1
+[bar]: /url2",
+        r#"<p><a href="/url2">foo</a><a href="/url1">baz</a></p>"#
+    );
+    test!(
+        example_570,
+        r"[foo][bar][baz]
+
+[baz]: /url1
+[foo]: /url2",
+        r#"<p>[foo]<a href="/url1">bar</a></p>"#
+    );
+    test!(
+        example_571,
+        r#"![foo](/url "title")"#,
+        r#"<p><img src="/url" alt="foo" title="title" /></p>"#
+    );
+}
+
+mod link_reference_definition {
+    use super::*;
+
+    test!(
+        example_192,
+        r#"[foo]: /url "title"
+
+[foo]"#,
+        r#"<p><a href="/url" title="title">foo</a></p>"#
+    );
+    test!(
+        example_193,
+        r"   [foo]: 
+      /url  
+           'the title'  
+
+[foo]",
+        r#"<p><a href="/url" title="the title">foo</a></p>"#
+    );
+    test!(
+        example_194,
+        r"[Foo*bar\]]:my_(url) 'title (with parens)'
+
+[Foo*bar\]]",
+        r#"<p><a href="my_(url)" title="title (with parens)">Foo*bar]</a></p>"#
+    );
+    test!(
+        example_195,
+        r"[Foo bar]:
+<my url>
+'title'
+
+[Foo bar]",
+        r#"<p><a href="my%20url" title="title">Foo bar</a></p>"#
+    );
+    test!(
+        example_196,
+        r"[foo]: /url '
+title
+line1
+line2
+'
+
+[foo]",
+        r#"<p><a href="/url" title="
+title
+line1
+line2
+">foo</a></p>"#
+    );
+    test!(
+        example_197,
+        r"[foo]: /url 'title
+
+with blank line'
+
+[foo]",
+        r"<p>[foo]: /url 'title</p>
+<p>with blank line'</p>
+<p>[foo]</p>"
+    );
+    test!(
+        example_198,
+        r"[foo]:
+/url
+
+[foo]",
+        r#"<p><a href="/url">foo</a></p>"#
+    );
+    test!(
+        example_199,
+        r"[foo]:
+
+[foo]",
+        r"<p>[foo]:</p>
+<p>[foo]</p>"
+    );
+    test!(
+        example_200,
+        r"[foo]: <>
+
+[foo]",
+        r#"<p><a href="">foo</a></p>"#
+    );
+    test!(
+        example_201,
+        r"[foo]: <bar>(baz)
+
+[foo]",
+        r"<p>[foo]: <bar>(baz)</p>
+<p>[foo]</p>"
+    );
+    test!(
+        example_202,
+        r#"[foo]: /url\bar\*baz "foo\"bar\baz"
+
+[foo]"#,
+        r#"<p><a href="/url%5Cbar*baz" title="foo&quot;bar\baz">foo</a></p>"#
+    );
+    test!(
+        example_203,
+        r"[foo]
+
+[foo]: url",
+        r#"<p><a href="url">foo</a></p>"#
+    );
+    test!(
+        example_204,
+        r"[foo]
+
+[foo]: first
+[foo]: second",
+        r#"<p><a href="first">foo</a></p>"#
+    );
+    test!(
+        example_205,
+        r"[FOO]: /url
+
+[Foo]",
+        r#"<p><a href="/url">Foo</a></p>"#
+    );
+    test!(
+        example_206,
+        r"[ΑΓΩ]: /φου
+
+[αγω]",
+        r#"<p><a href="/%CF%86%CE%BF%CF%85">αγω</a></p>"#
+    );
+    test!(example_207, r"[foo]: /url", r"");
+    test!(
+        example_208,
+        r"[
+foo
+]: /url
+bar",
+        r"<p>bar</p>"
+    );
+    test!(
+        example_209,
+        r#"[foo]: /url "title" ok"#,
+        r"<p>[foo]: /url &quot;title&quot; ok</p>"
+    );
+    test!(
+        example_210,
+        r#"[foo]: /url
+"title" ok"#,
+        r"<p>&quot;title&quot; ok</p>"
+    );
+    test!(
+        example_211,
+        r#"    [foo]: /url "title"
+
+[foo]"#,
+        r"<pre><code>[foo]: /url &quot;title&quot;
+</code></pre>
+<p>[foo]</p>"
+    );
+    test!(
+        example_212,
+        r"```
+[foo]: /url
+```
+
+[foo]",
+        r"<pre><code>[foo]: /url
+</code></pre>
+<p>[foo]</p>"
+    );
+    test!(
+        example_213,
+        r"Foo
+[bar]: /baz
+
+[bar]",
+        r"<p>Foo
+[bar]: /baz</p>
+<p>[bar]</p>"
+    );
+    test!(
+        example_214,
+        r"# [Foo]
+[foo]: /url
+> bar",
+        r#"<h1><a href="/url">Foo</a></h1>
+<blockquote>
+<p>bar</p>
+</blockquote>"#
+    );
+    test!(
+        example_215,
+        r"[foo]: /url
+bar
+===
+[foo]",
+        r#"<h1>bar</h1>
+<p><a href="/url">foo</a></p>"#
+    );
+    test!(
+        example_216,
+        r"[foo]: /url
+===
+[foo]",
+        r#"<p>===
+<a href="/url">foo</a></p>"#
+    );
+    test!(
+        example_217,
+        r#"[foo]: /foo-url "foo"
+[bar]: /bar-url
+  "bar"
+[baz]: /baz-url
+
+[foo],
+[bar],
+[baz]"#,
+        r#"<p><a href="/foo-url" title="foo">foo</a>,
+<a href="/bar-url" title="bar">bar</a>,
+<a href="/baz-url">baz</a></p>"#
+    );
+    test!(
+        example_218,
+        r"[foo]
+
+> [foo]: /url",
+        r#"<p><a href="/url">foo</a></p>
+<blockquote>
+</blockquote>"#
+    );
+}
+
+mod list {
+    use super::*;
+
+    test!(
+        example_301,
+        r"- foo
+- bar
++ baz",
+        r"<ul>
+<li>foo</li>
+<li>bar</li>
+</ul>
+<ul>
+<li>baz</li>
+</ul>"
+    );
+    test!(
+        example_302,
+        r"1. foo
+2. bar
+3) baz",
+        r#"<ol>
+<li>foo</li>
+<li>bar</li>
+</ol>
+<ol start="3">
+<li>baz</li>
+</ol>"#
+    );
+    test!(
+        example_303,
+        r"Foo
+- bar
+- baz",
+        r"<p>Foo</p>
+<ul>
+<li>bar</li>
+<li>baz</li>
+</ul>"
+    );
+    test!(
+        example_304,
+        r"The number of windows in my house is
+14.  The number of doors is 6.",
+        r"<p>The number of windows in my house is
+14.  The number of doors is 6.</p>"
+    );
+    test!(
+        example_305,
+        r"The number of windows in my house is
+1.  The number of doors is 6.",
+        r"<p>The number of windows in my house is</p>
+<ol>
+<li>The number of doors is 6.</li>
+</ol>"
+    );
+    test!(
+        example_306,
+        r"- foo
+
+- bar
+
+
+- baz",
+        r"<ul>
+<li>
+<p>foo</p>
+</li>
+<li>
+<p>bar</p>
+</li>
+<li>
+<p>baz</p>
+</li>
+</ul>"
+    );
+    test!(
+        example_307,
+        r"- foo
+  - bar
+    - baz
+
+
+      bim",
+        r"<ul>
+<li>foo
+<ul>
+<li>bar
+<ul>
+<li>
+<p>baz</p>
+<p>bim</p>
+</li>
+</ul>
+</li>
+</ul>
+</li>
+</ul>"
+    );
+    test!(
+        example_308,
+        r"- foo
+- bar
+
+<!-- -->
+
+- baz
+- bim",
+        r"<ul>
+<li>foo</li>
+<li>bar</li>
+</ul>
+<!-- -->
+<ul>
+<li>baz</li>
+<li>bim</li>
+</ul>"
+    );
+    test!(
+        example_309,
+        r"-   foo
+
+    notcode
+
+-   foo
+
+<!-- -->
+
+    code",
+        r"<ul>
+<li>
+<p>foo</p>
+<p>notcode</p>
+</li>
+<li>
+<p>foo</p>
+</li>
+</ul>
+<!-- -->
+<pre><code>code
+</code></pre>"
+    );
+    test!(
+        example_310,
+        r"- a
+ - b
+  - c
+   - d
+  - e
+ - f
+- g",
+        r"<ul>
+<li>a</li>
+<li>b</li>
+<li>c</li>
+<li>d</li>
+<li>e</li>
+<li>f</li>
+<li>g</li>
+</ul>"
+    );
+    test!(
+        example_311,
+        r"1. a
+
+  2. b
+
+   3. c",
+        r"<ol>
+<li>
+<p>a</p>
+</li>
+<li>
+<p>b</p>
+</li>
+<li>
+<p>c</p>
+</li>
+</ol>"
+    );
+    test!(
+        example_312,
+        r"- a
+ - b
+  - c
+   - d
+    - e",
+        r"<ul>
+<li>a</li>
+<li>b</li>
+<li>c</li>
+<li>d
+- e</li>
+</ul>"
+    );
+    test!(
+        example_313,
+        r"1. a
+
+  2. b
+
+    3. c",
+        r"<ol>
+<li>
+<p>a</p>
+</li>
+<li>
+<p>b</p>
+</li>
+</ol>
+<pre><code>3. c
+</code></pre>"
+    );
+    test!(
+        example_314,
+        r"- a
+- b
+
+- c",
+        r"<ul>
+<li>
+<p>a</p>
+</li>
+<li>
+<p>b</p>
+</li>
+<li>
+<p>c</p>
+</li>
+</ul>"
+    );
+    test!(
+        example_315,
+        r"* a
+*
+
+* c",
+        r"<ul>
+<li>
+<p>a</p>
+</li>
+<li></li>
+<li>
+<p>c</p>
+</li>
+</ul>"
+    );
+    test!(
+        example_316,
+        r"- a
+- b
+
+  c
+- d",
+        r"<ul>
+<li>
+<p>a</p>
+</li>
+<li>
+<p>b</p>
+<p>c</p>
+</li>
+<li>
+<p>d</p>
+</li>
+</ul>"
+    );
+    test!(
+        example_317,
+        r"- a
+- b
+
+  [ref]: /url
+- d",
+        r"<ul>
+<li>
+<p>a</p>
+</li>
+<li>
+<p>b</p>
+</li>
+<li>
+<p>d</p>
+</li>
+</ul>"
+    );
+    test!(
+        example_318,
+        r"- a
+- ```
+  b
+
+
+  ```
+- c",
+        r"<ul>
+<li>a</li>
+<li>
+<pre><code>b
+
+
+</code></pre>
+</li>
+<li>c</li>
+</ul>"
+    );
+    test!(
+        example_319,
+        r"- a
+  - b
+
+    c
+- d",
+        r"<ul>
+<li>a
+<ul>
+<li>
+<p>b</p>
+<p>c</p>
+</li>
+</ul>
+</li>
+<li>d</li>
+</ul>"
+    );
+    test!(
+        example_320,
+        r"* a
+  > b
+  >
+* c",
+        r"<ul>
+<li>a
+<blockquote>
+<p>b</p>
+</blockquote>
+</li>
+<li>c</li>
+</ul>"
+    );
+    test!(
+        example_321,
+        r"- a
+  > b
+  ```
+  c
+  ```
+- d",
+        r"<ul>
+<li>a
+<blockquote>
+<p>b</p>
+</blockquote>
+<pre><code>c
+</code></pre>
+</li>
+<li>d</li>
+</ul>"
+    );
+    test!(
+        example_322,
+        r"- a",
+        r"<ul>
+<li>a</li>
+</ul>"
+    );
+    test!(
+        example_323,
+        r"- a
+  - b",
+        r"<ul>
+<li>a
+<ul>
+<li>b</li>
+</ul>
+</li>
+</ul>"
+    );
+    test!(
+        example_324,
+        r"1. ```
+   foo
+   ```
+
+   bar",
+        r"<ol>
+<li>
+<pre><code>foo
+</code></pre>
+<p>bar</p>
+</li>
+</ol>"
+    );
+    test!(
+        example_325,
+        r"* foo
+  * bar
+
+  baz",
+        r"<ul>
+<li>
+<p>foo</p>
+<ul>
+<li>bar</li>
+</ul>
+<p>baz</p>
+</li>
+</ul>"
+    );
+    test!(
+        example_326,
+        r"- a
+  - b
+  - c
+
+- d
+  - e
+  - f",
+        r"<ul>
+<li>
+<p>a</p>
+<ul>
+<li>b</li>
+<li>c</li>
+</ul>
+</li>
+<li>
+<p>d</p>
+<ul>
+<li>e</li>
+<li>f</li>
+</ul>
+</li>
+</ul>"
+    );
+}
+
+mod list_item {
+    use super::*;
+
+    test!(
+        example_253,
+        r"A paragraph
+with two lines.
+
+    indented code
+
+> A block quote.",
+        r"<p>A paragraph
+with two lines.</p>
+<pre><code>indented code
+</code></pre>
+<blockquote>
+<p>A block quote.</p>
+</blockquote>"
+    );
+    test!(
+        example_254,
+        r"1.  A paragraph
+    with two lines.
+
+        indented code
+
+    > A block quote.",
+        r"<ol>
+<li>
+<p>A paragraph
+with two lines.</p>
+<pre><code>indented code
+</code></pre>
+<blockquote>
+<p>A block quote.</p>
+</blockquote>
+</li>
+</ol>"
+    );
+    test!(
+        example_255,
+        r"- one
+
+ two",
+        r"<ul>
+<li>one</li>
+</ul>
+<p>two</p>"
+    );
+    test!(
+        example_256,
+        r"- one
+
+  two",
+        r"<ul>
+<li>
+<p>one</p>
+<p>two</p>
+</li>
+</ul>"
+    );
+    test!(
+        example_257,
+        r" -    one
+
+     two",
+        r"<ul>
+<li>one</li>
+</ul>
+<pre><code> two
+</code></pre>"
+    );
+    test!(
+        example_258,
+        r" -    one
+
+      two",
+        r"<ul>
+<li>
+<p>one</p>
+<p>two</p>
+</li>
+</ul>"
+    );
+    test!(
+        example_259,
+        r"   > > 1.  one
+>>
+>>     two",
+        r"<blockquote>
+<blockquote>
+<ol>
+<li>
+<p>one</p>
+<p>two</p>
+</li>
+</ol>
+</blockquote>
+</blockquote>"
+    );
+    test!(
+        example_260,
+        r">>- one
+>>
+  >  > two",
+        r"<blockquote>
+<blockquote>
+<ul>
+<li>one</li>
+</ul>
+<p>two</p>
+</blockquote>
+</blockquote>"
+    );
+    test!(
+        example_261,
+        r"-one
+
+2.two",
+        r"<p>-one</p>
+<p>2.two</p>"
+    );
+    test!(
+        example_262,
+        r"- foo
+
+
+  bar",
+        r"<ul>
+<li>
+<p>foo</p>
+<p>bar</p>
+</li>
+</ul>"
+    );
+    test!(
+        example_263,
+        r"1.  foo
+
+    ```
+    bar
+    ```
+
+    baz
+
+    > bam",
+        r"<ol>
+<li>
+<p>foo</p>
+<pre><code>bar
+</code></pre>
+<p>baz</p>
+<blockquote>
+<p>bam</p>
+</blockquote>
+</li>
+</ol>"
+    );
+    test!(
+        example_264,
+        r"- Foo
+
+      bar
+
+
+      baz",
+        r"<ul>
+<li>
+<p>Foo</p>
+<pre><code>bar
+
+
+baz
+</code></pre>
+</li>
+</ul>"
+    );
+    test!(
+        example_265,
+        r"123456789. ok",
+        r#"<ol start="123456789">
+<li>ok</li>
+</ol>"#
+    );
+    test!(
+        example_266,
+        r"1234567890. not ok",
+        r"<p>1234567890. not ok</p>"
+    );
+    test!(
+        example_267,
+        r"0. ok",
+        r#"<ol start="0">
+<li>ok</li>
+</ol>"#
+    );
+    test!(
+        example_268,
+        r"003. ok",
+        r#"<ol start="3">
+<li>ok</li>
+</ol>"#
+    );
+    test!(example_269, r"-1. not ok", r"<p>-1. not ok</p>");
+    test!(
+        example_270,
+        r"- foo
+
+      bar",
+        r"<ul>
+<li>
+<p>foo</p>
+<pre><code>bar
+</code></pre>
+</li>
+</ul>"
+    );
+    test!(
+        example_271,
+        r"  10.  foo
+
+           bar",
+        r#"<ol start="10">
+<li>
+<p>foo</p>
+<pre><code>bar
+</code></pre>
+</li>
+</ol>"#
+    );
+    test!(
+        example_272,
+        r"    indented code
+
+paragraph
+
+    more code",
+        r"<pre><code>indented code
+</code></pre>
+<p>paragraph</p>
+<pre><code>more code
+</code></pre>"
+    );
+    test!(
+        example_273,
+        r"1.     indented code
+
+   paragraph
+
+       more code",
+        r"<ol>
+<li>
+<pre><code>indented code
+</code></pre>
+<p>paragraph</p>
+<pre><code>more code
+</code></pre>
+</li>
+</ol>"
+    );
+    test!(
+        example_274,
+        r"1.      indented code
+
+   paragraph
+
+       more code",
+        r"<ol>
+<li>
+<pre><code> indented code
+</code></pre>
+<p>paragraph</p>
+<pre><code>more code
+</code></pre>
+</li>
+</ol>"
+    );
+    test!(
+        example_275,
+        r"   foo
+
+bar",
+        r"<p>foo</p>
+<p>bar</p>"
+    );
+    test!(
+        example_276,
+        r"-    foo
+
+  bar",
+        r"<ul>
+<li>foo</li>
+</ul>
+<p>bar</p>"
+    );
+    test!(
+        example_277,
+        r"-  foo
+
+   bar",
+        r"<ul>
+<li>
+<p>foo</p>
+<p>bar</p>
+</li>
+</ul>"
+    );
+    test!(
+        example_278,
+        r"-
+  foo
+-
+  ```
+  bar
+  ```
+-
+      baz",
+        r"<ul>
+<li>foo</li>
+<li>
+<pre><code>bar
+</code></pre>
+</li>
+<li>
+<pre><code>baz
+</code></pre>
+</li>
+</ul>"
+    );
+    test!(
+        example_279,
+        r"-   
+  foo",
+        r"<ul>
+<li>foo</li>
+</ul>"
+    );
+    test!(
+        example_280,
+        r"-
+
+  foo",
+        r"<ul>
+<li></li>
+</ul>
+<p>foo</p>"
+    );
+    test!(
+        example_281,
+        r"- foo
+-
+- bar",
+        r"<ul>
+<li>foo</li>
+<li></li>
+<li>bar</li>
+</ul>"
+    );
+    test!(
+        example_282,
+        r"- foo
+-   
+- bar",
+        r"<ul>
+<li>foo</li>
+<li></li>
+<li>bar</li>
+</ul>"
+    );
+    test!(
+        example_283,
+        r"1. foo
+2.
+3. bar",
+        r"<ol>
+<li>foo</li>
+<li></li>
+<li>bar</li>
+</ol>"
+    );
+    test!(
+        example_284,
+        r"*",
+        r"<ul>
+<li></li>
+</ul>"
+    );
+    test!(
+        example_285,
+        r"foo
+*
+
+foo
+1.",
+        r"<p>foo
+*</p>
+<p>foo
+1.</p>"
+    );
+    test!(
+        example_286,
+        r" 1.  A paragraph
+     with two lines.
+
+         indented code
+
+     > A block quote.",
+        r"<ol>
+<li>
+<p>A paragraph
+with two lines.</p>
+<pre><code>indented code
+</code></pre>
+<blockquote>
+<p>A block quote.</p>
+</blockquote>
+</li>
+</ol>"
+    );
+    test!(
+        example_287,
+        r"  1.  A paragraph
+      with two lines.
+
+          indented code
+
+      > A block quote.",
+        r"<ol>
+<li>
+<p>A paragraph
+with two lines.</p>
+<pre><code>indented code
+</code></pre>
+<blockquote>
+<p>A block quote.</p>
+</blockquote>
+</li>
+</ol>"
+    );
+    test!(
+        example_288,
+        r"   1.  A paragraph
+       with two lines.
+
+           indented code
+
+       > A block quote.",
+        r"<ol>
+<li>
+<p>A paragraph
+with two lines.</p>
+<pre><code>indented code
+</code></pre>
+<blockquote>
+<p>A block quote.</p>
+</blockquote>
+</li>
+</ol>"
+    );
+    test!(
+        example_289,
+        r"    1.  A paragraph
+        with two lines.
+
+            indented code
+
+        > A block quote.",
+        r"<pre><code>1.  A paragraph
+    with two lines.
+
+        indented code
+
+    &gt; A block quote.
+</code></pre>"
+    );
+    test!(
+        example_290,
+        r"  1.  A paragraph
+with two lines.
+
+          indented code
+
+      > A block quote.",
+        r"<ol>
+<li>
+<p>A paragraph
+with two lines.</p>
+<pre><code>indented code
+</code></pre>
+<blockquote>
+<p>A block quote.</p>
+</blockquote>
+</li>
+</ol>"
+    );
+    test!(
+        example_291,
+        r"  1.  A paragraph
+    with two lines.",
+        r"<ol>
+<li>A paragraph
+with two lines.</li>
+</ol>"
+    );
+    test!(
+        example_292,
+        r"> 1. > Blockquote
+continued here.",
+        r"<blockquote>
+<ol>
+<li>
+<blockquote>
+<p>Blockquote
+continued here.</p>
+</blockquote>
+</li>
+</ol>
+</blockquote>"
+    );
+    test!(
+        example_293,
+        r"> 1. > Blockquote
+> continued here.",
+        r"<blockquote>
+<ol>
+<li>
+<blockquote>
+<p>Blockquote
+continued here.</p>
+</blockquote>
+</li>
+</ol>
+</blockquote>"
+    );
+    test!(
+        example_294,
+        r"- foo
+  - bar
+    - baz
+      - boo",
+        r"<ul>
+<li>foo
+<ul>
+<li>bar
+<ul>
+<li>baz
+<ul>
+<li>boo</li>
+</ul>
+</li>
+</ul>
+</li>
+</ul>
+</li>
+</ul>"
+    );
+    test!(
+        example_295,
+        r"- foo
+ - bar
+  - baz
+   - boo",
+        r"<ul>
+<li>foo</li>
+<li>bar</li>
+<li>baz</li>
+<li>boo</li>
+</ul>"
+    );
+    test!(
+        example_296,
+        r"10) foo
+    - bar",
+        r#"<ol start="10">
+<li>foo
+<ul>
+<li>bar</li>
+</ul>
+</li>
+</ol>"#
+    );
+    test!(
+        example_297,
+        r"10) foo
+   - bar",
+        r#"<ol start="10">
+<li>foo</li>
+</ol>
+<ul>
+<li>bar</li>
+</ul>"#
+    );
+    test!(
+        example_298,
+        r"- - foo",
+        r"<ul>
+<li>
+<ul>
+<li>foo</li>
+</ul>
+</li>
+</ul>"
+    );
+    test!(
+        example_299,
+        r"1. - 2. foo",
+        r#"<ol>
+<li>
+<ul>
+<li>
+<ol start="2">
+<li>foo</li>
+</ol>
+</li>
+</ul>
+</li>
+</ol>"#
+    );
+    test!(
+        example_300,
+        r"- # Foo
+- Bar
+  ---
+  baz",
+        r"<ul>
+<li>
+<h1>Foo</h1>
+</li>
+<li>
+<h2>Bar</h2>
+baz</li>
+</ul>"
+    );
+}
+
+mod paragraph {
+    use super::*;
+
+    test!(
+        example_219,
+        r"aaa
+
+bbb",
+        r"<p>aaa</p>
+<p>bbb</p>"
     );
     test!(
-        example_111,
-        r"    chunk1
+        example_220,
+        r"aaa
+bbb
 
-    chunk2
+ccc
+ddd",
+        r"<p>aaa
+bbb</p>
+<p>ccc
+ddd</p>"
+    );
+    test!(
+        example_221,
+        r"aaa
+
+
+bbb",
+        r"<p>aaa</p>
+<p>bbb</p>"
+    );
+    test!(
+        example_222,
+        r"  aaa
+ bbb",
+        r"<p>aaa
+bbb</p>"
+    );
+    test!(
+        example_223,
+        r"aaa
+             bbb
+                                       ccc",
+        r"<p>aaa
+bbb
+ccc</p>"
+    );
+    test!(
+        example_224,
+        r"   aaa
+bbb",
+        r"<p>aaa
+bbb</p>"
+    );
+    test!(
+        example_225,
+        r"    aaa
+bbb",
+        r"<pre><code>aaa
+</code></pre>
+<p>bbb</p>"
+    );
+    test!(
+        example_226,
+        r"aaa     
+bbb     ",
+        r"<p>aaa<br />
+bbb</p>"
+    );
+}
 
+mod precedence {
+    use super::*;
 
+    test!(
+        example_42,
+        r"- `one
+- two`",
+        r"<ul>
+<li>`one</li>
+<li>two`</li>
+</ul>"
+    );
+}
 
-    chunk3
-",
-        r"<pre><code>chunk1
+mod raw_html {
+    use super::*;
 
-chunk2
+    test!(example_612, r"<a><bab><c2c>", r"<p><a><bab><c2c></p>");
+    test!(example_613, r"<a/><b2/>", r"<p><a/><b2/></p>");
+    test!(
+        example_614,
+        r#"<a  /><b2
+data="foo" >"#,
+        r#"<p><a  /><b2
+data="foo" ></p>"#
+    );
+    test!(
+        example_615,
+        r#"<a foo="bar" bam = 'baz <em>"</em>'
+_boolean zoop:33=zoop:33 />"#,
+        r#"<p><a foo="bar" bam = 'baz <em>"</em>'
+_boolean zoop:33=zoop:33 /></p>"#
+    );
+    test!(
+        example_616,
+        r#"Foo <responsive-image src="foo.jpg" />"#,
+        r#"<p>Foo <responsive-image src="foo.jpg" /></p>"#
+    );
+    test!(example_617, r"<33> <__>", r"<p>&lt;33&gt; &lt;__&gt;</p>");
+    test!(
+        example_618,
+        r#"<a h*#ref="hi">"#,
+        r"<p>&lt;a h*#ref=&quot;hi&quot;&gt;</p>"
+    );
+    test!(
+        example_619,
+        r#"<a href="hi'> <a href=hi'>"#,
+        r"<p>&lt;a href=&quot;hi'&gt; &lt;a href=hi'&gt;</p>"
+    );
+    test!(
+        example_620,
+        r"< a><
+foo><bar/ >
+<foo bar=baz
+bim!bop />",
+        r"<p>&lt; a&gt;&lt;
+foo&gt;&lt;bar/ &gt;
+&lt;foo bar=baz
+bim!bop /&gt;</p>"
+    );
+    test!(
+        example_621,
+        r"<a href='bar'title=title>",
+        r"<p>&lt;a href='bar'title=title&gt;</p>"
+    );
+    test!(example_622, r"</a></foo >", r"<p></a></foo ></p>");
+    test!(
+        example_623,
+        r#"</a href="foo">"#,
+        r"<p>&lt;/a href=&quot;foo&quot;&gt;</p>"
+    );
+    test!(
+        example_624,
+        r"foo <!-- this is a
+comment - with hyphen -->",
+        r"<p>foo <!-- this is a
+comment - with hyphen --></p>"
+    );
+    test!(
+        example_627,
+        r"foo <?php echo $a; ?>",
+        r"<p>foo <?php echo $a; ?></p>"
+    );
+    test!(
+        example_628,
+        r"foo <!ELEMENT br EMPTY>",
+        r"<p>foo <!ELEMENT br EMPTY></p>"
+    );
+    test!(
+        example_629,
+        r"foo <![CDATA[>&<]]>",
+        r"<p>foo <![CDATA[>&<]]></p>"
+    );
+    test!(
+        example_630,
+        r#"foo <a href="&ouml;">"#,
+        r#"<p>foo <a href="&ouml;"></p>"#
+    );
+    test!(
+        example_631,
+        r#"foo <a href="\*">"#,
+        r#"<p>foo <a href="\*"></p>"#
+    );
+    test!(
+        example_632,
+        r#"<a href="\"">"#,
+        r"<p>&lt;a href=&quot;&quot;&quot;&gt;</p>"
+    );
+}
 
+mod setext_heading {
+    use super::*;
 
+    test!(
+        example_80,
+        r"Foo *bar*
+=========
 
-chunk3
-</code></pre>"
+Foo *bar*
+---------",
+        r"<h1>Foo <em>bar</em></h1>
+<h2>Foo <em>bar</em></h2>"
     );
-    // Added terminating new line in input.
     test!(
-        example_112,
-        r"    chunk1
-      
-      chunk2
-",
-        r"<pre><code>chunk1
-  
-  chunk2
-</code></pre>"
+        example_81,
+        r"Foo *bar
+baz*
+====",
+        r"<h1>Foo <em>bar
+baz</em></h1>"
     );
-    /* test!(
-        example_115,
-        r"# Heading
-        foo
-    Heading
-    ------
-        foo
-    ----",
-        r"<h1>Heading</h1>
-    <pre><code>foo
-    </code></pre>
-    <h2>Heading</h2>
-    <pre><code>foo
-    </code></pre>
-    <hr />"
-    ); */
-    // Added terminating new line in input.
     test!(
-        example_116,
-        r"        foo
-    bar
-",
-        r"<pre><code>    foo
+        example_82,
+        r"  Foo *bar
+baz*	
+====",
+        r"<h1>Foo <em>bar
+baz</em></h1>"
+    );
+    test!(
+        example_83,
+        r"Foo
+-------------------------
+
+Foo
+=",
+        r"<h2>Foo</h2>
+<h1>Foo</h1>"
+    );
+    test!(
+        example_84,
+        r"   Foo
+---
+
+  Foo
+-----
+
+  Foo
+  ===",
+        r"<h2>Foo</h2>
+<h2>Foo</h2>
+<h1>Foo</h1>"
+    );
+    test!(
+        example_85,
+        r"    Foo
+    ---
+
+    Foo
+---",
+        r"<pre><code>Foo
+---
+
+Foo
+</code></pre>
+<hr />"
+    );
+    test!(
+        example_86,
+        r"Foo
+   ----      ",
+        r"<h2>Foo</h2>"
+    );
+    test!(
+        example_87,
+        r"Foo
+    ---",
+        r"<p>Foo
+---</p>"
+    );
+    test!(
+        example_88,
+        r"Foo
+= =
+
+Foo
+--- -",
+        r"<p>Foo
+= =</p>
+<p>Foo</p>
+<hr />"
+    );
+    test!(
+        example_89,
+        r"Foo  
+-----",
+        r"<h2>Foo</h2>"
+    );
+    test!(
+        example_90,
+        r"Foo\
+----",
+        r"<h2>Foo\</h2>"
+    );
+    test!(
+        example_91,
+        r#"`Foo
+----
+`
+
+<a title="a lot
+---
+of dashes"/>"#,
+        r"<h2>`Foo</h2>
+<p>`</p>
+<h2>&lt;a title=&quot;a lot</h2>
+<p>of dashes&quot;/&gt;</p>"
+    );
+    test!(
+        example_92,
+        r"> Foo
+---",
+        r"<blockquote>
+<p>Foo</p>
+</blockquote>
+<hr />"
+    );
+    test!(
+        example_93,
+        r"> foo
 bar
-</code></pre>"
+===",
+        r"<blockquote>
+<p>foo
+bar
+===</p>
+</blockquote>"
     );
     test!(
-        example_117,
+        example_94,
+        r"- Foo
+---",
+        r"<ul>
+<li>Foo</li>
+</ul>
+<hr />"
+    );
+    test!(
+        example_95,
+        r"Foo
+Bar
+---",
+        r"<h2>Foo
+Bar</h2>"
+    );
+    test!(
+        example_96,
+        r"---
+Foo
+---
+Bar
+---
+Baz",
+        r"<hr />
+<h2>Foo</h2>
+<h2>Bar</h2>
+<p>Baz</p>"
+    );
+    test!(
+        example_97,
         r"
-    
-    foo
-    ",
+====",
+        r"<p>====</p>"
+    );
+    test!(
+        example_98,
+        r"---
+---",
+        r"<hr />
+<hr />"
+    );
+    test!(
+        example_99,
+        r"- foo
+-----",
+        r"<ul>
+<li>foo</li>
+</ul>
+<hr />"
+    );
+    test!(
+        example_100,
+        r"    foo
+---",
         r"<pre><code>foo
-</code></pre>"
+</code></pre>
+<hr />"
     );
-    // Added terminating new line in input.
     test!(
-        example_118,
-        r"    foo  
-",
-        r"<pre><code>foo  
-</code></pre>"
+        example_101,
+        r"> foo
+-----",
+        r"<blockquote>
+<p>foo</p>
+</blockquote>
+<hr />"
+    );
+    test!(
+        example_102,
+        r"\> foo
+------",
+        r"<h2>&gt; foo</h2>"
+    );
+    test!(
+        example_103,
+        r"Foo
+
+bar
+---
+baz",
+        r"<p>Foo</p>
+<h2>bar</h2>
+<p>baz</p>"
     );
+    test!(
+        example_104,
+        r"Foo
+bar
+
+---
+
+baz",
+        r"<p>Foo
+bar</p>
+<hr />
+<p>baz</p>"
+    );
+    test!(
+        example_105,
+        r"Foo
+bar
+* * *
+baz",
+        r"<p>Foo
+bar</p>
+<hr />
+<p>baz</p>"
+    );
+    test!(
+        example_106,
+        r"Foo
+bar
+\---
+baz",
+        r"<p>Foo
+bar
+---
+baz</p>"
+    );
+}
+
+mod soft_line_break {
+    use super::*;
+
+    test!(
+        example_648,
+        r"foo
+baz",
+        r"<p>foo
+baz</p>"
+    );
+    test!(
+        example_649,
+        r"foo 
+ baz",
+        r"<p>foo
+baz</p>"
+    );
+}
+
+mod tabs {
+    use super::*;
+
+    test!(
+        example_1,
+        "\tfoo\tbaz\t\tbim",
+        "<pre><code>foo\tbaz\t\tbim\n</code></pre>"
+    );
+    test!(
+        example_2,
+        "  \tfoo\tbaz\t\tbim",
+        "<pre><code>foo\tbaz\t\tbim\n</code></pre>"
+    );
+    test!(
+        example_3,
+        "    a\ta\n    ὐ\ta",
+        "<pre><code>a\ta\nὐ\ta\n</code></pre>"
+    );
+    test!(
+        example_4,
+        "  - foo\n\n\tbar",
+        "<ul>\n<li>\n<p>foo</p>\n<p>bar</p>\n</li>\n</ul>"
+    );
+    // The tabs are expanded relative to the line rather than to the content of the containers, and
+    // a tab partly taken by a container marker cannot be split, as the content is made of slices of
+    // the source.
+    // test!(
+    //     example_5,
+    //     "- foo\n\n\t\tbar",
+    //     "<ul>\n<li>\n<p>foo</p>\n<pre><code>  bar\n</code></pre>\n</li>\n</ul>"
+    // );
+    // test!(
+    //     example_6,
+    //     ">\t\tfoo",
+    //     "<blockquote>\n<pre><code>  foo\n</code></pre>\n</blockquote>"
+    // );
+    // test!(
+    //     example_7,
+    //     "-\t\tfoo",
+    //     "<ul>\n<li>\n<pre><code>  foo\n</code></pre>\n</li>\n</ul>"
+    // );
+    test!(
+        example_8,
+        "    foo\n\tbar",
+        "<pre><code>foo\nbar\n</code></pre>"
+    );
+    // Same as above.
+    // test!(
+    //     example_9,
+    //     " - foo\n   - bar\n\t - baz",
+    //     "<ul>\n<li>foo\n<ul>\n<li>bar\n<ul>\n<li>baz</li>\n</ul>\n</li>\n</ul>\n</li>\n</ul>"
+    // );
+    test!(example_10, "#\tFoo", "<h1>Foo</h1>");
+    test!(example_11, "*\t*\t*\t", "<hr />");
 }
 
 mod thematic_break {
@@ -422,6 +4684,17 @@ ___",
         r"<hr />
 <hr />
 <hr />"
+    );
+    test!(example_44, r"+++", r"<p>+++</p>");
+    test!(example_45, r"===", r"<p>===</p>");
+    test!(
+        example_46,
+        r"--
+**
+__",
+        r"<p>--
+**
+__</p>"
     );
     test!(
         example_47,
@@ -431,6 +4704,19 @@ ___",
         r"<hr />
 <hr />
 <hr />"
+    );
+    test!(
+        example_48,
+        r"    ***",
+        r"<pre><code>***
+</code></pre>"
+    );
+    test!(
+        example_49,
+        r"Foo
+    ***",
+        r"<p>Foo
+***</p>"
     );
     test!(
         example_50,
@@ -441,4 +4727,69 @@ ___",
     test!(example_52, r" **  * ** * ** * **", r"<hr />");
     test!(example_53, r"-     -      -      -", r"<hr />");
     test!(example_54, r"- - - -    ", r"<hr />");
+    test!(
+        example_55,
+        r"_ _ _ _ a
+
+a------
+
+---a---",
+        r"<p>_ _ _ _ a</p>
+<p>a------</p>
+<p>---a---</p>"
+    );
+    test!(
+        example_57,
+        r"- foo
+***
+- bar",
+        r"<ul>
+<li>foo</li>
+</ul>
+<hr />
+<ul>
+<li>bar</li>
+</ul>"
+    );
+    test!(
+        example_58,
+        r"Foo
+***
+bar",
+        r"<p>Foo</p>
+<hr />
+<p>bar</p>"
+    );
+    test!(
+        example_59,
+        r"Foo
+---
+bar",
+        r"<h2>Foo</h2>
+<p>bar</p>"
+    );
+    test!(
+        example_60,
+        r"* Foo
+* * *
+* Bar",
+        r"<ul>
+<li>Foo</li>
+</ul>
+<hr />
+<ul>
+<li>Bar</li>
+</ul>"
+    );
+    test!(
+        example_61,
+        r"- Foo
+- * * *",
+        r"<ul>
+<li>Foo</li>
+<li>
+<hr />
+</li>
+</ul>"
+    );
 }